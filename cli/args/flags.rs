@@ -18,6 +18,7 @@ use std::sync::LazyLock;
 
 use clap::Arg;
 use clap::ArgAction;
+use clap::ArgGroup;
 use clap::ArgMatches;
 use clap::ColorChoice;
 use clap::Command;
@@ -760,6 +761,16 @@ static ENV_VARS: &[EnvVar] = &[
     description: "Set the directory for collecting code coverage profiles.\nEquivalent to using the --coverage flag.",
     example: None,
   },
+  EnvVar {
+    name: "DENO_CRON_CATCH_UP",
+    description: "What Deno.cron() does with executions missed while the process wasn't\nrunning, when DENO_CRON_DB_PATH is set: skip, run-once or run-all.",
+    example: Some("(defaults to run-once)"),
+  },
+  EnvVar {
+    name: "DENO_CRON_DB_PATH",
+    description: "Persist Deno.cron() executions to a SQLite database at this path,\nso missed executions can be caught up on restart.",
+    example: None,
+  },
   EnvVar {
     name: "DENO_DIR",
     description: "Set the cache directory",
@@ -775,6 +786,11 @@ static ENV_VARS: &[EnvVar] = &[
     description: "Number of parallel workers used for the --parallel flag with the test\nsubcommand. Defaults to the number of available CPUs.",
     example: None,
   },
+  EnvVar {
+    name: "DENO_KV_ACCESS_TOKEN",
+    description: "Access token for remote databases opened with Deno.openKv(), and the\ntoken `deno kv serve` requires from clients.",
+    example: None,
+  },
   EnvVar {
     name: "DENO_KV_DB_MODE",
     description: "Controls whether Deno.openKv() API should use disk based or in-memory\ndatabase.",
@@ -996,6 +1012,8 @@ static DENO_HELP: &str = cstr!(
                   <p(245)>deno fmt  |  deno fmt main.ts</>
     <g>info</>         Show info about cache or info related to source file
     <g>jupyter</>      Deno kernel for Jupyter notebooks
+    <g>kv</>           Manage Deno KV databases
+                  <p(245)>deno kv serve --path kv.sqlite3</>
     <g>lint</>         Lint source files
     <g>init</>         Initialize a new project
     <g>test</>         Run tests
@@ -1301,6 +1319,7 @@ pub fn flags_from_vec_with_initial_cwd(
         "ci" => ci_parse(&mut flags, &mut m)?,
         "json_reference" => json_reference_parse(&mut flags, &mut m, app),
         "jupyter" => jupyter_parse(&mut flags, &mut m),
+        "kv" => kv_parse(&mut flags, &mut m),
         "lint" => lint_parse(&mut flags, &mut m)?,
         "lsp" => lsp_parse(&mut flags, &mut m),
         "outdated" => outdated_parse(&mut flags, &mut m, false)?,
@@ -1580,6 +1599,7 @@ pub fn clap_root() -> Command {
         .subcommand(ci_subcommand())
         .subcommand(json_reference_subcommand())
         .subcommand(jupyter_subcommand())
+        .subcommand(kv_subcommand())
         .subcommand(approve_scripts_subcommand())
         .subcommand(uninstall_subcommand())
         .subcommand(outdated_subcommand())
@@ -1963,6 +1983,44 @@ If you specify a directory instead of a file, the path is expanded to all contai
           .action(ArgAction::SetTrue)
           .help("UNSTABLE: Output benchmark result in JSON format"),
       )
+      .arg(
+        Arg::new("reporter")
+          .long("reporter")
+          .help("Select reporter to use. Default to 'pretty'")
+          .value_parser(["pretty", "junit", "tap", "github"])
+          .conflicts_with("json"),
+      )
+      .arg(
+        Arg::new("junit-path")
+          .long("junit-path")
+          .value_name("PATH")
+          .value_hint(ValueHint::FilePath)
+          .help("Write a JUnit XML bench report to PATH. Use '-' to write to stdout, where the 'junit' reporter writes when this flag is not provided")
+          .conflicts_with("json"),
+      )
+      .arg(
+        Arg::new("save-baseline")
+          .long("save-baseline")
+          .value_name("NAME")
+          .require_equals(true)
+          .help("Save the results of this run as a named baseline to compare future runs against"),
+      )
+      .arg(
+        Arg::new("baseline")
+          .long("baseline")
+          .value_name("NAME")
+          .require_equals(true)
+          .help("Compare the results of this run against a previously saved baseline and fail on regressions"),
+      )
+      .arg(
+        Arg::new("regression-threshold")
+          .long("regression-threshold")
+          .value_name("PERCENT")
+          .require_equals(true)
+          .value_parser(value_parser!(u32))
+          .requires("baseline")
+          .help("Slowdown relative to the baseline, in percent, that is reported as a regression. Defaults to 10"),
+      )
       .arg(
         Arg::new("ignore")
           .long("ignore")
@@ -2616,6 +2674,10 @@ for an url to match it must match the include pattern and not match the exclude
 Write a report using the lcov format:
   <p(245)>deno coverage --lcov --output=cov.lcov cov_profile/</>
 
+Write a report using the Cobertura XML or Istanbul JSON format:
+  <p(245)>deno coverage --cobertura --output=cobertura.xml cov_profile/</>
+  <p(245)>deno coverage --istanbul --output=coverage-final.json cov_profile/</>
+
 Generate html reports from lcov:
   <p(245)>genhtml -o html_cov cov.lcov</>
 
@@ -2659,13 +2721,30 @@ Generate html reports from lcov:
           .help("Output coverage report in lcov format")
           .action(ArgAction::SetTrue),
       )
+      .arg(
+        Arg::new("cobertura")
+          .long("cobertura")
+          .help("Output coverage report in Cobertura XML format")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("istanbul")
+          .long("istanbul")
+          .help("Output coverage report in Istanbul JSON format")
+          .action(ArgAction::SetTrue),
+      )
+      .group(
+        ArgGroup::new("file-report")
+          .args(["lcov", "cobertura", "istanbul"])
+          .multiple(false),
+      )
       .arg(
         Arg::new("output")
-          .requires("lcov")
+          .requires("file-report")
           .long("output")
           .value_parser(value_parser!(String))
           .help(
-            cstr!("Exports the coverage report in lcov format to the given file.
+            cstr!("Exports the coverage report in lcov, Cobertura or Istanbul format to the given file.
   <p(245)>If no --output arg is specified then the report is written to stdout.</>",
           ))
           .require_equals(true)
@@ -2691,6 +2770,14 @@ Generate html reports from lcov:
           .require_equals(true)
           .help(cstr!("Fail if coverage is below this percentage (0-100), applied to line, branch, and function coverage.
   <p(245)>Per-metric thresholds can be set in deno.json under \"coverage\": { \"thresholds\": { ... } }. The flag takes precedence.</>")),
+      )
+      .arg(
+        Arg::new("changed-since")
+          .long("changed-since")
+          .value_name("GIT_REF")
+          .require_equals(true)
+          .help(cstr!("Only report and check coverage of lines changed since the given git ref.
+  <p(245)>Lines are compared against the merge-base of the ref and HEAD, including uncommitted and untracked changes.</>")),
       )
       .arg(
         Arg::new("files")
@@ -3482,6 +3569,243 @@ fn jupyter_subcommand() -> Command {
         .conflicts_with("install"))
 }
 
+fn kv_subcommand() -> Command {
+  command(
+    "kv",
+    cstr!("Manage Deno KV databases
+
+Keys are given as JSON arrays, with bigints and byte arrays written as objects:
+  <p(245)>deno kv get --path kv.sqlite3 '[\"users\", {\"type\": \"bigint\", \"value\": \"1\"}]'</>
+
+The database can be a SQLite file or a KV Connect URL, as with <c>Deno.openKv()</>."),
+    UnstableArgsConfig::None,
+  )
+  .subcommand_required(true)
+  .subcommand(kv_serve_subcommand())
+  .subcommand(kv_list_subcommand())
+  .subcommand(kv_get_subcommand())
+  .subcommand(kv_set_subcommand())
+  .subcommand(kv_delete_subcommand())
+  .subcommand(kv_watch_subcommand())
+  .subcommand(kv_export_subcommand())
+  .subcommand(kv_import_subcommand())
+  .subcommand(kv_backup_subcommand())
+  .subcommand(kv_restore_subcommand())
+}
+
+fn kv_path_arg() -> Arg {
+  Arg::new("path")
+    .long("path")
+    .help(cstr!(
+      "The SQLite file or KV Connect URL of the database <p(245)>[default: $DENO_KV_DEFAULT_PATH]</>"
+    ))
+    .value_parser(value_parser!(String))
+    .value_hint(ValueHint::FilePath)
+}
+
+fn kv_list_subcommand() -> Command {
+  command(
+    "list",
+    cstr!("List the entries whose keys start with a prefix, one JSON object per line
+  <p(245)>deno kv list --path kv.sqlite3 '[\"users\"]'</>
+
+Without a prefix, every entry is listed."),
+    UnstableArgsConfig::None,
+  )
+  .arg(kv_path_arg())
+  .arg(Arg::new("prefix").help("The key prefix to list"))
+  .arg(
+    Arg::new("limit")
+      .long("limit")
+      .help("The maximum number of entries to list")
+      .value_parser(value_parser!(usize)),
+  )
+  .arg(
+    Arg::new("reverse")
+      .long("reverse")
+      .help("List entries in descending key order")
+      .action(ArgAction::SetTrue),
+  )
+}
+
+fn kv_get_subcommand() -> Command {
+  command(
+    "get",
+    cstr!(
+      "Print the value of a key as JSON
+  <p(245)>deno kv get --path kv.sqlite3 '[\"users\", \"alice\"]'</>"
+    ),
+    UnstableArgsConfig::None,
+  )
+  .arg(kv_path_arg())
+  .arg(Arg::new("key").help("The key to get").required(true))
+}
+
+fn kv_set_subcommand() -> Command {
+  command(
+    "set",
+    cstr!("Set a key to a JSON value
+  <p(245)>deno kv set --path kv.sqlite3 '[\"users\", \"alice\"]' '{\"age\": 30}'</>"),
+    UnstableArgsConfig::None,
+  )
+  .arg(kv_path_arg())
+  .arg(Arg::new("key").help("The key to set").required(true))
+  .arg(Arg::new("value").help("The value, as JSON").required(true))
+}
+
+fn kv_delete_subcommand() -> Command {
+  command(
+    "delete",
+    cstr!(
+      "Delete a key
+  <p(245)>deno kv delete --path kv.sqlite3 '[\"users\", \"alice\"]'</>"
+    ),
+    UnstableArgsConfig::None,
+  )
+  .arg(kv_path_arg())
+  .arg(Arg::new("key").help("The key to delete").required(true))
+}
+
+fn kv_watch_subcommand() -> Command {
+  command(
+    "watch",
+    cstr!("Print the entries of keys whenever they change, one JSON array per line
+  <p(245)>deno kv watch --path kv.sqlite3 '[\"config\"]' '[\"users\", \"alice\"]'</>"),
+    UnstableArgsConfig::None,
+  )
+  .arg(kv_path_arg())
+  .arg(
+    Arg::new("keys")
+      .help("The keys to watch")
+      .num_args(1..)
+      .action(ArgAction::Append)
+      .required(true),
+  )
+}
+
+fn kv_export_subcommand() -> Command {
+  command(
+    "export",
+    cstr!("Export entries as newline-delimited JSON
+  <p(245)>deno kv export --path kv.sqlite3 --output backup.ndjson</>
+
+Values are exported in their stored encoding, so <c>deno kv import</> restores them exactly."),
+    UnstableArgsConfig::None,
+  )
+  .arg(kv_path_arg())
+  .arg(Arg::new("prefix").help("Only export keys with this prefix"))
+  .arg(
+    Arg::new("output")
+      .long("output")
+      .short('o')
+      .help(cstr!("The file to write to <p(245)>[default: stdout]</>"))
+      .value_parser(value_parser!(String))
+      .value_hint(ValueHint::FilePath),
+  )
+}
+
+fn kv_backup_subcommand() -> Command {
+  command(
+    "backup",
+    cstr!(
+      "Write a consistent snapshot of a local database to a new SQLite file
+  <p(245)>deno kv backup --path kv.sqlite3 kv-backup.sqlite3</>
+
+The database can stay in use while the backup is taken."
+    ),
+    UnstableArgsConfig::None,
+  )
+  .arg(kv_path_arg())
+  .arg(
+    Arg::new("output")
+      .help("The file to write the snapshot to")
+      .value_hint(ValueHint::FilePath)
+      .required(true),
+  )
+}
+
+fn kv_restore_subcommand() -> Command {
+  command(
+    "restore",
+    cstr!("Replace the contents of a local database with a snapshot from <c>deno kv backup</>
+  <p(245)>deno kv restore --path kv.sqlite3 kv-backup.sqlite3</>
+
+The database can stay in use while it is restored."),
+    UnstableArgsConfig::None,
+  )
+  .arg(kv_path_arg())
+  .arg(
+    Arg::new("input")
+      .help("The snapshot to restore")
+      .value_hint(ValueHint::FilePath)
+      .required(true),
+  )
+}
+
+fn kv_import_subcommand() -> Command {
+  command(
+    "import",
+    cstr!("Import entries from newline-delimited JSON written by <c>deno kv export</>
+  <p(245)>deno kv import --path kv.sqlite3 backup.ndjson</>
+
+Imported entries overwrite existing entries with the same key."),
+    UnstableArgsConfig::None,
+  )
+  .arg(kv_path_arg())
+  .arg(
+    Arg::new("input")
+      .help(cstr!("The file to read from <p(245)>[default: stdin]</>"))
+      .value_hint(ValueHint::FilePath),
+  )
+}
+
+fn kv_serve_subcommand() -> Command {
+  command(
+    "serve",
+    cstr!("Serve a local Deno KV database over the KV Connect protocol
+
+Processes can then open the database with <c>Deno.openKv(\"http://127.0.0.1:4512\")</>, with
+<c>DENO_KV_ACCESS_TOKEN</> set to the server's access token:
+  <p(245)>deno kv serve --path kv.sqlite3 --access-token my-token</>
+
+When no path is given, an in-memory database is served."),
+    UnstableArgsConfig::None,
+  )
+  .arg(
+    Arg::new("path")
+      .long("path")
+      .help("The SQLite database file to serve")
+      .value_parser(value_parser!(String))
+      .value_hint(ValueHint::FilePath),
+  )
+  .arg(
+    Arg::new("access-token")
+      .long("access-token")
+      .help(cstr!("The token clients must authenticate with <p(245)>[default: $DENO_KV_ACCESS_TOKEN]</>"))
+      .value_parser(value_parser!(String)),
+  )
+  .arg(
+    Arg::new("host")
+      .long("host")
+      .help(cstr!("The TCP address to serve on <p(245)>[default: 127.0.0.1]</>"))
+      .value_parser(serve_host_validator),
+  )
+  .arg(
+    Arg::new("port")
+      .long("port")
+      .help(cstr!("The TCP port to serve on <p(245)>[default: 4512]</>"))
+      .value_parser(value_parser!(u16)),
+  )
+  .arg(
+    Arg::new("public-url")
+      .long("public-url")
+      .help(cstr!("The URL clients reach the server at, if it isn't the address it listens on
+  <p(245)>Clients are sent this URL to make their requests to.</>"))
+      .value_parser(value_parser!(String))
+      .value_hint(ValueHint::Url),
+  )
+}
+
 fn update_and_outdated_args() -> [Arg; 6] {
   [
     Arg::new("filters")
@@ -3979,6 +4303,7 @@ fn run_args(command: Command, top_level: bool) -> Command {
   .arg(tunnel_arg())
   .arg(use_env_proxy_arg())
   .arg(no_use_env_proxy_arg())
+  .arg(permissions_learn_arg())
 }
 
 #[cfg(test)]
@@ -4175,7 +4500,13 @@ List all available tasks (from config files in the current and ancestor director
   <p(245)>deno task</>
 
 Evaluate a task from string:
-  <p(245)>deno task --eval \"echo $(pwd)\"</>"
+  <p(245)>deno task --eval \"echo $(pwd)\"</>
+
+Explain what a task would run, and why, without running it:
+  <p(245)>deno task --dry-run build</>
+
+Rerun the tasks whose declared files change:
+  <p(245)>deno task --watch build</>"
     ),
     UnstableArgsConfig::ResolutionAndRuntime,
   )
@@ -4245,6 +4576,34 @@ parallelizable dependencies)",
           )
           .action(ArgAction::SetTrue),
       )
+      .arg(
+        Arg::new("dry-run")
+          .long("dry-run")
+          .help(
+            "Print the resolved task graph and which tasks would run or be skipped by the input cache, and why, without running anything",
+          )
+          .action(ArgAction::SetTrue)
+          .conflicts_with("eval"),
+      )
+      .arg(
+        Arg::new("graph")
+          .long("graph")
+          .value_name("FORMAT")
+          .require_equals(true)
+          .help("Print the resolved task graph in the given format without running anything")
+          .value_parser(["dot", "json"])
+          .conflicts_with("eval"),
+      )
+      .arg(
+        watch_arg(false)
+          .conflicts_with_all(["eval", "dry-run", "graph"])
+          .help(cstr!(
+            "Watch the declared <c>files</> of the task and its dependencies, and rerun only the tasks whose inputs changed.
+  <p(245)>Tasks that are unaffected by a change keep running.</>"
+          )),
+      )
+      .arg(no_clear_screen_arg())
+      .arg(watch_exclude_arg())
       .arg(env_file_arg())
       .arg(node_modules_dir_arg())
       .arg(node_modules_linker_arg())
@@ -4508,6 +4867,18 @@ or <c>**/__tests__/**</>:
           .conflicts_with("watch")
           .help_heading(TEST_HEADING),
       )
+      .arg(
+        Arg::new("history")
+          .long("history")
+          .help(cstr!("Record each test's outcome to a history file and report flaky tests.
+  <p(245)>With no value, uses a file in the Deno dir for this workspace.
+  Pass a path to share the history, e.g. a file cached between CI runs.</>"))
+          .value_name("FILE")
+          .num_args(0..=1)
+          .require_equals(true)
+          .value_hint(ValueHint::FilePath)
+          .help_heading(TEST_HEADING),
+      )
       .arg(
         Arg::new("related")
           .long("related")
@@ -4519,6 +4890,20 @@ or <c>**/__tests__/**</>:
           .conflicts_with("watch")
           .help_heading(TEST_HEADING),
       )
+      .arg(
+        Arg::new("mutate")
+          .long("mutate")
+          .value_name("PATHS")
+          .num_args(0..)
+          .action(ArgAction::Append)
+          .require_equals(true)
+          .value_hint(ValueHint::AnyPath)
+          .conflicts_with_all(["watch", "no-run", "coverage", "doc"])
+          .help(cstr!("Run mutation testing: apply small changes to the source modules and check that the tests catch them.
+  <p(245)>Defaults to all source modules run by the tests. Results are cached, so an interrupted run resumes where it stopped.
+  <p(245)>Each mutant runs every test in the test modules that run the mutated line, not only the tests that run it.</>"))
+          .help_heading(TEST_HEADING),
+      )
       .arg(
         watch_arg(true)
           .conflicts_with("no-run")
@@ -5076,6 +5461,8 @@ fn permission_args(app: Command, requires: Option<&'static str>) -> Command {
   <g>-P, --permission-set[=<<NAME>]</>            Loads the permission set from the config file.
   <g>--no-prompt</>                               Always throw if required permission wasn't passed.
                                              <p(245)>Can also be set via the DENO_NO_PROMPT environment variable.</>
+  <g>--permission-audit=<<FILE></>                 Write a JSONL record of each distinct permission check and its result to a file.
+                                             <p(245)>--permission-audit=./audit.jsonl</>
   <g>-R, --allow-read[=<<PATH>...]</>             Allow file system read access. Optionally specify allowed paths.
                                              <p(245)>--allow-read  |  --allow-read="/etc,/var/log.txt"</>
   <g>-W, --allow-write[=<<PATH>...]</>            Allow file system write access. Optionally specify allowed paths.
@@ -5144,6 +5531,20 @@ fn permission_args(app: Command, requires: Option<&'static str>) -> Command {
         arg
       }
     )
+    .arg(
+      {
+        let mut arg = Arg::new("permission-audit")
+          .long("permission-audit")
+          .require_equals(true)
+          .value_name("FILE")
+          .value_hint(ValueHint::FilePath)
+          .hide(true);
+        if let Some(requires) = requires {
+          arg = arg.requires(requires);
+        }
+        arg
+      }
+    )
     .arg(
       {
         let mut arg = Arg::new("allow-read")
@@ -6053,6 +6454,20 @@ fn tunnel_arg() -> Arg {
     .action(ArgAction::SetTrue)
 }
 
+fn permissions_learn_arg() -> Arg {
+  Arg::new("permissions-learn")
+    .long("permissions-learn")
+    .num_args(0..=1)
+    .require_equals(true)
+    .default_missing_value("default")
+    .value_name("SET")
+    .help(cstr!(
+      "Run with all permissions and record the ones used. They are written to the given permission set in the config file
+    (<p(245)>default</> when no name is passed), or printed as <p(245)>--allow-*</> flags when there is no config file"
+    ))
+    .conflicts_with_all(["permission-set", "allow-all", "watch", "hmr"])
+}
+
 fn check_arg(checks_local_by_default: bool) -> Arg {
   let arg = Arg::new("check")
     .conflicts_with("no-check")
@@ -6706,6 +7121,28 @@ fn bench_parse(
 
   let no_run = matches.get_flag("no-run");
 
+  let junit_path = matches.remove_one::<String>("junit-path");
+  let reporter =
+    if let Some(reporter) = matches.remove_one::<String>("reporter") {
+      match reporter.as_str() {
+        "pretty" => BenchReporterConfig::Pretty,
+        "junit" => BenchReporterConfig::Junit,
+        "tap" => BenchReporterConfig::Tap,
+        "github" => BenchReporterConfig::Github,
+        _ => unreachable!(),
+      }
+    } else {
+      BenchReporterConfig::Pretty
+    };
+
+  if reporter == BenchReporterConfig::Tap {
+    flags.log_level = Some(Level::Error);
+  }
+
+  let save_baseline = matches.remove_one::<String>("save-baseline");
+  let baseline = matches.remove_one::<String>("baseline");
+  let regression_threshold = matches.remove_one::<u32>("regression-threshold");
+
   flags.subcommand = DenoSubcommand::Bench(BenchFlags {
     files: FileFlags { include, ignore },
     filter,
@@ -6713,6 +7150,11 @@ fn bench_parse(
     no_run,
     permit_no_files: permit_no_files_parse(matches),
     watch: watch_arg_parse(matches)?,
+    reporter,
+    junit_path,
+    save_baseline,
+    baseline,
+    regression_threshold,
   });
 
   Ok(())
@@ -7046,6 +7488,10 @@ fn coverage_parse(
   };
   let r#type = if matches.get_flag("lcov") {
     CoverageType::Lcov
+  } else if matches.get_flag("cobertura") {
+    CoverageType::Cobertura
+  } else if matches.get_flag("istanbul") {
+    CoverageType::Istanbul
   } else if matches.get_flag("html") {
     CoverageType::Html
   } else if matches.get_flag("detailed") {
@@ -7055,6 +7501,7 @@ fn coverage_parse(
   };
   let output = matches.remove_one::<String>("output");
   let threshold = matches.remove_one::<u32>("threshold");
+  let changed_since = matches.remove_one::<String>("changed-since");
   flags.subcommand = DenoSubcommand::Coverage(CoverageFlags {
     files: FileFlags {
       include: files,
@@ -7065,6 +7512,7 @@ fn coverage_parse(
     exclude,
     r#type,
     threshold,
+    changed_since,
   });
   Ok(())
 }
@@ -7620,6 +8068,63 @@ fn jupyter_parse(flags: &mut Flags, matches: &mut ArgMatches) {
   });
 }
 
+fn kv_parse(flags: &mut Flags, matches: &mut ArgMatches) {
+  let (name, mut matches) = matches.remove_subcommand().unwrap();
+  let kv_flags = match name.as_str() {
+    "serve" => KvFlags::Serve(KvServeFlags {
+      path: matches.remove_one::<String>("path"),
+      access_token: matches.remove_one::<String>("access-token"),
+      host: matches
+        .remove_one::<String>("host")
+        .unwrap_or_else(|| "127.0.0.1".to_string()),
+      port: matches.remove_one::<u16>("port").unwrap_or(4512),
+      public_url: matches.remove_one::<String>("public-url"),
+    }),
+    "list" => KvFlags::List(KvListFlags {
+      path: matches.remove_one::<String>("path"),
+      prefix: matches.remove_one::<String>("prefix"),
+      limit: matches.remove_one::<usize>("limit"),
+      reverse: matches.get_flag("reverse"),
+    }),
+    "get" => KvFlags::Get(KvGetFlags {
+      path: matches.remove_one::<String>("path"),
+      key: matches.remove_one::<String>("key").unwrap(),
+    }),
+    "set" => KvFlags::Set(KvSetFlags {
+      path: matches.remove_one::<String>("path"),
+      key: matches.remove_one::<String>("key").unwrap(),
+      value: matches.remove_one::<String>("value").unwrap(),
+    }),
+    "delete" => KvFlags::Delete(KvDeleteFlags {
+      path: matches.remove_one::<String>("path"),
+      key: matches.remove_one::<String>("key").unwrap(),
+    }),
+    "watch" => KvFlags::Watch(KvWatchFlags {
+      path: matches.remove_one::<String>("path"),
+      keys: matches.remove_many::<String>("keys").unwrap().collect(),
+    }),
+    "export" => KvFlags::Export(KvExportFlags {
+      path: matches.remove_one::<String>("path"),
+      prefix: matches.remove_one::<String>("prefix"),
+      output: matches.remove_one::<String>("output"),
+    }),
+    "import" => KvFlags::Import(KvImportFlags {
+      path: matches.remove_one::<String>("path"),
+      input: matches.remove_one::<String>("input"),
+    }),
+    "backup" => KvFlags::Backup(KvBackupFlags {
+      path: matches.remove_one::<String>("path"),
+      output: matches.remove_one::<String>("output").unwrap(),
+    }),
+    "restore" => KvFlags::Restore(KvRestoreFlags {
+      path: matches.remove_one::<String>("path"),
+      input: matches.remove_one::<String>("input").unwrap(),
+    }),
+    _ => unreachable!(),
+  };
+  flags.subcommand = DenoSubcommand::Kv(kv_flags);
+}
+
 fn uninstall_parse(flags: &mut Flags, matches: &mut ArgMatches) {
   lock_args_parse(flags, matches);
   let name = matches.remove_one::<String>("name-or-package").unwrap();
@@ -7757,6 +8262,10 @@ fn run_parse(
   runtime_args_parse(flags, matches, true, true, true)?;
   ext_arg_parse(flags, matches);
 
+  if let Some(set_name) = matches.remove_one::<String>("permissions-learn") {
+    flags.permissions_learn = Some(set_name);
+    flags.allow_all();
+  }
   flags.tunnel = matches.get_flag("tunnel");
   if matches.get_flag("use-env-proxy") {
     // Node's --use-env-proxy is process-wide. Deno's node polyfills read the
@@ -7909,6 +8418,12 @@ fn task_parse(
 
   flags.tunnel = matches.get_flag("tunnel");
 
+  let graph = match matches.remove_one::<String>("graph").as_deref() {
+    Some("dot") => Some(TaskGraphFormat::Dot),
+    Some("json") => Some(TaskGraphFormat::Json),
+    _ => None,
+  };
+
   let mut task_flags = TaskFlags {
     cwd: matches.remove_one::<String>("cwd"),
     task: None,
@@ -7919,6 +8434,9 @@ fn task_parse(
     no_prefix: matches.get_flag("no-prefix"),
     concurrency: matches.remove_one::<NonZeroUsize>("jobs"),
     if_present: matches.get_flag("if-present"),
+    dry_run: matches.get_flag("dry-run"),
+    graph,
+    watch: watch_arg_parse(matches)?,
   };
 
   match matches.remove_subcommand() {
@@ -8140,6 +8658,12 @@ fn test_parse(
     None
   };
 
+  let history = if matches.contains_id("history") {
+    Some(matches.remove_one::<String>("history"))
+  } else {
+    None
+  };
+
   let related = match matches.remove_many::<String>("related") {
     Some(f) => f
       .flat_map(flat_escape_split_commas)
@@ -8147,6 +8671,15 @@ fn test_parse(
     None => vec![],
   };
 
+  let mutate = matches
+    .remove_many::<String>("mutate")
+    .map(|paths| {
+      paths
+        .flat_map(flat_escape_split_commas)
+        .collect::<Result<Vec<_>, _>>()
+    })
+    .transpose()?;
+
   flags.subcommand = DenoSubcommand::Test(TestFlags {
     no_run,
     doc,
@@ -8173,6 +8706,8 @@ fn test_parse(
     changed,
     related,
     update_snapshots,
+    mutate,
+    history,
   });
   Ok(())
 }
@@ -8456,6 +8991,9 @@ fn permission_args_parse(
   if let Some(set) = matches.remove_one::<String>("permission-set") {
     flags.permission_set = Some(set);
   }
+  if let Some(file) = matches.remove_one::<String>("permission-audit") {
+    flags.permission_audit = Some(file);
+  }
   if let Some(read_wl) = matches.remove_many::<String>("allow-read") {
     let read_wl = read_wl
       .flat_map(flat_escape_split_commas)
@@ -9791,22 +10329,25 @@ mod tests {
   }
 
   #[test]
-  fn deny_read() {
-    let r = flags_from_vec(svec!["deno", "--deny-read", "gist.ts"]);
+  fn permission_audit() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "run",
+      "--permission-audit=audit.jsonl",
+      "--allow-read",
+      "gist.ts"
+    ]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Run(RunFlags {
-          script: "gist.ts".to_string(),
-          watch: None,
-          bare: true,
-          coverage_dir: None,
-          print_task_list: false,
-        }),
+        subcommand: DenoSubcommand::Run(RunFlags::new_default(
+          "gist.ts".to_string()
+        )),
         permissions: PermissionFlags {
-          deny_read: Some(vec![]),
+          allow_read: Some(vec![]),
           ..Default::default()
         },
+        permission_audit: Some("audit.jsonl".to_string()),
         code_cache_enabled: true,
         ..Flags::default()
       }
@@ -9814,24 +10355,85 @@ mod tests {
   }
 
   #[test]
-  fn double_hyphen() {
-    // notice that flags passed after double dash will not
-    // be parsed to Flags but instead forwarded to
-    // script args as Deno.args
-    let r = flags_from_vec(svec![
-      "deno",
-      "run",
-      "--allow-write",
-      "script.ts",
-      "--",
-      "-D",
-      "--allow-net"
-    ]);
+  fn permissions_learn() {
+    let r =
+      flags_from_vec(svec!["deno", "run", "--permissions-learn", "gist.ts"]);
     assert_eq!(
       r.unwrap(),
       Flags {
         subcommand: DenoSubcommand::Run(RunFlags::new_default(
-          "script.ts".to_string(),
+          "gist.ts".to_string()
+        )),
+        permissions: PermissionFlags {
+          allow_all: true,
+          ..Default::default()
+        },
+        permissions_learn: Some("default".to_string()),
+        code_cache_enabled: true,
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "run",
+      "--permissions-learn=server",
+      "gist.ts"
+    ]);
+    assert_eq!(r.unwrap().permissions_learn, Some("server".to_string()));
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "run",
+      "--permissions-learn",
+      "--permission-set",
+      "gist.ts"
+    ]);
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn deny_read() {
+    let r = flags_from_vec(svec!["deno", "--deny-read", "gist.ts"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags {
+          script: "gist.ts".to_string(),
+          watch: None,
+          bare: true,
+          coverage_dir: None,
+          print_task_list: false,
+        }),
+        permissions: PermissionFlags {
+          deny_read: Some(vec![]),
+          ..Default::default()
+        },
+        code_cache_enabled: true,
+        ..Flags::default()
+      }
+    );
+  }
+
+  #[test]
+  fn double_hyphen() {
+    // notice that flags passed after double dash will not
+    // be parsed to Flags but instead forwarded to
+    // script args as Deno.args
+    let r = flags_from_vec(svec![
+      "deno",
+      "run",
+      "--allow-write",
+      "script.ts",
+      "--",
+      "-D",
+      "--allow-net"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags::new_default(
+          "script.ts".to_string(),
         )),
         argv: svec!["--", "-D", "--allow-net"],
         permissions: PermissionFlags {
@@ -12747,6 +13349,8 @@ mod tests {
           changed: None,
           related: vec![],
           update_snapshots: false,
+          mutate: None,
+          history: None,
         }),
         no_npm: true,
         no_remote: true,
@@ -12863,6 +13467,8 @@ mod tests {
           changed: None,
           related: vec![],
           update_snapshots: false,
+          mutate: None,
+          history: None,
         }),
         type_check_mode: TypeCheckMode::Local,
         permissions: PermissionFlags {
@@ -12916,6 +13522,8 @@ mod tests {
           changed: None,
           related: vec![],
           update_snapshots: false,
+          mutate: None,
+          history: None,
         }),
         permissions: PermissionFlags {
           no_prompt: true,
@@ -12976,6 +13584,54 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_mutate() {
+    let r = flags_from_vec(svec!["deno", "test", "--mutate"]);
+    assert_eq!(
+      r.unwrap().subcommand,
+      DenoSubcommand::Test(TestFlags {
+        mutate: Some(vec![]),
+        ..Default::default()
+      })
+    );
+
+    let r = flags_from_vec(svec!["deno", "test", "--mutate=src/a.ts,src/b"]);
+    assert_eq!(
+      r.unwrap().subcommand,
+      DenoSubcommand::Test(TestFlags {
+        mutate: Some(svec!["src/a.ts", "src/b"]),
+        ..Default::default()
+      })
+    );
+
+    let r = flags_from_vec(svec!["deno", "test", "--mutate", "--watch"]);
+    assert!(r.is_err());
+    let r = flags_from_vec(svec!["deno", "test", "--mutate", "--coverage"]);
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn test_history() {
+    let r = flags_from_vec(svec!["deno", "test", "--history"]);
+    assert_eq!(
+      r.unwrap().subcommand,
+      DenoSubcommand::Test(TestFlags {
+        history: Some(None),
+        ..Default::default()
+      })
+    );
+
+    let r =
+      flags_from_vec(svec!["deno", "test", "--history=.cache/history.json"]);
+    assert_eq!(
+      r.unwrap().subcommand,
+      DenoSubcommand::Test(TestFlags {
+        history: Some(Some(".cache/history.json".to_string())),
+        ..Default::default()
+      })
+    );
+  }
+
   #[test]
   fn test_changed_conflicts_with_watch() {
     let r = flags_from_vec(svec!["deno", "test", "--changed", "--watch"]);
@@ -13119,6 +13775,8 @@ mod tests {
           changed: None,
           related: vec![],
           update_snapshots: false,
+          mutate: None,
+          history: None,
         }),
         permissions: PermissionFlags {
           no_prompt: true,
@@ -13165,6 +13823,8 @@ mod tests {
           changed: None,
           related: vec![],
           update_snapshots: false,
+          mutate: None,
+          history: None,
         }),
         permissions: PermissionFlags {
           no_prompt: true,
@@ -13230,6 +13890,8 @@ mod tests {
           changed: None,
           related: vec![],
           update_snapshots: false,
+          mutate: None,
+          history: None,
         }),
         permissions: PermissionFlags {
           no_prompt: true,
@@ -13275,6 +13937,8 @@ mod tests {
           changed: None,
           related: vec![],
           update_snapshots: false,
+          mutate: None,
+          history: None,
         }),
         permissions: PermissionFlags {
           no_prompt: true,
@@ -13327,6 +13991,8 @@ mod tests {
           changed: None,
           related: vec![],
           update_snapshots: false,
+          mutate: None,
+          history: None,
         }),
         type_check_mode: TypeCheckMode::Local,
         permissions: PermissionFlags {
@@ -13532,6 +14198,8 @@ mod tests {
         subcommand: DenoSubcommand::Test(TestFlags {
           hide_stacktraces: true,
           update_snapshots: false,
+          mutate: None,
+          history: None,
           ..TestFlags::default()
         }),
         type_check_mode: TypeCheckMode::Local,
@@ -14221,6 +14889,34 @@ mod tests {
     );
   }
 
+  #[test]
+  fn coverage_with_changed_since() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "coverage",
+      "--changed-since=main",
+      "--threshold=80",
+      "foo.json"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Coverage(CoverageFlags {
+          files: FileFlags {
+            include: vec!["foo.json".to_string()],
+            ignore: vec![],
+          },
+          include: vec![r"^file:".to_string()],
+          exclude: vec![r"test\.(js|mjs|ts|jsx|tsx)$".to_string()],
+          threshold: Some(80),
+          changed_since: Some("main".to_string()),
+          ..CoverageFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
+  }
+
   #[test]
   fn coverage_threshold_out_of_range() {
     // Percentages above 100 are rejected by the value parser.
@@ -14251,12 +14947,78 @@ mod tests {
           r#type: CoverageType::Lcov,
           threshold: None,
           output: Some(String::from("foo.lcov")),
+          changed_since: None,
+        }),
+        ..Flags::default()
+      }
+    );
+  }
+
+  #[test]
+  fn coverage_with_cobertura_and_out_file() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "coverage",
+      "--cobertura",
+      "--output=cobertura.xml",
+      "foo.json"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Coverage(CoverageFlags {
+          files: FileFlags {
+            include: vec!["foo.json".to_string()],
+            ignore: vec![],
+          },
+          include: vec![r"^file:".to_string()],
+          exclude: vec![r"test\.(js|mjs|ts|jsx|tsx)$".to_string()],
+          r#type: CoverageType::Cobertura,
+          threshold: None,
+          output: Some(String::from("cobertura.xml")),
+          changed_since: None,
         }),
         ..Flags::default()
       }
     );
   }
 
+  #[test]
+  fn coverage_with_istanbul() {
+    let r = flags_from_vec(svec!["deno", "coverage", "--istanbul", "foo.json"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Coverage(CoverageFlags {
+          files: FileFlags {
+            include: vec!["foo.json".to_string()],
+            ignore: vec![],
+          },
+          include: vec![r"^file:".to_string()],
+          exclude: vec![r"test\.(js|mjs|ts|jsx|tsx)$".to_string()],
+          r#type: CoverageType::Istanbul,
+          ..CoverageFlags::default()
+        }),
+        ..Flags::default()
+      }
+    );
+  }
+
+  #[test]
+  fn coverage_output_requires_file_report() {
+    let r =
+      flags_from_vec(svec!["deno", "coverage", "--output=foo.xml", "foo.json"]);
+    assert!(r.is_err());
+    let r = flags_from_vec(svec![
+      "deno",
+      "coverage",
+      "--lcov",
+      "--cobertura",
+      "foo.json"
+    ]);
+    assert!(r.is_err());
+  }
+
   #[test]
   fn coverage_with_default_files() {
     let r = flags_from_vec(svec!["deno", "coverage",]);
@@ -14374,6 +15136,9 @@ mod tests {
           no_prefix: false,
           concurrency: None,
           if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: None,
         }),
         argv: svec!["hello", "world"],
         ..Flags::default()
@@ -14394,6 +15159,9 @@ mod tests {
           no_prefix: false,
           concurrency: None,
           if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: None,
         }),
         ..Flags::default()
       }
@@ -14413,6 +15181,9 @@ mod tests {
           no_prefix: false,
           concurrency: None,
           if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: None,
         }),
         ..Flags::default()
       }
@@ -14432,6 +15203,9 @@ mod tests {
           no_prefix: false,
           concurrency: None,
           if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: None,
         }),
         ..Flags::default()
       }
@@ -14451,6 +15225,9 @@ mod tests {
           no_prefix: false,
           concurrency: None,
           if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: None,
         }),
         ..Flags::default()
       }
@@ -14470,6 +15247,9 @@ mod tests {
           no_prefix: false,
           concurrency: None,
           if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: None,
         }),
         ..Flags::default()
       }
@@ -14489,6 +15269,9 @@ mod tests {
           no_prefix: false,
           concurrency: None,
           if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: None,
         }),
         ..Flags::default()
       }
@@ -14521,6 +15304,9 @@ mod tests {
             no_prefix: false,
             concurrency: Some(NonZeroUsize::new(1).unwrap()),
             if_present: false,
+            dry_run: false,
+            graph: None,
+            watch: None,
           }),
           ..Flags::default()
         },
@@ -14560,6 +15346,9 @@ mod tests {
           no_prefix: false,
           concurrency: None,
           if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: None,
         }),
         argv: svec!["--", "hello", "world"],
         config_flag: ConfigFlag::Path("deno.json".to_owned()),
@@ -14583,6 +15372,9 @@ mod tests {
           no_prefix: false,
           concurrency: None,
           if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: None,
         }),
         argv: svec!["--", "hello", "world"],
         ..Flags::default()
@@ -14607,6 +15399,9 @@ mod tests {
           no_prefix: false,
           concurrency: None,
           if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: None,
         }),
         argv: svec!["--"],
         ..Flags::default()
@@ -14630,6 +15425,9 @@ mod tests {
           no_prefix: false,
           concurrency: None,
           if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: None,
         }),
         argv: svec!["-1", "--test"],
         ..Flags::default()
@@ -14653,6 +15451,9 @@ mod tests {
           no_prefix: false,
           concurrency: None,
           if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: None,
         }),
         argv: svec!["--test"],
         ..Flags::default()
@@ -14677,6 +15478,9 @@ mod tests {
           no_prefix: false,
           concurrency: None,
           if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: None,
         }),
         log_level: Some(log::Level::Error),
         ..Flags::default()
@@ -14700,6 +15504,9 @@ mod tests {
           no_prefix: false,
           concurrency: None,
           if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: None,
         }),
         ..Flags::default()
       }
@@ -14722,6 +15529,9 @@ mod tests {
           no_prefix: false,
           concurrency: None,
           if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: None,
         }),
         config_flag: ConfigFlag::Path("deno.jsonc".to_string()),
         ..Flags::default()
@@ -14745,6 +15555,9 @@ mod tests {
           no_prefix: false,
           concurrency: None,
           if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: None,
         }),
         config_flag: ConfigFlag::Path("deno.jsonc".to_string()),
         ..Flags::default()
@@ -14777,6 +15590,9 @@ mod tests {
           no_prefix: false,
           concurrency: None,
           if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: None,
         }),
         env_file: Some(vec![".env".to_owned()]),
         ..Flags::default()
@@ -14803,6 +15619,9 @@ mod tests {
           no_prefix: false,
           concurrency: None,
           if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: None,
         }),
         env_file: Some(vec![".env.dev".to_owned(), ".env.local".to_owned()]),
         ..Flags::default()
@@ -14826,12 +15645,137 @@ mod tests {
           no_prefix: false,
           concurrency: None,
           if_present: true,
+          dry_run: false,
+          graph: None,
+          watch: None,
         }),
         ..Flags::default()
       }
     );
   }
 
+  #[test]
+  fn task_subcommand_dry_run_and_graph() {
+    let r = flags_from_vec(svec!["deno", "task", "--dry-run", "build"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Task(TaskFlags {
+          cwd: None,
+          task: Some("build".to_string()),
+          is_run: false,
+          recursive: false,
+          filter: None,
+          eval: false,
+          no_prefix: false,
+          concurrency: None,
+          if_present: false,
+          dry_run: true,
+          graph: None,
+          watch: None,
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "task", "--graph=dot", "build"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Task(TaskFlags {
+          cwd: None,
+          task: Some("build".to_string()),
+          is_run: false,
+          recursive: false,
+          filter: None,
+          eval: false,
+          no_prefix: false,
+          concurrency: None,
+          if_present: false,
+          dry_run: false,
+          graph: Some(TaskGraphFormat::Dot),
+          watch: None,
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "task", "--graph=svg", "build"]);
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn task_subcommand_watch() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "task",
+      "--watch",
+      "--no-clear-screen",
+      "--watch-exclude=dist",
+      "build"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Task(TaskFlags {
+          cwd: None,
+          task: Some("build".to_string()),
+          is_run: false,
+          recursive: false,
+          filter: None,
+          eval: false,
+          no_prefix: false,
+          concurrency: None,
+          if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: Some(WatchFlags {
+            hmr: false,
+            no_clear_screen: true,
+            exclude: svec!["dist"],
+          }),
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "task", "--watch", "build"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Task(TaskFlags {
+          cwd: None,
+          task: Some("build".to_string()),
+          is_run: false,
+          recursive: false,
+          filter: None,
+          eval: false,
+          no_prefix: false,
+          concurrency: None,
+          if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: Some(WatchFlags {
+            hmr: false,
+            no_clear_screen: false,
+            exclude: vec![],
+          }),
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r =
+      flags_from_vec(svec!["deno", "task", "--watch", "--dry-run", "build"]);
+    assert!(r.is_err());
+    let r =
+      flags_from_vec(svec!["deno", "task", "--watch", "--graph", "build"]);
+    assert!(r.is_err());
+    let r =
+      flags_from_vec(svec!["deno", "task", "--watch", "--eval", "echo 1"]);
+    assert!(r.is_err());
+  }
+
   #[test]
   fn bench_with_flags() {
     let r = flags_from_vec(svec![
@@ -14865,6 +15809,11 @@ mod tests {
           },
           watch: Default::default(),
           permit_no_files: false,
+          reporter: Default::default(),
+          junit_path: None,
+          save_baseline: None,
+          baseline: None,
+          regression_threshold: None,
         }),
         no_npm: true,
         no_remote: true,
@@ -14881,6 +15830,96 @@ mod tests {
     );
   }
 
+  #[test]
+  fn bench_reporter() {
+    let cases = [
+      ("pretty", BenchReporterConfig::Pretty, None),
+      ("junit", BenchReporterConfig::Junit, None),
+      ("tap", BenchReporterConfig::Tap, Some(Level::Error)),
+      ("github", BenchReporterConfig::Github, None),
+    ];
+    for (name, reporter, log_level) in cases {
+      let r =
+        flags_from_vec(svec!["deno", "bench", format!("--reporter={name}")]);
+      assert_eq!(
+        r.unwrap(),
+        Flags {
+          subcommand: DenoSubcommand::Bench(BenchFlags {
+            reporter,
+            ..BenchFlags::default()
+          }),
+          permissions: PermissionFlags {
+            no_prompt: true,
+            ..Default::default()
+          },
+          type_check_mode: TypeCheckMode::Local,
+          log_level,
+          ..Flags::default()
+        },
+        "{name}"
+      );
+    }
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "bench",
+      "--reporter=tap",
+      "--junit-path=bench.xml"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Bench(BenchFlags {
+          reporter: BenchReporterConfig::Tap,
+          junit_path: Some("bench.xml".to_string()),
+          ..BenchFlags::default()
+        }),
+        permissions: PermissionFlags {
+          no_prompt: true,
+          ..Default::default()
+        },
+        type_check_mode: TypeCheckMode::Local,
+        log_level: Some(Level::Error),
+        ..Flags::default()
+      }
+    );
+
+    let r =
+      flags_from_vec(svec!["deno", "bench", "--json", "--reporter=junit"]);
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn bench_baseline() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "bench",
+      "--save-baseline=feature",
+      "--baseline=main",
+      "--regression-threshold=5"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Bench(BenchFlags {
+          save_baseline: Some("feature".to_string()),
+          baseline: Some("main".to_string()),
+          regression_threshold: Some(5),
+          ..BenchFlags::default()
+        }),
+        permissions: PermissionFlags {
+          no_prompt: true,
+          ..Default::default()
+        },
+        type_check_mode: TypeCheckMode::Local,
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "bench", "--regression-threshold=5"]);
+    assert!(r.is_err());
+  }
+
   #[test]
   fn bench_watch() {
     let r = flags_from_vec(svec!["deno", "bench", "--watch"]);
@@ -14896,7 +15935,12 @@ mod tests {
             ignore: vec![],
           },
           watch: Some(Default::default()),
-          permit_no_files: false
+          permit_no_files: false,
+          reporter: Default::default(),
+          junit_path: None,
+          save_baseline: None,
+          baseline: None,
+          regression_threshold: None,
         }),
         permissions: PermissionFlags {
           no_prompt: true,
@@ -14923,7 +15967,12 @@ mod tests {
             ignore: vec![],
           },
           watch: None,
-          permit_no_files: true
+          permit_no_files: true,
+          reporter: Default::default(),
+          junit_path: None,
+          save_baseline: None,
+          baseline: None,
+          regression_threshold: None,
         }),
         permissions: PermissionFlags {
           no_prompt: true,
@@ -15490,6 +16539,161 @@ mod tests {
     assert!(r.is_err());
   }
 
+  #[test]
+  fn kv_serve() {
+    let r = flags_from_vec(svec!["deno", "kv", "serve"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Kv(KvFlags::Serve(KvServeFlags {
+          path: None,
+          access_token: None,
+          host: "127.0.0.1".to_string(),
+          port: 4512,
+          public_url: None,
+        })),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "kv",
+      "serve",
+      "--path",
+      "kv.sqlite3",
+      "--access-token",
+      "secret",
+      "--host",
+      "0.0.0.0",
+      "--port",
+      "8080",
+      "--public-url",
+      "http://kv.internal:8080"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Kv(KvFlags::Serve(KvServeFlags {
+          path: Some("kv.sqlite3".to_string()),
+          access_token: Some("secret".to_string()),
+          host: "0.0.0.0".to_string(),
+          port: 8080,
+          public_url: Some("http://kv.internal:8080".to_string()),
+        })),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "kv"]);
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn kv_data_subcommands() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "kv",
+      "list",
+      "--path",
+      "kv.sqlite3",
+      "--limit",
+      "10",
+      "--reverse",
+      r#"["users"]"#
+    ]);
+    assert_eq!(
+      r.unwrap().subcommand,
+      DenoSubcommand::Kv(KvFlags::List(KvListFlags {
+        path: Some("kv.sqlite3".to_string()),
+        prefix: Some(r#"["users"]"#.to_string()),
+        limit: Some(10),
+        reverse: true,
+      }))
+    );
+
+    let r = flags_from_vec(svec!["deno", "kv", "set", r#"["a"]"#, "1"]);
+    assert_eq!(
+      r.unwrap().subcommand,
+      DenoSubcommand::Kv(KvFlags::Set(KvSetFlags {
+        path: None,
+        key: r#"["a"]"#.to_string(),
+        value: "1".to_string(),
+      }))
+    );
+
+    let r =
+      flags_from_vec(svec!["deno", "kv", "watch", r#"["a"]"#, r#"["b"]"#]);
+    assert_eq!(
+      r.unwrap().subcommand,
+      DenoSubcommand::Kv(KvFlags::Watch(KvWatchFlags {
+        path: None,
+        keys: svec![r#"["a"]"#, r#"["b"]"#],
+      }))
+    );
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "kv",
+      "export",
+      "--path",
+      "https://example.com/kv",
+      "-o",
+      "backup.ndjson"
+    ]);
+    assert_eq!(
+      r.unwrap().subcommand,
+      DenoSubcommand::Kv(KvFlags::Export(KvExportFlags {
+        path: Some("https://example.com/kv".to_string()),
+        prefix: None,
+        output: Some("backup.ndjson".to_string()),
+      }))
+    );
+
+    let r = flags_from_vec(svec!["deno", "kv", "import", "backup.ndjson"]);
+    assert_eq!(
+      r.unwrap().subcommand,
+      DenoSubcommand::Kv(KvFlags::Import(KvImportFlags {
+        path: None,
+        input: Some("backup.ndjson".to_string()),
+      }))
+    );
+
+    let r = flags_from_vec(svec!["deno", "kv", "get"]);
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn kv_backup_restore() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "kv",
+      "backup",
+      "--path",
+      "kv.sqlite3",
+      "backup.sqlite3"
+    ]);
+    assert_eq!(
+      r.unwrap().subcommand,
+      DenoSubcommand::Kv(KvFlags::Backup(KvBackupFlags {
+        path: Some("kv.sqlite3".to_string()),
+        output: "backup.sqlite3".to_string(),
+      }))
+    );
+
+    let r = flags_from_vec(svec!["deno", "kv", "restore", "backup.sqlite3"]);
+    assert_eq!(
+      r.unwrap().subcommand,
+      DenoSubcommand::Kv(KvFlags::Restore(KvRestoreFlags {
+        path: None,
+        input: "backup.sqlite3".to_string(),
+      }))
+    );
+
+    let r = flags_from_vec(svec!["deno", "kv", "backup"]);
+    assert!(r.is_err());
+  }
+
   #[test]
   fn jupyter() {
     let r = flags_from_vec(svec!["deno", "jupyter"]);
@@ -11,6 +11,7 @@ use std::num::NonZeroUsize;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 use deno_ast::MediaType;
 use deno_ast::ModuleSpecifier;
@@ -26,6 +27,7 @@ use deno_config::deno_json::NodeModulesDirMode;
 use deno_config::deno_json::PermissionConfigValue;
 use deno_config::deno_json::PermissionsObjectWithBase;
 pub use deno_config::deno_json::ProseWrap;
+use deno_config::deno_json::QuarantinedTest;
 use deno_config::deno_json::TestConfig;
 pub use deno_config::glob::FilePatterns;
 pub use deno_config::workspace::TsTypeLib;
@@ -34,8 +36,10 @@ use deno_config::workspace::WorkspaceDirLintConfig;
 use deno_config::workspace::WorkspaceDirectory;
 use deno_config::workspace::WorkspaceDirectoryRc;
 use deno_config::workspace::WorkspaceLintConfig;
+use deno_core::anyhow::Context;
 use deno_core::anyhow::bail;
 use deno_core::error::AnyError;
+use deno_core::serde_json;
 use deno_core::url::Url;
 use deno_graph::GraphKind;
 use deno_lib::args::CaData;
@@ -48,6 +52,8 @@ use deno_npm::NpmSystemInfo;
 use deno_npm_installer::LifecycleScriptsConfig;
 use deno_npm_installer::graph::NpmCachingStrategy;
 use deno_resolver::factory::resolve_jsr_url;
+use deno_runtime::deno_kv::KvLimits;
+use deno_runtime::deno_kv::LocalKvLimits;
 use deno_runtime::deno_node::ops::ipc::ChildIpcSerialization;
 use deno_runtime::deno_permissions::AllowRunDescriptor;
 use deno_runtime::deno_permissions::PathDescriptor;
@@ -55,6 +61,8 @@ use deno_runtime::deno_permissions::PermissionsOptions;
 use deno_semver::StackString;
 use deno_semver::npm::NpmPackageReqReference;
 use deno_telemetry::OtelConfig;
+use deno_telemetry::SamplerConfig;
+use deno_telemetry::SamplingRule;
 use deno_terminal::colors;
 pub use flags::*;
 use once_cell::sync::Lazy;
@@ -62,6 +70,7 @@ use thiserror::Error;
 
 use crate::sys::CliSys;
 use crate::util::fs::canonicalize_path;
+use crate::util::fs::canonicalize_path_maybe_not_exists;
 use crate::util::path::resolve_url_or_path_normalized;
 
 pub type CliLockfile = deno_resolver::lockfile::LockfileLock<CliSys>;
@@ -87,6 +96,11 @@ pub struct WorkspaceBenchOptions {
   pub json: bool,
   pub no_run: bool,
   pub permit_no_files: bool,
+  pub reporter: BenchReporterConfig,
+  pub junit_path: Option<String>,
+  pub save_baseline: Option<String>,
+  pub baseline: Option<String>,
+  pub regression_threshold: Option<u32>,
 }
 
 impl WorkspaceBenchOptions {
@@ -96,6 +110,11 @@ impl WorkspaceBenchOptions {
       json: bench_flags.json,
       no_run: bench_flags.no_run,
       permit_no_files: bench_flags.permit_no_files,
+      reporter: bench_flags.reporter,
+      junit_path: bench_flags.junit_path.clone(),
+      save_baseline: bench_flags.save_baseline.clone(),
+      baseline: bench_flags.baseline.clone(),
+      regression_threshold: bench_flags.regression_threshold,
     }
   }
 }
@@ -221,6 +240,8 @@ pub struct WorkspaceTestOptions {
   pub junit_path: Option<String>,
   pub hide_stacktraces: bool,
   pub update_snapshots: bool,
+  pub quarantine: Vec<QuarantinedTest>,
+  pub history: Option<Option<String>>,
 }
 
 impl WorkspaceTestOptions {
@@ -263,6 +284,10 @@ impl WorkspaceTestOptions {
       junit_path: test_flags.junit_path.clone(),
       hide_stacktraces: test_flags.hide_stacktraces,
       update_snapshots: test_flags.update_snapshots,
+      quarantine: test_config
+        .map(|c| c.quarantine.clone())
+        .unwrap_or_default(),
+      history: test_flags.history.clone(),
     }
   }
 }
@@ -721,6 +746,29 @@ impl CliOptions {
     self.flags.otel_config()
   }
 
+  /// Resolves the trace sampling settings of the root `deno.json`'s
+  /// `telemetry` section.
+  pub fn otel_sampler_config(&self) -> Result<SamplerConfig, AnyError> {
+    let Some(deno_json) = self.workspace().root_deno_json() else {
+      return Ok(SamplerConfig::default());
+    };
+    let sampler = deno_json.to_telemetry_config()?.sampler;
+    Ok(SamplerConfig {
+      sampler: sampler.kind,
+      arg: sampler.arg,
+      rules: sampler
+        .rules
+        .into_iter()
+        .map(|rule| SamplingRule {
+          route: rule.route,
+          ratio: rule.ratio,
+          errors: rule.errors,
+          slower_than: rule.slower_than_ms.map(Duration::from_millis),
+        })
+        .collect(),
+    })
+  }
+
   pub fn no_legacy_abort(&self) -> bool {
     self.flags.no_legacy_abort()
   }
@@ -1373,6 +1421,41 @@ impl CliOptions {
       .unwrap_or_default()
   }
 
+  /// Resolves the limits that the `kv` sections of the workspace root and
+  /// member deno.json set for KV databases stored locally.
+  pub fn kv_local_limits(&self) -> Result<LocalKvLimits, AnyError> {
+    fn parse_limits(
+      limits: serde_json::Map<String, serde_json::Value>,
+      field: &str,
+    ) -> Result<KvLimits, AnyError> {
+      let limits: KvLimits =
+        serde_json::from_value(serde_json::Value::Object(limits))
+          .with_context(|| {
+            format!("Invalid \"kv\" configuration: \"{field}\"")
+          })?;
+      if let Some(name) = limits.find_zero() {
+        bail!(
+          "Invalid \"kv\" configuration: \"{field}.{name}\" must be greater than 0"
+        );
+      }
+      Ok(limits)
+    }
+
+    let config = self.start_dir.to_kv_config()?;
+    let default = parse_limits(config.limits, "limits")?;
+    let databases = config
+      .databases
+      .into_iter()
+      .map(|(path, limits)| {
+        let field = format!("databases[\"{}\"].limits", path.display());
+        let limits = parse_limits(limits, &field)?;
+        // opened databases are looked up by their canonicalized path
+        Ok((canonicalize_path_maybe_not_exists(&path)?, limits))
+      })
+      .collect::<Result<_, AnyError>>()?;
+    Ok(LocalKvLimits { default, databases })
+  }
+
   pub fn reload_flag(&self) -> bool {
     self.flags.reload
   }
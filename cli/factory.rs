@@ -653,6 +653,7 @@ impl CliFactory {
             | DenoSubcommand::List { .. }
             | DenoSubcommand::JSONReference { .. }
             | DenoSubcommand::Jupyter { .. }
+            | DenoSubcommand::Kv { .. }
             | DenoSubcommand::Lsp
             | DenoSubcommand::Lint { .. }
             | DenoSubcommand::Repl { .. }
@@ -1195,6 +1196,10 @@ impl CliFactory {
     let pkg_json_resolver = self.pkg_json_resolver()?;
     let module_loader_factory = self.create_module_loader_factory().await?;
     self.maybe_start_inspector_server()?;
+    deno_telemetry::configure_sampler(
+      &self.sys(),
+      cli_options.otel_sampler_config()?,
+    )?;
 
     let maybe_cpu_prof_config_for_workers =
       cli_options.cpu_prof_dir().map(|dir| CpuProfilerConfig {
@@ -1276,6 +1281,7 @@ impl CliFactory {
       node_cluster_sched_policy: std::env::var("NODE_CLUSTER_SCHED_POLICY")
         .ok(),
       origin_data_folder_path: Some(self.deno_dir()?.origin_data_folder_path()),
+      kv_local_limits: cli_options.kv_local_limits()?,
       seed: cli_options.seed(),
       unsafely_ignore_certificate_errors: cli_options
         .unsafely_ignore_certificate_errors()
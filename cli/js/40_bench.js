@@ -23,6 +23,7 @@ const {
   MathMax,
   MathMin,
   MathCeil,
+  MathFloor,
   SymbolToStringTag,
   TypeError,
 } = primordials;
@@ -231,9 +232,24 @@ function benchStats(
     avg: !highPrecision ? (avg / n) : MathCeil(avg / n),
     highPrecision,
     usedExplicitTimers,
+    samples: benchSamples(all, allLength),
   };
 }
 
+// cap the number of samples sent back for baseline comparisons,
+// evenly picking them from the sorted measurements so that the
+// distribution is preserved
+const maxSamples = 1_000;
+
+function benchSamples(all, allLength) {
+  const length = MathMin(maxSamples, allLength);
+  const samples = new Array(length);
+  for (let i = 0; i < length; i++) {
+    samples[i] = all[MathFloor(i * allLength / length)];
+  }
+  return samples;
+}
+
 // reuse the same array across all benchmarks
 // and cap the length so that we don't spend
 // too much time sorting
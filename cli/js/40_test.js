@@ -46,6 +46,13 @@ const {
 } = primordials;
 
 const { setExitHandler } = core.loadExtScript("ext:deno_os/30_os.js");
+const { createVirtualClock } = core.loadExtScript(
+  "ext:deno_web/02_timers.js",
+);
+
+// The virtual clock of the running test, created on first access to
+// `t.clock` and shared with its steps.
+let virtualClock = null;
 
 // Capture `Deno` global so that users deleting or mangling it, won't
 // have impact on our sanitizers.
@@ -249,6 +256,10 @@ function wrapOuter(fn, desc) {
         stepReportResult(childDesc, { failed: "incomplete" }, 0);
       }
       state.completed = true;
+      if (!("parent" in desc) && virtualClock !== null) {
+        virtualClock.restore();
+        virtualClock = null;
+      }
     }
   };
 }
@@ -771,6 +782,16 @@ function createTestContext(desc) {
     async assertSnapshot(actual, maybeOptions) {
       return assertSnapshot(testContext, actual, maybeOptions);
     },
+    /**
+     * Virtual clock for timers, `Date` and `performance.now()`. Time is
+     * frozen from the first access until the test finishes.
+     */
+    get clock() {
+      if (virtualClock === null) {
+        virtualClock = createVirtualClock();
+      }
+      return virtualClock;
+    },
     /**
      * @param nameOrFnOrOptions {string | TestStepDefinition | ((t: TestContext) => void | Promise<void>)}
      * @param maybeFn {((t: TestContext) => void | Promise<void>) | undefined}
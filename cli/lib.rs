@@ -207,6 +207,7 @@ async fn run_subcommand(
         coverage_flags.exclude,
         coverage_flags.output,
         coverage_flags.threshold.map(|t| t as f64),
+        coverage_flags.changed_since.as_deref(),
         &[&*reporter],
       )
     }),
@@ -247,6 +248,9 @@ async fn run_subcommand(
     DenoSubcommand::Jupyter(jupyter_flags) => spawn_subcommand(async {
       tools::jupyter::kernel(Arc::new(flags), jupyter_flags).await
     }),
+    DenoSubcommand::Kv(kv_flags) => {
+      spawn_subcommand(async { tools::kv::kv(Arc::new(flags), kv_flags).await })
+    }
     DenoSubcommand::Uninstall(uninstall_flags) => spawn_subcommand(async {
       tools::installer::uninstall(Arc::new(flags), uninstall_flags).await
     }),
@@ -297,6 +301,9 @@ async fn run_subcommand(
           no_prefix: false,
           concurrency: None,
           if_present: false,
+          dry_run: false,
+          graph: None,
+          watch: None,
         };
         let mut flags = flags;
         flags.subcommand = DenoSubcommand::Task(task_flags.clone());
@@ -411,6 +418,9 @@ async fn run_subcommand(
                   no_prefix: false,
                   concurrency: None,
                   if_present: false,
+                  dry_run: false,
+                  graph: None,
+                  watch: None,
                 };
                 new_flags.subcommand = DenoSubcommand::Task(task_flags.clone());
                 let result = tools::task::execute_script(
@@ -468,6 +478,9 @@ async fn run_subcommand(
 
         if test_flags.watch.is_some() {
           tools::test::run_tests_with_watch(Arc::new(flags), test_flags).await
+        } else if test_flags.mutate.is_some() {
+          tools::test::mutation::run_mutation_tests(Arc::new(flags), test_flags)
+            .await
         } else {
           tools::test::run_tests(Arc::new(flags), test_flags).await
         }
@@ -1044,6 +1057,18 @@ async fn resolve_flags_and_init(
     }
   }
 
+  if let Some(audit_file) = &flags.permission_audit {
+    let file = std::fs::File::create(audit_file).with_context(|| {
+      format!("Failed to create permission audit file '{audit_file}'")
+    })?;
+    if !deno_runtime::deno_permissions::audit::set_permission_audit_log(file) {
+      log::warn!(
+        "⚠️  {}",
+        colors::yellow("Permission audit log is already set")
+      );
+    }
+  }
+
   Ok(flags)
 }
 
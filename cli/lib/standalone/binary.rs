@@ -5,6 +5,7 @@ use std::collections::BTreeMap;
 
 use deno_media_type::MediaType;
 use deno_resolver::workspace::PackageJsonDepResolution;
+use deno_runtime::deno_kv::LocalKvLimits;
 use deno_runtime::deno_permissions::PermissionsOptions;
 use deno_runtime::deno_telemetry::OtelConfig;
 use deno_semver::Version;
@@ -114,6 +115,10 @@ pub struct Metadata {
   /// Auto-update release base URL from deno.json `desktop.release.baseUrl`.
   #[serde(default, skip_serializing_if = "Option::is_none")]
   pub release_base_url: Option<String>,
+  /// KV limits from the deno.json `kv` section. Per-database limits keep
+  /// the paths resolved at compile time.
+  #[serde(default)]
+  pub kv_local_limits: LocalKvLimits,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
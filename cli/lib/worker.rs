@@ -38,6 +38,7 @@ use deno_runtime::deno_node::NodeExtInitServices;
 use deno_runtime::deno_node::NodeRequireLoader;
 use deno_runtime::deno_node::NodeResolver;
 use deno_runtime::deno_permissions::PermissionsContainer;
+use deno_runtime::deno_permissions::audit::has_permission_audit_log;
 use deno_runtime::deno_process::NpmProcessStateProviderRc;
 use deno_runtime::deno_telemetry::OtelConfig;
 use deno_runtime::deno_tls::RootCertStoreProvider;
@@ -279,6 +280,7 @@ pub struct LibMainWorkerOptions {
   pub node_cluster_sched_policy: Option<String>,
   pub otel_config: OtelConfig,
   pub origin_data_folder_path: Option<PathBuf>,
+  pub kv_local_limits: deno_runtime::deno_kv::LocalKvLimits,
   pub seed: Option<u64>,
   pub unsafely_ignore_certificate_errors: Option<Vec<String>>,
   pub skip_op_registration: bool,
@@ -515,6 +517,7 @@ impl<TSys: DenoLibSys> LibWorkerFactorySharedState<TSys> {
         worker_type: args.worker_type,
         stdio: stdio.clone(),
         cache_storage_dir,
+        kv_local_limits: shared.options.kv_local_limits.clone(),
         trace_ops: shared.options.trace_ops.clone(),
         close_on_idle: args.close_on_idle,
         maybe_worker_metadata: args.maybe_worker_metadata,
@@ -524,7 +527,7 @@ impl<TSys: DenoLibSys> LibWorkerFactorySharedState<TSys> {
         enable_raw_imports: shared.options.enable_raw_imports,
         enable_stack_trace_arg_in_ops: has_trace_permissions_enabled(
           &shared.sys,
-        ),
+        ) || has_permission_audit_log(),
         wait_for_debugger_on_start: args.wait_for_debugger_on_start,
         wait_for_page_wait_for_debugger: args.wait_for_page_wait_for_debugger,
       };
@@ -764,10 +767,13 @@ impl<TSys: DenoLibSys> LibMainWorkerFactory<TSys> {
       trace_ops: shared.options.trace_ops.clone(),
       cache_storage_dir,
       origin_storage_dir,
+      kv_local_limits: shared.options.kv_local_limits.clone(),
       stdio,
       skip_op_registration: shared.options.skip_op_registration,
       enable_raw_imports: shared.options.enable_raw_imports,
-      enable_stack_trace_arg_in_ops: has_trace_permissions_enabled(&shared.sys),
+      // the audit log records the caller of each check
+      enable_stack_trace_arg_in_ops: has_trace_permissions_enabled(&shared.sys)
+        || has_permission_audit_log(),
       unconfigured_runtime,
     };
 
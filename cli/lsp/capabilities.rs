@@ -20,6 +20,13 @@ pub static INFERRED_TYPE_CODE_ACTION_KIND: LazyLock<CodeActionKind> =
       .into()
   });
 
+pub static SOURCE_FIX_ALL_DENO_CODE_ACTION_KIND: LazyLock<CodeActionKind> =
+  LazyLock::new(|| {
+    [CodeActionKind::SOURCE_FIX_ALL.as_str(), "deno"]
+      .join(".")
+      .into()
+  });
+
 fn code_action_capabilities(
   client_capabilities: &ClientCapabilities,
 ) -> CodeActionProviderCapability {
@@ -34,6 +41,7 @@ fn code_action_capabilities(
         CodeActionKind::REFACTOR,
         INFERRED_TYPE_CODE_ACTION_KIND.clone(),
         CodeActionKind::SOURCE_ORGANIZE_IMPORTS,
+        SOURCE_FIX_ALL_DENO_CODE_ACTION_KIND.clone(),
       ];
       code_action_kinds.extend(
         ALL_KNOWN_REFACTOR_ACTION_KINDS
@@ -162,14 +170,24 @@ pub fn server_capabilities(
       resolve_provider: Some(true),
     }),
     document_formatting_provider: Some(OneOf::Left(true)),
-    document_range_formatting_provider: None,
-    document_on_type_formatting_provider: None,
+    document_range_formatting_provider: Some(OneOf::Left(true)),
+    document_on_type_formatting_provider: Some(
+      DocumentOnTypeFormattingOptions {
+        first_trigger_character: "}".to_string(),
+        more_trigger_character: Some(vec![";".to_string()]),
+      },
+    ),
     selection_range_provider: Some(SelectionRangeProviderCapability::Simple(
       true,
     )),
     folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
     rename_provider: Some(OneOf::Left(true)),
-    document_link_provider: None,
+    document_link_provider: Some(DocumentLinkOptions {
+      resolve_provider: Some(false),
+      work_done_progress_options: WorkDoneProgressOptions {
+        work_done_progress: None,
+      },
+    }),
     color_provider: None,
     execute_command_provider: Some(ExecuteCommandOptions {
       commands: vec![
@@ -201,7 +219,9 @@ pub fn server_capabilities(
       }),
       file_operations: None,
     }),
-    linked_editing_range_provider: None,
+    linked_editing_range_provider: Some(
+      LinkedEditingRangeServerCapabilities::Simple(true),
+    ),
     moniker_provider: None,
     experimental: Some(json!({
       "denoConfigTasks": true,
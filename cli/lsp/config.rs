@@ -1195,6 +1195,16 @@ impl Config {
     .unwrap_or(false)
   }
 
+  /// The server capabilities have no field for type hierarchy, so it can
+  /// only be provided to clients which register it dynamically.
+  pub fn type_hierarchy_capable(&self) -> bool {
+    (|| {
+      let text_document = self.client_capabilities.text_document.as_ref()?;
+      text_document.type_hierarchy.as_ref()?.dynamic_registration
+    })()
+    .unwrap_or(false)
+  }
+
   /// Whether or not the client supports pull-based diagnostics.
   pub fn diagnostic_capable(&self) -> bool {
     (|| {
@@ -465,6 +465,32 @@ pub fn ts_json_to_diagnostics(
     .collect()
 }
 
+/// The lint diagnostics of a module, or none if linting is disabled for it.
+pub fn generate_module_lint_diagnostics(
+  module: &DocumentModule,
+  snapshot: &StateSnapshot,
+  token: CancellationToken,
+) -> Arc<Vec<lsp::Diagnostic>> {
+  // TODO(nayeemrmn): Support linting notebooks cells. Will require
+  // stitching cells from the same notebook into one module, linting it
+  // and then splitting/relocating the diagnostics to each cell.
+  if token.is_cancelled()
+    || module.notebook_uri.is_some()
+    || module.specifier.scheme() != "file"
+    || snapshot.resolver.in_node_modules(&module.specifier)
+  {
+    return Default::default();
+  }
+  let settings = snapshot
+    .config
+    .workspace_settings_for_specifier(&module.specifier);
+  if !settings.lint {
+    return Default::default();
+  }
+  let linter = snapshot.linter_resolver.for_module(module);
+  generate_document_lint_diagnostics(module, &linter, token).unwrap_or_default()
+}
+
 /// Generates lint diagnostics for a module, caching them on the module
 /// instance. Lint diagnostics depend only on the module's own contents, so a
 /// given module instance always produces the same result and can be cached.
@@ -1812,27 +1838,7 @@ pub async fn generate_module_diagnostics(
     let snapshot = snapshot.clone();
     let module = module.clone();
     let token = token.clone();
-    move || -> Arc<Vec<lsp::Diagnostic>> {
-      // TODO(nayeemrmn): Support linting notebooks cells. Will require
-      // stitching cells from the same notebook into one module, linting it
-      // and then splitting/relocating the diagnostics to each cell.
-      if token.is_cancelled()
-        || module.notebook_uri.is_some()
-        || module.specifier.scheme() != "file"
-        || snapshot.resolver.in_node_modules(&module.specifier)
-      {
-        return Default::default();
-      }
-      let settings = snapshot
-        .config
-        .workspace_settings_for_specifier(&module.specifier);
-      if !settings.lint {
-        return Default::default();
-      }
-      let linter = snapshot.linter_resolver.for_module(&module);
-      generate_document_lint_diagnostics(&module, &linter, token)
-        .unwrap_or_default()
-    }
+    move || generate_module_lint_diagnostics(&module, &snapshot, token)
   });
 
   let doc_handle = tokio::task::spawn_blocking({
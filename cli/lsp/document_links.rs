@@ -0,0 +1,340 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! `textDocument/documentLink` for the import specifiers of a module and for
+//! the dependencies listed in `deno.json`, import maps and `package.json`.
+//!
+//! A link opens the cached source a specifier resolves to. `jsr:` and `npm:`
+//! specifiers which aren't cached yet link to their package page instead.
+
+use deno_core::url::Url;
+use deno_graph::Resolution;
+use deno_resolver::deno_json::CompilerOptionsKey;
+use deno_semver::jsr::JsrPackageReqReference;
+use deno_semver::npm::NpmPackageReqReference;
+use jsonc_parser::CollectOptions;
+use jsonc_parser::CommentCollectionStrategy;
+use jsonc_parser::ParseOptions;
+use jsonc_parser::ast::Object;
+use jsonc_parser::ast::Value as JsoncValue;
+use jsonc_parser::common::Ranged;
+use lsp_types::Uri;
+use node_resolver::NodeResolutionKind;
+use node_resolver::ResolutionMode;
+use tower_lsp::lsp_types as lsp;
+
+use super::config::ConfigWatchedFileType;
+use super::documents::DocumentModule;
+use super::documents::DocumentModules;
+use super::language_server::to_lsp_range;
+use super::resolver::LspResolver;
+use super::text::LineIndex;
+use super::urls::url_to_uri;
+use crate::args::jsr_url;
+
+const PACKAGE_JSON_DEPENDENCY_KEYS: [&str; 4] = [
+  "dependencies",
+  "devDependencies",
+  "peerDependencies",
+  "optionalDependencies",
+];
+
+/// Resolves specifiers to link targets.
+pub struct LinkResolver<'a> {
+  pub document_modules: &'a DocumentModules,
+  pub resolver: &'a LspResolver,
+  pub scope: Option<&'a Url>,
+  pub compiler_options_key: Option<&'a CompilerOptionsKey>,
+}
+
+impl LinkResolver<'_> {
+  fn link_target(
+    &self,
+    specifier: &Url,
+    referrer: &Url,
+    resolution_mode: ResolutionMode,
+  ) -> Option<(Uri, Option<String>)> {
+    match specifier.scheme() {
+      "npm" => {
+        let req_ref = NpmPackageReqReference::from_specifier(specifier).ok()?;
+        let resolved = self
+          .resolver
+          .get_scoped_resolver(self.scope)
+          .npm_to_file_url(
+            &req_ref,
+            referrer,
+            NodeResolutionKind::Execution,
+            resolution_mode,
+          )
+          .and_then(|(url, _)| url_to_uri(&url).ok());
+        match resolved {
+          Some(uri) => Some((uri, None)),
+          None => npm_package_page(&req_ref.req().name),
+        }
+      }
+      "jsr" => {
+        let req_ref = JsrPackageReqReference::from_specifier(specifier).ok()?;
+        match self.cached_module_uri(specifier) {
+          Some(uri) => Some((uri, None)),
+          None => jsr_package_page(&req_ref.req().name),
+        }
+      }
+      "http" | "https" => match self.cached_module_uri(specifier) {
+        Some(uri) => Some((uri, None)),
+        None => Some((url_to_uri(specifier).ok()?, None)),
+      },
+      "file" => Some((url_to_uri(specifier).ok()?, None)),
+      _ => None,
+    }
+  }
+
+  fn cached_module_uri(&self, specifier: &Url) -> Option<Uri> {
+    let module = self.document_modules.module_for_specifier(
+      specifier,
+      self.scope,
+      self.compiler_options_key,
+    )?;
+    Some(module.uri.as_ref().clone())
+  }
+}
+
+fn jsr_package_page(name: &str) -> Option<(Uri, Option<String>)> {
+  let url = jsr_url().join(name).ok()?;
+  Some((url_to_uri(&url).ok()?, Some(format!("Open {name} on JSR"))))
+}
+
+fn npm_package_page(name: &str) -> Option<(Uri, Option<String>)> {
+  let url =
+    Url::parse(&format!("https://www.npmjs.com/package/{name}")).ok()?;
+  Some((url_to_uri(&url).ok()?, Some(format!("Open {name} on npm"))))
+}
+
+/// Links for the import specifiers of a module, including `@deno-types`
+/// and `@ts-types` pragmas.
+pub fn module_document_links(
+  module: &DocumentModule,
+  link_resolver: &LinkResolver,
+) -> Vec<lsp::DocumentLink> {
+  let mut links = Vec::<lsp::DocumentLink>::new();
+  let mut push_link = |range: &deno_graph::Range, specifier: &Url| {
+    let Some((target, tooltip)) = link_resolver.link_target(
+      specifier,
+      &module.specifier,
+      module.resolution_mode,
+    ) else {
+      return;
+    };
+    let range = narrow_quoted_range(
+      &module.text,
+      &module.line_index,
+      to_lsp_range(range),
+    );
+    if links.iter().any(|link| link.range == range) {
+      return;
+    }
+    links.push(lsp::DocumentLink {
+      range,
+      target: Some(target),
+      tooltip,
+      data: None,
+    });
+  };
+  for dependency in module.dependencies.values() {
+    let Some(specifier) = dependency
+      .get_code()
+      .or_else(|| dependency.maybe_type.maybe_specifier())
+    else {
+      continue;
+    };
+    for import in &dependency.imports {
+      push_link(&import.specifier_range, specifier);
+    }
+    if let Resolution::Ok(resolved) = &dependency.maybe_type
+      && !dependency
+        .imports
+        .iter()
+        .any(|i| i.specifier_range == resolved.range)
+    {
+      push_link(&resolved.range, &resolved.specifier);
+    }
+  }
+  links
+}
+
+/// Links for the dependencies in a config file: the `imports` and `scopes` of
+/// `deno.json` and import maps, and the dependency fields of `package.json`.
+pub fn config_document_links(
+  file_type: ConfigWatchedFileType,
+  config_url: &Url,
+  text: &str,
+  line_index: &LineIndex,
+  link_resolver: &LinkResolver,
+) -> Vec<lsp::DocumentLink> {
+  let Ok(parse_result) = jsonc_parser::parse_to_ast(
+    text,
+    &CollectOptions {
+      comments: CommentCollectionStrategy::Off,
+      tokens: false,
+    },
+    &ParseOptions {
+      allow_comments: true,
+      allow_trailing_commas: true,
+      allow_loose_object_property_names: false,
+      ..Default::default()
+    },
+  ) else {
+    return Vec::new();
+  };
+  let Some(root) = parse_result.value.as_ref().and_then(|v| v.as_object())
+  else {
+    return Vec::new();
+  };
+
+  let mut links = Vec::new();
+  let mut push_link =
+    |range: jsonc_parser::common::Range,
+     target: Option<(Uri, Option<String>)>| {
+      let Some((target, tooltip)) = target else {
+        return;
+      };
+      let (Some(start), Some(end)) = (
+        line_index.position_utf8((range.start as u32 + 1).into()),
+        line_index.position_utf8((range.end as u32 - 1).into()),
+      ) else {
+        return;
+      };
+      links.push(lsp::DocumentLink {
+        range: lsp::Range { start, end },
+        target: Some(target),
+        tooltip,
+        data: None,
+      });
+    };
+
+  match file_type {
+    ConfigWatchedFileType::DenoJson | ConfigWatchedFileType::ImportMap => {
+      let mut import_objects = Vec::new();
+      if let Some(imports) = root.get_object("imports") {
+        import_objects.push(imports);
+      }
+      if let Some(scopes) = root.get_object("scopes") {
+        import_objects.extend(scopes.properties.iter().filter_map(|p| {
+          match &p.value {
+            JsoncValue::Object(o) => Some(o),
+            _ => None,
+          }
+        }));
+      }
+      for imports in import_objects {
+        for (value, range) in string_values(imports) {
+          // Directory mappings have nothing to open.
+          if value.ends_with('/') {
+            continue;
+          }
+          let Ok(specifier) = config_url.join(value) else {
+            continue;
+          };
+          push_link(
+            range,
+            link_resolver.link_target(
+              &specifier,
+              config_url,
+              ResolutionMode::Import,
+            ),
+          );
+        }
+      }
+    }
+    ConfigWatchedFileType::PackageJson => {
+      for key in PACKAGE_JSON_DEPENDENCY_KEYS {
+        let Some(dependencies) = root.get_object(key) else {
+          continue;
+        };
+        for prop in &dependencies.properties {
+          let JsoncValue::StringLit(value) = &prop.value else {
+            continue;
+          };
+          let name = prop.name.as_str();
+          let value_text = value.value.as_ref();
+          let target = if value_text.starts_with("npm:")
+            || value_text.starts_with("jsr:")
+          {
+            Url::parse(value_text).ok().and_then(|specifier| {
+              link_resolver.link_target(
+                &specifier,
+                config_url,
+                ResolutionMode::Import,
+              )
+            })
+          } else if value_text.contains(':') {
+            // `file:`, `link:`, `workspace:` and git dependencies.
+            None
+          } else {
+            Url::parse(&format!("npm:{name}@{value_text}"))
+              .ok()
+              .and_then(|specifier| {
+                link_resolver.link_target(
+                  &specifier,
+                  config_url,
+                  ResolutionMode::Import,
+                )
+              })
+              .or_else(|| npm_package_page(name))
+          };
+          push_link(value.range(), target);
+        }
+      }
+    }
+    ConfigWatchedFileType::Lockfile | ConfigWatchedFileType::NpmRc => {}
+  }
+  links
+}
+
+fn string_values<'a, 'b>(
+  object: &'b Object<'a>,
+) -> impl Iterator<Item = (&'b str, jsonc_parser::common::Range)> {
+  object
+    .properties
+    .iter()
+    .filter_map(|prop| match &prop.value {
+      JsoncValue::StringLit(s) => Some((s.value.as_ref(), s.range())),
+      _ => None,
+    })
+}
+
+/// Excludes the quotes around a string literal from its range.
+fn narrow_quoted_range(
+  text: &str,
+  line_index: &LineIndex,
+  range: lsp::Range,
+) -> lsp::Range {
+  let is_quote = |position: lsp::Position, back: bool| {
+    line_index.offset(position).ok().is_some_and(|offset| {
+      let offset = usize::from(offset);
+      let offset = if back {
+        offset.checked_sub(1)
+      } else {
+        Some(offset)
+      };
+      offset
+        .and_then(|o| text.as_bytes().get(o))
+        .is_some_and(|b| matches!(b, b'"' | b'\'' | b'`'))
+    })
+  };
+  if range.start.line != range.end.line
+    || range.end.character < range.start.character + 2
+    || !is_quote(range.start, false)
+    || !is_quote(range.end, true)
+  {
+    return range;
+  }
+  lsp::Range {
+    start: lsp::Position {
+      line: range.start.line,
+      character: range.start.character + 1,
+    },
+    end: lsp::Position {
+      line: range.end.line,
+      character: range.end.character - 1,
+    },
+  }
+}
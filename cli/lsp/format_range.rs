@@ -0,0 +1,240 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Range and on-type formatting. The whole document is formatted and the
+//! edits are narrowed down to the lines of the statements the range covers,
+//! so the result matches formatting the whole document and the rest of it is
+//! left untouched.
+
+use std::ops::Range;
+use std::ops::RangeInclusive;
+
+use deno_ast::ParsedSource;
+use deno_ast::SourceRangedForSpanned;
+use deno_ast::StartSourcePos;
+use deno_ast::swc::ast;
+use deno_ast::swc::ecma_visit::Visit;
+use deno_ast::swc::ecma_visit::VisitWith;
+use tower_lsp::lsp_types as lsp;
+
+use super::documents::DocumentModule;
+
+#[derive(Debug, Clone, Copy)]
+pub enum FormatRange {
+  /// A range selected with "format selection".
+  Selection(lsp::Range),
+  /// The position after a typed trigger character.
+  OnType(lsp::Position),
+}
+
+/// The lines whose formatting edits apply to the range, or `None` if nothing
+/// in it can be formatted.
+pub fn format_line_range(
+  module: &DocumentModule,
+  range: FormatRange,
+) -> Option<RangeInclusive<u32>> {
+  let Some(parsed_source) = module
+    .open_data
+    .as_ref()
+    .and_then(|d| d.parsed_source.as_ref())
+  else {
+    // Without a syntax tree, e.g. for Markdown, narrow the edits down to the
+    // selected lines.
+    return match range {
+      FormatRange::Selection(range) => {
+        let end_line =
+          if range.end.character == 0 && range.end.line > range.start.line {
+            range.end.line - 1
+          } else {
+            range.end.line
+          };
+        Some(range.start.line..=end_line)
+      }
+      FormatRange::OnType(_) => None,
+    };
+  };
+  let parsed_source = parsed_source.as_ref().ok()?;
+  let selection = match range {
+    FormatRange::Selection(range) => {
+      let range = module.line_index.get_text_range(range).ok()?;
+      usize::from(range.start())..usize::from(range.end())
+    }
+    FormatRange::OnType(position) => {
+      let offset = usize::from(module.line_index.offset(position).ok()?);
+      offset..offset
+    }
+  };
+  let statements = statements_byte_range(parsed_source, selection)?;
+  let text = parsed_source.text();
+  let line_of = |offset: usize| text[..offset].matches('\n').count() as u32;
+  Some(line_of(statements.start)..=line_of(statements.end))
+}
+
+/// Keeps the edits that only touch the given lines.
+pub fn edits_within_lines(
+  edits: Vec<lsp::TextEdit>,
+  lines: &RangeInclusive<u32>,
+) -> Vec<lsp::TextEdit> {
+  edits
+    .into_iter()
+    .filter(|edit| {
+      let end = edit.range.end;
+      lines.contains(&edit.range.start.line)
+        && (lines.contains(&end.line)
+          || (end.line == lines.end() + 1 && end.character == 0))
+    })
+    .collect()
+}
+
+/// Collects the byte ranges of the items of every statement list: module and
+/// script bodies, blocks, switch cases and class bodies.
+struct StatementListCollector {
+  start_pos: StartSourcePos,
+  lists: Vec<Vec<Range<usize>>>,
+}
+
+impl StatementListCollector {
+  fn push_list<'a, T: SourceRangedForSpanned + 'a>(
+    &mut self,
+    items: impl Iterator<Item = &'a T>,
+  ) {
+    let list = items
+      .map(|item| item.range().as_byte_range(self.start_pos))
+      .collect::<Vec<_>>();
+    if !list.is_empty() {
+      self.lists.push(list);
+    }
+  }
+}
+
+impl Visit for StatementListCollector {
+  fn visit_module_items(&mut self, items: &[ast::ModuleItem]) {
+    self.push_list(items.iter());
+    items.visit_children_with(self);
+  }
+
+  fn visit_stmts(&mut self, stmts: &[ast::Stmt]) {
+    self.push_list(stmts.iter());
+    stmts.visit_children_with(self);
+  }
+
+  fn visit_class_members(&mut self, members: &[ast::ClassMember]) {
+    self.push_list(members.iter());
+    members.visit_children_with(self);
+  }
+}
+
+/// The byte range of the statements the selection covers, taken from the
+/// innermost statement list that covers all of it.
+fn statements_byte_range(
+  parsed_source: &ParsedSource,
+  selection: Range<usize>,
+) -> Option<Range<usize>> {
+  // Whole-line selections start and end in indentation, which shouldn't
+  // widen the selection to the enclosing statement.
+  let text = parsed_source.text();
+  let selected = text.get(selection.clone())?;
+  let start = selection.start + (selected.len() - selected.trim_start().len());
+  let end =
+    (selection.end - (selected.len() - selected.trim_end().len())).max(start);
+
+  let mut collector = StatementListCollector {
+    start_pos: parsed_source.start_pos(),
+    lists: Vec::new(),
+  };
+  parsed_source.program_ref().visit_with(&mut collector);
+  collector
+    .lists
+    .iter()
+    .filter_map(|list| {
+      let mut covered: Option<Range<usize>> = None;
+      for item in list {
+        if item.start <= end && start <= item.end {
+          covered = Some(match covered {
+            Some(covered) => covered.start..item.end,
+            None => item.clone(),
+          });
+        }
+      }
+      covered.filter(|covered| covered.start <= start && end <= covered.end)
+    })
+    .min_by_key(|covered| covered.end - covered.start)
+}
+
+#[cfg(test)]
+mod tests {
+  use deno_ast::MediaType;
+  use deno_core::resolve_url;
+
+  use super::*;
+
+  fn parse(source: &str) -> ParsedSource {
+    deno_ast::parse_module(deno_ast::ParseParams {
+      specifier: resolve_url("file:///a/example.ts").unwrap(),
+      text: source.into(),
+      media_type: MediaType::TypeScript,
+      capture_tokens: true,
+      scope_analysis: false,
+      maybe_syntax: None,
+    })
+    .unwrap()
+  }
+
+  fn statements_text(source: &str, selected: &str) -> Option<String> {
+    let parsed_source = parse(source);
+    let start = source.find(selected).unwrap();
+    statements_byte_range(&parsed_source, start..start + selected.len())
+      .map(|range| source[range].to_string())
+  }
+
+  #[test]
+  fn test_statements_byte_range() {
+    let source = "const a=1;\nfunction f() {\n  let b=2;\n  let c=3;\n  return b+c;\n}\nconst d=4;\n";
+    // A selection inside a function body only covers its statements.
+    assert_eq!(
+      statements_text(source, "b=2;\n  let c").as_deref(),
+      Some("let b=2;\n  let c=3;")
+    );
+    // Whole lines, including indentation.
+    assert_eq!(
+      statements_text(source, "  let c=3;\n").as_deref(),
+      Some("let c=3;")
+    );
+    // A selection leaving the function covers it as a whole.
+    assert_eq!(
+      statements_text(source, "return b+c;\n}\nconst").as_deref(),
+      Some(&source[11..source.len() - 1])
+    );
+  }
+
+  #[test]
+  fn test_statements_byte_range_on_type() {
+    let source = "if (a) {\n  b( );\n}\nc( );\n";
+    let parsed_source = parse(source);
+    let offset = source.find("}").unwrap() + 1;
+    assert_eq!(
+      statements_byte_range(&parsed_source, offset..offset),
+      Some(0..offset)
+    );
+    let offset = source.find("b( );").unwrap() + 5;
+    assert_eq!(
+      statements_byte_range(&parsed_source, offset..offset),
+      Some(11..offset)
+    );
+  }
+
+  #[test]
+  fn test_edits_within_lines() {
+    let edit = |start_line, end_line| lsp::TextEdit {
+      range: lsp::Range::new(
+        lsp::Position::new(start_line, 0),
+        lsp::Position::new(end_line, 0),
+      ),
+      new_text: String::new(),
+    };
+    let edits = edits_within_lines(
+      vec![edit(0, 1), edit(2, 3), edit(3, 5), edit(4, 5)],
+      &(2..=3),
+    );
+    assert_eq!(edits, vec![edit(2, 3)]);
+  }
+}
@@ -63,6 +63,7 @@ use super::analysis::ts_changes_to_edit;
 use super::cache::LspCache;
 use super::capabilities;
 use super::capabilities::INFERRED_TYPE_CODE_ACTION_KIND;
+use super::capabilities::SOURCE_FIX_ALL_DENO_CODE_ACTION_KIND;
 use super::capabilities::semantic_tokens_registration_options;
 use super::client::Client;
 use super::code_lens;
@@ -74,10 +75,16 @@ use super::diagnostics;
 use super::diagnostics::DiagnosticDataSpecifier;
 use super::diagnostics::DiagnosticsServer;
 use super::diagnostics::DiagnosticsUpdateMessage;
+use super::document_links::LinkResolver;
+use super::document_links::config_document_links;
+use super::document_links::module_document_links;
 use super::documents::Document;
 use super::documents::DocumentModule;
 use super::documents::DocumentModules;
 use super::documents::LanguageId;
+use super::format_range::FormatRange;
+use super::format_range::edits_within_lines;
+use super::format_range::format_line_range;
 use super::jsr::CliJsrSearchApi;
 use super::logging::lsp_log;
 use super::logging::lsp_warn;
@@ -113,8 +120,12 @@ use crate::lsp::config::ConfigWatchedFileType;
 use crate::lsp::diagnostics::DenoDiagnostic;
 use crate::lsp::diagnostics::generate_import_map_diagnostics;
 use crate::lsp::diagnostics::generate_module_diagnostics;
+use crate::lsp::diagnostics::generate_module_lint_diagnostics;
 use crate::lsp::lint::LspLinterResolver;
 use crate::lsp::lint::get_deno_lint_code_actions;
+use crate::lsp::lint::get_deno_lint_fix_all_action;
+use crate::lsp::lint::get_deno_lint_source_fix_all_action;
+use crate::lsp::lint::get_deno_lint_workspace_fix_all_action;
 use crate::lsp::logging::init_log_file;
 use crate::sys::CliSys;
 use crate::tools::fmt::format_file;
@@ -740,6 +751,33 @@ impl Inner {
     Ok(Some(module))
   }
 
+  /// The lint diagnostics of the open documents, skipping those which aren't
+  /// linted or have no lint problems.
+  fn open_docs_lint_diagnostics(
+    &self,
+    token: &CancellationToken,
+  ) -> Vec<(Uri, Arc<Vec<Diagnostic>>)> {
+    let snapshot = self.snapshot();
+    self
+      .document_modules
+      .documents
+      .open_docs()
+      .filter(|doc| doc.is_diagnosable())
+      .filter_map(|doc| {
+        let module = self
+          .document_modules
+          .primary_module(&Document::Open(doc.clone()))?;
+        if !self.config.specifier_enabled(&module.specifier) {
+          return None;
+        }
+        let diagnostics =
+          generate_module_lint_diagnostics(&module, &snapshot, token.clone());
+        (!diagnostics.is_empty())
+          .then(|| (doc.uri.as_ref().clone(), diagnostics))
+      })
+      .collect()
+  }
+
   #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
   pub fn snapshot(&self) -> Arc<StateSnapshot> {
     Arc::new(StateSnapshot {
@@ -1820,6 +1858,64 @@ impl Inner {
     self.performance.measure(mark);
   }
 
+  #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
+  fn document_link(
+    &self,
+    params: DocumentLinkParams,
+  ) -> LspResult<Option<Vec<DocumentLink>>> {
+    let mark = self
+      .performance
+      .mark_with_args("lsp.document_link", &params);
+    let Some(document) = self.get_document(
+      &params.text_document.uri,
+      Enabled::Filter,
+      Exists::Enforce,
+      Diagnosable::Ignore,
+    )?
+    else {
+      return Ok(None);
+    };
+    let url = uri_to_url(document.uri());
+    let links = if let Some((scope, file_type)) =
+      self.config.tree.watched_file_type(&url)
+    {
+      config_document_links(
+        file_type,
+        &url,
+        &document.text(),
+        document.line_index(),
+        &LinkResolver {
+          document_modules: &self.document_modules,
+          resolver: &self.resolver,
+          scope: Some(scope),
+          compiler_options_key: None,
+        },
+      )
+    } else {
+      if !document.is_diagnosable() {
+        return Ok(None);
+      }
+      let Some(module) = self.get_primary_module(&document)? else {
+        return Ok(None);
+      };
+      module_document_links(
+        &module,
+        &LinkResolver {
+          document_modules: &self.document_modules,
+          resolver: &self.resolver,
+          scope: module.scope.as_deref(),
+          compiler_options_key: Some(&module.compiler_options_key),
+        },
+      )
+    };
+    self.performance.measure(mark);
+    if links.is_empty() {
+      Ok(None)
+    } else {
+      Ok(Some(links))
+    }
+  }
+
   #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
   async fn document_symbol(
     &self,
@@ -1867,8 +1963,63 @@ impl Inner {
     _token: &CancellationToken,
   ) -> LspResult<Option<Vec<TextEdit>>> {
     let mark = self.performance.mark_with_args("lsp.formatting", &params);
+    let text_edits = self
+      .format_document(&params.text_document.uri, &params.options, None)
+      .await?;
+    self.performance.measure(mark);
+    Ok(text_edits)
+  }
+
+  #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
+  async fn range_formatting(
+    &self,
+    params: DocumentRangeFormattingParams,
+    _token: &CancellationToken,
+  ) -> LspResult<Option<Vec<TextEdit>>> {
+    let mark = self
+      .performance
+      .mark_with_args("lsp.range_formatting", &params);
+    let text_edits = self
+      .format_document(
+        &params.text_document.uri,
+        &params.options,
+        Some(FormatRange::Selection(params.range)),
+      )
+      .await?;
+    self.performance.measure(mark);
+    Ok(text_edits)
+  }
+
+  #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
+  async fn on_type_formatting(
+    &self,
+    params: DocumentOnTypeFormattingParams,
+    _token: &CancellationToken,
+  ) -> LspResult<Option<Vec<TextEdit>>> {
+    let mark = self
+      .performance
+      .mark_with_args("lsp.on_type_formatting", &params);
+    let text_edits = self
+      .format_document(
+        &params.text_document_position.text_document.uri,
+        &params.options,
+        Some(FormatRange::OnType(params.text_document_position.position)),
+      )
+      .await?;
+    self.performance.measure(mark);
+    Ok(text_edits)
+  }
+
+  /// Formats the document, keeping only the edits for the statements `range`
+  /// covers when it is set.
+  async fn format_document(
+    &self,
+    uri: &Uri,
+    options: &FormattingOptions,
+    range: Option<FormatRange>,
+  ) -> LspResult<Option<Vec<TextEdit>>> {
     let Some(document) = self.get_document(
-      &params.text_document.uri,
+      uri,
       Enabled::Ignore,
       Exists::Enforce,
       Diagnosable::Ignore,
@@ -1883,15 +2034,17 @@ impl Inner {
       self.config.tree.fmt_config_for_specifier(&module.specifier);
     // Untitled files are exempt from enabled-checks because they tend not to
     // have meaningful paths, and they won't be auto-formatted on save anyway.
-    let is_untitled = params
-      .text_document
-      .uri
-      .scheme()
-      .as_str()
-      .eq_ignore_ascii_case("untitled");
+    let is_untitled = uri.scheme().as_str().eq_ignore_ascii_case("untitled");
     if !is_untitled && !fmt_config.files.matches_specifier(&module.specifier) {
       return Ok(None);
     }
+    let line_range = match range {
+      Some(range) => match format_line_range(&module, range) {
+        Some(line_range) => Some(line_range),
+        None => return Ok(None),
+      },
+      None => None,
+    };
     let file_path = url_to_file_path(&module.specifier)
       .or_else(|_| uri_to_file_path(document.uri()))
       .map_err(|err| {
@@ -1908,8 +2061,8 @@ impl Inner {
         reason = "clippy's suggestion is more confusing"
       )]
       if !config_data.is_some_and(|d| d.maybe_deno_json().is_some()) {
-        fmt_options.use_tabs = Some(!params.options.insert_spaces);
-        fmt_options.indent_width = Some(params.options.tab_size as u8);
+        fmt_options.use_tabs = Some(!options.insert_spaces);
+        fmt_options.indent_width = Some(options.tab_size as u8);
       }
       let unstable_options = UnstableFmtOptions {
         component: config_data
@@ -1962,7 +2115,12 @@ impl Inner {
     .await
     .unwrap();
 
-    self.performance.measure(mark);
+    let text_edits = match (text_edits, &line_range) {
+      (Some(text_edits), Some(line_range)) => {
+        Some(edits_within_lines(text_edits, line_range))
+      }
+      (text_edits, _) => text_edits,
+    };
     if let Some(text_edits) = text_edits {
       if text_edits.is_empty() {
         Ok(None)
@@ -2304,6 +2462,9 @@ impl Inner {
       }
     }
     .shared();
+    let mut lint_diagnostics = None;
+    let mut workspace_lint_diagnostics = None;
+    let mut lint_fix_all_codes = HashSet::new();
     for diagnostic in &params.context.diagnostics {
       match diagnostic.source.as_deref() {
         Some("deno") => {
@@ -2353,6 +2514,29 @@ impl Inner {
               );
             }
           }
+          if let Some(NumberOrString::String(code)) = &diagnostic.code
+            && lint_fix_all_codes.insert(code.clone())
+          {
+            let lint_diagnostics = lint_diagnostics.get_or_insert_with(|| {
+              generate_module_lint_diagnostics(
+                &module,
+                &self.snapshot(),
+                token.clone(),
+              )
+            });
+            deno_lint_actions.extend(get_deno_lint_fix_all_action(
+              document.uri(),
+              diagnostic,
+              lint_diagnostics.as_slice(),
+            ));
+            let workspace_lint_diagnostics = workspace_lint_diagnostics
+              .get_or_insert_with(|| self.open_docs_lint_diagnostics(token));
+            deno_lint_actions.extend(get_deno_lint_workspace_fix_all_action(
+              document.uri(),
+              diagnostic,
+              workspace_lint_diagnostics,
+            ));
+          }
         }
         _ => {}
       }
@@ -2406,6 +2590,25 @@ impl Inner {
         }
       }
     }
+    if params.context.only.as_ref().is_some_and(|only| {
+      only.iter().any(|kind| {
+        SOURCE_FIX_ALL_DENO_CODE_ACTION_KIND
+          .as_str()
+          .starts_with(kind.as_str())
+      })
+    }) {
+      let lint_diagnostics = lint_diagnostics.get_or_insert_with(|| {
+        generate_module_lint_diagnostics(
+          &module,
+          &self.snapshot(),
+          token.clone(),
+        )
+      });
+      deno_lint_actions.extend(get_deno_lint_source_fix_all_action(
+        document.uri(),
+        lint_diagnostics.as_slice(),
+      ));
+    }
     let ts_actions = self
       .ts_server
       .provide_code_actions(
@@ -3414,6 +3617,133 @@ impl Inner {
     Ok(items)
   }
 
+  #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
+  async fn prepare_type_hierarchy(
+    &self,
+    params: TypeHierarchyPrepareParams,
+    token: &CancellationToken,
+  ) -> LspResult<Option<Vec<TypeHierarchyItem>>> {
+    let mark = self
+      .performance
+      .mark_with_args("lsp.prepare_type_hierarchy", &params);
+    let Some(document) = self.get_document(
+      &params.text_document_position_params.text_document.uri,
+      Enabled::Filter,
+      Exists::Enforce,
+      Diagnosable::Filter,
+    )?
+    else {
+      return Ok(None);
+    };
+    let Some(module) = self.get_primary_module(&document)? else {
+      return Ok(None);
+    };
+    let items = self
+      .ts_server
+      .provide_prepare_type_hierarchy(
+        &module,
+        params.text_document_position_params.position,
+        &self.snapshot(),
+        token,
+      )
+      .await
+      .map_err(|err| {
+        if token.is_cancelled() {
+          LspError::request_cancelled()
+        } else {
+          error!("Unable to get type hierarchy from TypeScript: {:#}", err);
+          LspError::internal_error()
+        }
+      })?;
+    self.performance.measure(mark);
+    Ok(items)
+  }
+
+  #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
+  async fn type_hierarchy_supertypes(
+    &self,
+    params: TypeHierarchySupertypesParams,
+    token: &CancellationToken,
+  ) -> LspResult<Option<Vec<TypeHierarchyItem>>> {
+    let mark = self
+      .performance
+      .mark_with_args("lsp.type_hierarchy_supertypes", &params);
+    let Some(document) = self.get_document(
+      &params.item.uri,
+      Enabled::Filter,
+      Exists::Enforce,
+      Diagnosable::Filter,
+    )?
+    else {
+      return Ok(None);
+    };
+    let Some(module) = self.get_primary_module(&document)? else {
+      return Ok(None);
+    };
+    let supertypes = self
+      .ts_server
+      .provide_type_hierarchy_supertypes(
+        &module,
+        &params.item,
+        &self.snapshot(),
+        token,
+      )
+      .await
+      .map_err(|err| {
+        if token.is_cancelled() {
+          LspError::request_cancelled()
+        } else {
+          error!("Unable to get supertypes from TypeScript: {:#}", err);
+          LspError::internal_error()
+        }
+      })?;
+    self.performance.measure(mark);
+    Ok(supertypes)
+  }
+
+  #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
+  async fn type_hierarchy_subtypes(
+    &self,
+    params: TypeHierarchySubtypesParams,
+    token: &CancellationToken,
+  ) -> LspResult<Option<Vec<TypeHierarchyItem>>> {
+    let mark = self
+      .performance
+      .mark_with_args("lsp.type_hierarchy_subtypes", &params);
+    let Some(document) = self.get_document(
+      &params.item.uri,
+      Enabled::Filter,
+      Exists::Enforce,
+      Diagnosable::Filter,
+    )?
+    else {
+      return Ok(None);
+    };
+    let Some(module) = self.get_primary_module(&document)? else {
+      return Ok(None);
+    };
+    let subtypes = self
+      .ts_server
+      .provide_type_hierarchy_subtypes(
+        &document,
+        &module,
+        &params.item,
+        &self.snapshot(),
+        token,
+      )
+      .await
+      .map_err(|err| {
+        if token.is_cancelled() {
+          LspError::request_cancelled()
+        } else {
+          error!("Unable to get subtypes from TypeScript: {:#}", err);
+          LspError::internal_error()
+        }
+      })?;
+    self.performance.measure(mark);
+    Ok(subtypes)
+  }
+
   #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
   async fn rename(
     &self,
@@ -3499,6 +3829,54 @@ impl Inner {
     Ok(selection_ranges)
   }
 
+  #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
+  async fn linked_editing_range(
+    &self,
+    params: LinkedEditingRangeParams,
+    token: &CancellationToken,
+  ) -> LspResult<Option<LinkedEditingRanges>> {
+    let mark = self
+      .performance
+      .mark_with_args("lsp.linked_editing_range", &params);
+    let Some(document) = self.get_document(
+      &params.text_document_position_params.text_document.uri,
+      Enabled::Filter,
+      Exists::Enforce,
+      Diagnosable::Filter,
+    )?
+    else {
+      return Ok(None);
+    };
+    let Some(module) = self.get_primary_module(&document)? else {
+      return Ok(None);
+    };
+    if !matches!(module.media_type, MediaType::Jsx | MediaType::Tsx) {
+      return Ok(None);
+    }
+    let linked_editing_ranges = self
+      .ts_server
+      .provide_linked_editing_ranges(
+        &module,
+        params.text_document_position_params.position,
+        self.snapshot(),
+        token,
+      )
+      .await
+      .map_err(|err| {
+        if token.is_cancelled() {
+          LspError::request_cancelled()
+        } else {
+          error!(
+            "Unable to get linked editing ranges from TypeScript: {:#}",
+            err
+          );
+          LspError::internal_error()
+        }
+      })?;
+    self.performance.measure(mark);
+    Ok(linked_editing_ranges)
+  }
+
   #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
   async fn semantic_tokens_full(
     &self,
@@ -3935,6 +4313,15 @@ impl tower_lsp::LanguageServer for LanguageServer {
     self.performance.measure(mark);
   }
 
+  async fn document_link(
+    &self,
+    params: DocumentLinkParams,
+    _token: CancellationToken,
+  ) -> LspResult<Option<Vec<DocumentLink>>> {
+    self.init_flag.wait_raised().await;
+    self.inner.read().await.document_link(params)
+  }
+
   async fn document_symbol(
     &self,
     params: DocumentSymbolParams,
@@ -3958,6 +4345,34 @@ impl tower_lsp::LanguageServer for LanguageServer {
     self.inner.read().await.formatting(params, &token).await
   }
 
+  async fn range_formatting(
+    &self,
+    params: DocumentRangeFormattingParams,
+    token: CancellationToken,
+  ) -> LspResult<Option<Vec<TextEdit>>> {
+    self.init_flag.wait_raised().await;
+    self
+      .inner
+      .read()
+      .await
+      .range_formatting(params, &token)
+      .await
+  }
+
+  async fn on_type_formatting(
+    &self,
+    params: DocumentOnTypeFormattingParams,
+    token: CancellationToken,
+  ) -> LspResult<Option<Vec<TextEdit>>> {
+    self.init_flag.wait_raised().await;
+    self
+      .inner
+      .read()
+      .await
+      .on_type_formatting(params, &token)
+      .await
+  }
+
   async fn hover(
     &self,
     params: HoverParams,
@@ -4160,6 +4575,48 @@ impl tower_lsp::LanguageServer for LanguageServer {
       .await
   }
 
+  async fn prepare_type_hierarchy(
+    &self,
+    params: TypeHierarchyPrepareParams,
+    token: CancellationToken,
+  ) -> LspResult<Option<Vec<TypeHierarchyItem>>> {
+    self.init_flag.wait_raised().await;
+    self
+      .inner
+      .read()
+      .await
+      .prepare_type_hierarchy(params, &token)
+      .await
+  }
+
+  async fn supertypes(
+    &self,
+    params: TypeHierarchySupertypesParams,
+    token: CancellationToken,
+  ) -> LspResult<Option<Vec<TypeHierarchyItem>>> {
+    self.init_flag.wait_raised().await;
+    self
+      .inner
+      .read()
+      .await
+      .type_hierarchy_supertypes(params, &token)
+      .await
+  }
+
+  async fn subtypes(
+    &self,
+    params: TypeHierarchySubtypesParams,
+    token: CancellationToken,
+  ) -> LspResult<Option<Vec<TypeHierarchyItem>>> {
+    self.init_flag.wait_raised().await;
+    self
+      .inner
+      .read()
+      .await
+      .type_hierarchy_subtypes(params, &token)
+      .await
+  }
+
   async fn rename(
     &self,
     params: RenameParams,
@@ -4169,6 +4626,20 @@ impl tower_lsp::LanguageServer for LanguageServer {
     self.inner.read().await.rename(params, &token).await
   }
 
+  async fn linked_editing_range(
+    &self,
+    params: LinkedEditingRangeParams,
+    token: CancellationToken,
+  ) -> LspResult<Option<LinkedEditingRanges>> {
+    self.init_flag.wait_raised().await;
+    self
+      .inner
+      .read()
+      .await
+      .linked_editing_range(params, &token)
+      .await
+  }
+
   async fn selection_range(
     &self,
     params: SelectionRangeParams,
@@ -4253,7 +4724,7 @@ struct PrepareCacheResult {
 // These are implementations of custom commands supported by the LSP
 impl Inner {
   async fn initialized(&mut self) -> Vec<Registration> {
-    let mut registrations = Vec::with_capacity(2);
+    let mut registrations = Vec::with_capacity(3);
     init_log_file(self.config.log_file(), &self.initial_cwd);
     self.update_debug_flag();
     self.update_global_cache().await;
@@ -4303,6 +4774,16 @@ impl Inner {
         register_options: Some(serde_json::to_value(options).unwrap()),
       });
     }
+    if self.config.type_hierarchy_capable() {
+      let options = TextDocumentRegistrationOptions {
+        document_selector: None,
+      };
+      registrations.push(Registration {
+        id: "textDocument/prepareTypeHierarchy".to_string(),
+        method: "textDocument/prepareTypeHierarchy".to_string(),
+        register_options: Some(serde_json::to_value(options).unwrap()),
+      });
+    }
 
     if self.config.testing_api_capable() {
       let test_server = testing::TestServer::new(
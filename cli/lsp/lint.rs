@@ -21,6 +21,7 @@ use crate::args::LintFlags;
 use crate::args::LintOptions;
 use crate::lsp::analysis::DataQuickFix;
 use crate::lsp::analysis::prepend_whitespace;
+use crate::lsp::capabilities::SOURCE_FIX_ALL_DENO_CODE_ACTION_KIND;
 use crate::lsp::compiler_options::LspCompilerOptionsResolver;
 use crate::lsp::config::Config;
 use crate::lsp::documents::DocumentModule;
@@ -225,6 +226,148 @@ impl Drop for LoadPluginsThread {
 static LOAD_PLUGINS_THREAD: Lazy<LoadPluginsThread> =
   Lazy::new(LoadPluginsThread::create);
 
+fn lint_code(diagnostic: &lsp::Diagnostic) -> Option<&str> {
+  match diagnostic.code.as_ref()? {
+    lsp::NumberOrString::String(code) => Some(code),
+    lsp::NumberOrString::Number(_) => None,
+  }
+}
+
+fn ranges_overlap(a: &lsp::Range, b: &lsp::Range) -> bool {
+  a == b || (a.start < b.end && b.start < a.end)
+}
+
+/// Collects the first fix of each diagnostic, skipping fixes which overlap
+/// one collected before. Returns the edits and the diagnostics they fix.
+fn collect_lint_fixes<'a>(
+  diagnostics: impl Iterator<Item = &'a lsp::Diagnostic>,
+) -> (Vec<lsp::TextEdit>, Vec<lsp::Diagnostic>) {
+  let mut edits = Vec::<lsp::TextEdit>::new();
+  let mut fixed = Vec::new();
+  for diagnostic in diagnostics {
+    let Some(quick_fix) = diagnostic
+      .data
+      .as_ref()
+      .and_then(|d| serde_json::from_value::<Vec<DataQuickFix>>(d.clone()).ok())
+      .and_then(|quick_fixes| quick_fixes.into_iter().next())
+    else {
+      continue;
+    };
+    if quick_fix.changes.iter().any(|change| {
+      edits
+        .iter()
+        .any(|edit| ranges_overlap(&edit.range, &change.range))
+    }) {
+      continue;
+    }
+    edits.extend(quick_fix.changes.into_iter().map(|change| lsp::TextEdit {
+      new_text: change.new_text,
+      range: change.range,
+    }));
+    fixed.push(diagnostic.clone());
+  }
+  (edits, fixed)
+}
+
+fn lint_fix_all_action(
+  title: String,
+  kind: lsp::CodeActionKind,
+  changes: HashMap<lsp::Uri, Vec<lsp::TextEdit>>,
+  fixed: Vec<lsp::Diagnostic>,
+) -> lsp::CodeAction {
+  lsp::CodeAction {
+    title,
+    kind: Some(kind),
+    diagnostics: Some(fixed),
+    command: None,
+    is_preferred: None,
+    disabled: None,
+    data: None,
+    edit: Some(lsp::WorkspaceEdit {
+      changes: Some(changes),
+      change_annotations: None,
+      document_changes: None,
+    }),
+  }
+}
+
+/// A quick fix applying the fixes of every problem of the diagnostic's rule
+/// in the file, when there is more than one. This covers the rules of lint
+/// plugins as well as the built-in ones.
+pub fn get_deno_lint_fix_all_action(
+  uri: &lsp::Uri,
+  diagnostic: &lsp::Diagnostic,
+  file_diagnostics: &[lsp::Diagnostic],
+) -> Option<lsp::CodeAction> {
+  let code = lint_code(diagnostic)?;
+  let (edits, fixed) = collect_lint_fixes(
+    file_diagnostics
+      .iter()
+      .filter(|d| lint_code(d) == Some(code)),
+  );
+  if fixed.len() < 2 {
+    return None;
+  }
+  Some(lint_fix_all_action(
+    format!("Fix all {code} problems in this file"),
+    lsp::CodeActionKind::QUICKFIX,
+    HashMap::from([(uri.clone(), edits)]),
+    fixed,
+  ))
+}
+
+/// A quick fix applying the fixes of every problem of the diagnostic's rule
+/// across the linted open documents, when they're found in more than one.
+pub fn get_deno_lint_workspace_fix_all_action(
+  uri: &lsp::Uri,
+  diagnostic: &lsp::Diagnostic,
+  workspace_diagnostics: &[(lsp::Uri, Arc<Vec<lsp::Diagnostic>>)],
+) -> Option<lsp::CodeAction> {
+  let code = lint_code(diagnostic)?;
+  let mut changes = HashMap::new();
+  let mut fixed = Vec::new();
+  for (document_uri, diagnostics) in workspace_diagnostics {
+    let (edits, document_fixed) = collect_lint_fixes(
+      diagnostics.iter().filter(|d| lint_code(d) == Some(code)),
+    );
+    if edits.is_empty() {
+      continue;
+    }
+    // Only the diagnostics of the requested document are reported as fixed.
+    if document_uri == uri {
+      fixed = document_fixed;
+    }
+    changes.insert(document_uri.clone(), edits);
+  }
+  if changes.len() < 2 {
+    return None;
+  }
+  Some(lint_fix_all_action(
+    format!("Fix all {code} problems in the workspace"),
+    lsp::CodeActionKind::QUICKFIX,
+    changes,
+    fixed,
+  ))
+}
+
+/// The `source.fixAll.deno` action, applying the fixes of every lint problem
+/// in the file.
+pub fn get_deno_lint_source_fix_all_action(
+  uri: &lsp::Uri,
+  file_diagnostics: &[lsp::Diagnostic],
+) -> Option<lsp::CodeAction> {
+  let (edits, fixed) = collect_lint_fixes(file_diagnostics.iter());
+  if fixed.is_empty() {
+    return None;
+  }
+  Some(lint_fix_all_action(
+    "Fix all auto-fixable lint problems".to_string(),
+    SOURCE_FIX_ALL_DENO_CODE_ACTION_KIND.clone(),
+    HashMap::from([(uri.clone(), edits)]),
+    fixed,
+  ))
+}
+
 pub fn get_deno_lint_code_actions(
   uri: &lsp::Uri,
   module: &DocumentModule,
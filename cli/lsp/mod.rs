@@ -15,7 +15,9 @@ mod compiler_options;
 mod completions;
 mod config;
 mod diagnostics;
+mod document_links;
 mod documents;
+mod format_range;
 mod jsr;
 pub mod language_server;
 mod lint;
@@ -377,6 +377,7 @@ impl TestRun {
               sanitize_ops: false,
               sanitize_resources: false,
               update_snapshots: false,
+              quarantine: Vec::new(),
             },
           ))
         }
@@ -59,6 +59,12 @@ impl LineIndex {
     }
   }
 
+  /// Returns a u16 position based on a u8 offset.
+  pub fn position_utf8(&self, offset: TextSize) -> Option<lsp::Position> {
+    let offset = self.inner.utf8_to_utf16_offset(offset)?;
+    Some(self.position_utf16(offset))
+  }
+
   pub fn line_length_utf16(&self, line: u32) -> TextSize {
     self.inner.line_length_utf16(line)
   }
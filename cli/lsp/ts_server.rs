@@ -924,6 +924,125 @@ impl TsServer {
     }
   }
 
+  pub async fn provide_prepare_type_hierarchy(
+    &self,
+    module: &DocumentModule,
+    position: lsp::Position,
+    snapshot: &Arc<StateSnapshot>,
+    token: &CancellationToken,
+  ) -> Result<Option<Vec<lsp::TypeHierarchyItem>>, AnyError> {
+    match self {
+      Self::Js(ts_server) => {
+        let items = ts_server
+          .prepare_type_hierarchy(
+            snapshot.clone(),
+            module,
+            module.line_index.offset_tsc(position)?,
+            token,
+          )
+          .await?;
+        if items.is_empty() {
+          return Ok(None);
+        }
+        let items = items
+          .iter()
+          .flat_map(|item| {
+            if token.is_cancelled() {
+              return Some(Err(anyhow!("request cancelled")));
+            }
+            Some(Ok(item.try_resolve_type_hierarchy_item(module, snapshot)?))
+          })
+          .collect::<Result<Vec<_>, _>>()?;
+        Ok(Some(items))
+      }
+    }
+  }
+
+  pub async fn provide_type_hierarchy_supertypes(
+    &self,
+    module: &DocumentModule,
+    item: &lsp::TypeHierarchyItem,
+    snapshot: &Arc<StateSnapshot>,
+    token: &CancellationToken,
+  ) -> Result<Option<Vec<lsp::TypeHierarchyItem>>, AnyError> {
+    match self {
+      Self::Js(ts_server) => {
+        let supertypes = ts_server
+          .provide_type_hierarchy_supertypes(
+            snapshot.clone(),
+            module,
+            module.line_index.offset_tsc(item.selection_range.start)?,
+            token,
+          )
+          .await?;
+        let supertypes = supertypes
+          .iter()
+          .flat_map(|item| {
+            if token.is_cancelled() {
+              return Some(Err(anyhow!("request cancelled")));
+            }
+            Some(Ok(item.try_resolve_type_hierarchy_item(module, snapshot)?))
+          })
+          .collect::<Result<_, _>>()?;
+        Ok(Some(supertypes))
+      }
+    }
+  }
+
+  pub async fn provide_type_hierarchy_subtypes(
+    &self,
+    document: &Document,
+    _module: &DocumentModule,
+    item: &lsp::TypeHierarchyItem,
+    snapshot: &Arc<StateSnapshot>,
+    token: &CancellationToken,
+  ) -> Result<Option<Vec<lsp::TypeHierarchyItem>>, AnyError> {
+    match self {
+      Self::Js(ts_server) => {
+        // Subtypes can be declared in any module which imports the type, so
+        // ask the language service of every compiler options key.
+        let mut subtypes_with_modules = IndexMap::new();
+        for module in snapshot
+          .document_modules
+          .get_or_temp_modules_by_compiler_options_key(document)
+          .into_values()
+        {
+          if token.is_cancelled() {
+            return Err(anyhow!("request cancelled"));
+          }
+          let subtypes = ts_server
+            .provide_type_hierarchy_subtypes(
+              snapshot.clone(),
+              &module,
+              module.line_index.offset_tsc(item.selection_range.start)?,
+              token,
+            )
+            .await
+            .inspect_err(|err| {
+              lsp_warn!(
+                "Unable to get subtypes from TypeScript: {:#}\nScope: {}",
+                err,
+                module.scope.as_ref().map(|s| s.as_str()).unwrap_or("null"),
+              );
+            })
+            .unwrap_or_default();
+          subtypes_with_modules
+            .extend(subtypes.into_iter().map(|t| (t, module.clone())));
+        }
+        let subtypes = subtypes_with_modules
+          .iter()
+          .flat_map(|(t, module)| {
+            if token.is_cancelled() {
+              return Some(Err(anyhow!("request cancelled")));
+            }
+            Some(Ok(t.try_resolve_type_hierarchy_item(module, snapshot)?))
+          })
+          .collect::<Result<Vec<_>, _>>()?;
+        Ok(Some(subtypes))
+      }
+    }
+  }
+
   #[allow(clippy::too_many_arguments, reason = "TODO: cleanup")]
   pub async fn provide_rename(
     &self,
@@ -1020,6 +1139,31 @@ impl TsServer {
     }
   }
 
+  pub async fn provide_linked_editing_ranges(
+    &self,
+    module: &DocumentModule,
+    position: lsp::Position,
+    snapshot: Arc<StateSnapshot>,
+    token: &CancellationToken,
+  ) -> Result<Option<lsp::LinkedEditingRanges>, AnyError> {
+    match self {
+      Self::Js(ts_server) => {
+        let linked_editing_info = ts_server
+          .get_linked_editing_range_at_position(
+            snapshot,
+            module,
+            module.line_index.offset_tsc(position)?,
+            token,
+          )
+          .await?;
+        Ok(
+          linked_editing_info
+            .map(|info| info.to_linked_editing_ranges(&module.line_index)),
+        )
+      }
+    }
+  }
+
   pub async fn provide_semantic_tokens_full(
     &self,
     module: &DocumentModule,
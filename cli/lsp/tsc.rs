@@ -1324,6 +1324,94 @@ impl TsJsServer {
       })
   }
 
+  /// Type hierarchy items have the same shape as call hierarchy items, so the
+  /// `$`-prefixed type hierarchy requests in `98_lsp.js` reuse
+  /// `CallHierarchyItem`.
+  #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
+  pub async fn prepare_type_hierarchy(
+    &self,
+    snapshot: Arc<StateSnapshot>,
+    module: &DocumentModule,
+    position: u32,
+    token: &CancellationToken,
+  ) -> Result<Vec<CallHierarchyItem>, AnyError> {
+    let req = TscRequest::PrepareTypeHierarchy((
+      self
+        .specifier_map
+        .denormalize(&module.specifier, module.media_type),
+      position,
+    ));
+    self
+      .type_hierarchy_request(snapshot, req, module, token)
+      .await
+  }
+
+  #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
+  pub async fn provide_type_hierarchy_supertypes(
+    &self,
+    snapshot: Arc<StateSnapshot>,
+    module: &DocumentModule,
+    position: u32,
+    token: &CancellationToken,
+  ) -> Result<Vec<CallHierarchyItem>, AnyError> {
+    let req = TscRequest::ProvideTypeHierarchySupertypes((
+      self
+        .specifier_map
+        .denormalize(&module.specifier, module.media_type),
+      position,
+    ));
+    self
+      .type_hierarchy_request(snapshot, req, module, token)
+      .await
+  }
+
+  #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
+  pub async fn provide_type_hierarchy_subtypes(
+    &self,
+    snapshot: Arc<StateSnapshot>,
+    module: &DocumentModule,
+    position: u32,
+    token: &CancellationToken,
+  ) -> Result<Vec<CallHierarchyItem>, AnyError> {
+    let req = TscRequest::ProvideTypeHierarchySubtypes((
+      self
+        .specifier_map
+        .denormalize(&module.specifier, module.media_type),
+      position,
+    ));
+    self
+      .type_hierarchy_request(snapshot, req, module, token)
+      .await
+  }
+
+  async fn type_hierarchy_request(
+    &self,
+    snapshot: Arc<StateSnapshot>,
+    req: TscRequest,
+    module: &DocumentModule,
+    token: &CancellationToken,
+  ) -> Result<Vec<CallHierarchyItem>, AnyError> {
+    self
+      .request::<Vec<CallHierarchyItem>>(
+        snapshot,
+        req,
+        &module.compiler_options_key,
+        module.scope.as_ref(),
+        module.notebook_uri.as_ref(),
+        token,
+      )
+      .await
+      .and_then(|mut items| {
+        for item in &mut items {
+          if token.is_cancelled() {
+            return Err(anyhow!("request cancelled"));
+          }
+          item.normalize(&self.specifier_map)?;
+        }
+        Ok(items)
+      })
+  }
+
   #[allow(clippy::too_many_arguments, reason = "TODO: cleanup")]
   #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
   pub async fn find_rename_locations(
@@ -1392,6 +1480,32 @@ impl TsJsServer {
       .await
   }
 
+  #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
+  pub async fn get_linked_editing_range_at_position(
+    &self,
+    snapshot: Arc<StateSnapshot>,
+    module: &DocumentModule,
+    position: u32,
+    token: &CancellationToken,
+  ) -> Result<Option<LinkedEditingInfo>, AnyError> {
+    let req = TscRequest::GetLinkedEditingRangeAtPosition((
+      self
+        .specifier_map
+        .denormalize(&module.specifier, module.media_type),
+      position,
+    ));
+    self
+      .request(
+        snapshot,
+        req,
+        &module.compiler_options_key,
+        module.scope.as_ref(),
+        module.notebook_uri.as_ref(),
+        token,
+      )
+      .await
+  }
+
   #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
   pub async fn get_encoded_semantic_classifications(
     &self,
@@ -3494,6 +3608,24 @@ impl CallHierarchyItem {
     Some(item)
   }
 
+  pub fn try_resolve_type_hierarchy_item(
+    &self,
+    module: &DocumentModule,
+    snapshot: &StateSnapshot,
+  ) -> Option<lsp::TypeHierarchyItem> {
+    let (item, _) = self.to_call_hierarchy_item(module, snapshot)?;
+    Some(lsp::TypeHierarchyItem {
+      name: item.name,
+      kind: item.kind,
+      tags: item.tags.and_then(|tags| tags.into_iter().next()),
+      detail: item.detail,
+      uri: item.uri,
+      range: item.range,
+      selection_range: item.selection_range,
+      data: None,
+    })
+  }
+
   fn to_call_hierarchy_item(
     &self,
     module: &DocumentModule,
@@ -4879,6 +5011,31 @@ impl SelectionRange {
   }
 }
 
+/// The ranges of the opening and closing tag names of a JSX element, or of
+/// the empty names of a fragment.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkedEditingInfo {
+  ranges: Vec<TextSpan>,
+  word_pattern: Option<String>,
+}
+
+impl LinkedEditingInfo {
+  pub fn to_linked_editing_ranges(
+    &self,
+    line_index: &LineIndex,
+  ) -> lsp::LinkedEditingRanges {
+    lsp::LinkedEditingRanges {
+      ranges: self
+        .ranges
+        .iter()
+        .map(|span| span.to_range(line_index))
+        .collect(),
+      word_pattern: self.word_pattern.clone(),
+    }
+  }
+}
+
 #[derive(Debug, Default)]
 pub struct TscSpecifierMap {
   normalized_specifiers: DashMap<String, ModuleSpecifier>,
@@ -6516,10 +6673,15 @@ enum TscRequest {
   ProvideCallHierarchyOutgoingCalls((String, u32)),
   // https://github.com/denoland/deno/blob/v1.37.1/cli/tsc/dts/typescript.d.ts#L6236
   PrepareCallHierarchy((String, u32)),
+  PrepareTypeHierarchy((String, u32)),
+  ProvideTypeHierarchySupertypes((String, u32)),
+  ProvideTypeHierarchySubtypes((String, u32)),
   // https://github.com/denoland/deno/blob/v2.2.2/cli/tsc/dts/typescript.d.ts#L6674
   FindRenameLocations((String, u32, bool, bool, UserPreferences)),
   // https://github.com/denoland/deno/blob/v1.37.1/cli/tsc/dts/typescript.d.ts#L6224
   GetSmartSelectionRange((String, u32)),
+  // https://github.com/denoland/deno/blob/v2.9.0/cli/tsc/dts/typescript.d.ts#L10294
+  GetLinkedEditingRangeAtPosition((String, u32)),
   // https://github.com/denoland/deno/blob/v1.37.1/cli/tsc/dts/typescript.d.ts#L6183
   GetEncodedSemanticClassifications((String, TextSpan, &'static str)),
   // https://github.com/denoland/deno/blob/v1.37.1/cli/tsc/dts/typescript.d.ts#L6217
@@ -6638,6 +6800,18 @@ impl TscRequest {
         "prepareCallHierarchy",
         Some((specifier, Number(position)).to_v8(scope)?),
       ),
+      TscRequest::PrepareTypeHierarchy((specifier, position)) => (
+        "$prepareTypeHierarchy",
+        Some((specifier, Number(position)).to_v8(scope)?),
+      ),
+      TscRequest::ProvideTypeHierarchySupertypes((specifier, position)) => (
+        "$provideTypeHierarchySupertypes",
+        Some((specifier, Number(position)).to_v8(scope)?),
+      ),
+      TscRequest::ProvideTypeHierarchySubtypes((specifier, position)) => (
+        "$provideTypeHierarchySubtypes",
+        Some((specifier, Number(position)).to_v8(scope)?),
+      ),
       TscRequest::FindRenameLocations(args) => (
         "findRenameLocations",
         Some(serde_v8::to_v8(scope, args).map_err(JsErrorBox::from_err)?),
@@ -6646,6 +6820,10 @@ impl TscRequest {
         "getSmartSelectionRange",
         Some((specifier, Number(position)).to_v8(scope)?),
       ),
+      TscRequest::GetLinkedEditingRangeAtPosition((specifier, position)) => (
+        "getLinkedEditingRangeAtPosition",
+        Some((specifier, Number(position)).to_v8(scope)?),
+      ),
       TscRequest::GetEncodedSemanticClassifications(args) => (
         "getEncodedSemanticClassifications",
         Some(serde_v8::to_v8(scope, args).map_err(JsErrorBox::from_err)?),
@@ -6706,8 +6884,18 @@ impl TscRequest {
         "provideCallHierarchyOutgoingCalls"
       }
       TscRequest::PrepareCallHierarchy(_) => "prepareCallHierarchy",
+      TscRequest::PrepareTypeHierarchy(_) => "$prepareTypeHierarchy",
+      TscRequest::ProvideTypeHierarchySupertypes(_) => {
+        "$provideTypeHierarchySupertypes"
+      }
+      TscRequest::ProvideTypeHierarchySubtypes(_) => {
+        "$provideTypeHierarchySubtypes"
+      }
       TscRequest::FindRenameLocations(_) => "findRenameLocations",
       TscRequest::GetSmartSelectionRange(_) => "getSmartSelectionRange",
+      TscRequest::GetLinkedEditingRangeAtPosition(_) => {
+        "getLinkedEditingRangeAtPosition"
+      }
       TscRequest::GetEncodedSemanticClassifications(_) => {
         "getEncodedSemanticClassifications"
       }
@@ -173,6 +173,7 @@ fn op_register_test(
     timeout_ms,
     retry,
     repeats,
+    quarantined: false,
   };
   state
     .borrow_mut::<TestContainer>()
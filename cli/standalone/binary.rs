@@ -1117,6 +1117,7 @@ impl<'a> DenoCompileBinaryWriter<'a> {
         .to_desktop_config()
         .ok()
         .and_then(|c| c.release.as_ref()?.base_url.clone()),
+      kv_local_limits: self.cli_options.kv_local_limits()?,
     };
 
     let (data_section_bytes, section_sizes) = serialize_binary_data_section(
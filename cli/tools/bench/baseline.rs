@@ -0,0 +1,584 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Named benchmark baselines for `deno bench`.
+//!
+//! `--save-baseline=<name>` persists the measurements of a run into the Deno
+//! dir, and `--baseline=<name>` compares a later run against them. Each
+//! benchmark keeps a downsampled copy of its sorted samples so a comparison
+//! can run Welch's t-test instead of just diffing averages: a benchmark is
+//! only reported as a regression when it got slower by more than the
+//! configured threshold *and* the difference is statistically significant at
+//! the 95% level, which keeps noisy benchmarks from failing CI at random.
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use deno_core::anyhow::Context;
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::BenchDescription;
+use super::BenchReport;
+use super::BenchStats;
+use super::mitata::fmt_duration;
+use crate::colors;
+
+const BASELINE_VERSION: u8 = 1;
+const DEFAULT_REGRESSION_THRESHOLD: u32 = 10;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchBaseline {
+  pub version: u8,
+  pub benches: Vec<BaselineBench>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineBench {
+  pub origin: String,
+  pub group: Option<String>,
+  pub name: String,
+  pub n: u64,
+  pub avg: f64,
+  pub min: f64,
+  pub max: f64,
+  pub p75: f64,
+  pub p99: f64,
+  #[serde(default)]
+  pub p995: f64,
+  #[serde(default)]
+  pub p999: f64,
+  #[serde(default)]
+  pub samples: Vec<f64>,
+}
+
+impl BaselineBench {
+  fn new(desc: &BenchDescription, stats: &BenchStats) -> Self {
+    Self {
+      origin: desc.origin.clone(),
+      group: desc.group.clone(),
+      name: desc.name.clone(),
+      n: stats.n,
+      avg: stats.avg,
+      min: stats.min,
+      max: stats.max,
+      p75: stats.p75,
+      p99: stats.p99,
+      p995: stats.p995,
+      p999: stats.p999,
+      samples: stats.samples.clone(),
+    }
+  }
+
+  fn matches(&self, desc: &BenchDescription) -> bool {
+    self.origin == desc.origin
+      && self.group == desc.group
+      && self.name == desc.name
+  }
+}
+
+impl BenchBaseline {
+  fn find(&self, desc: &BenchDescription) -> Option<&BaselineBench> {
+    self.benches.iter().find(|bench| bench.matches(desc))
+  }
+
+  /// Replaces the entries for benchmarks that ran, keeping the ones that
+  /// didn't (e.g. because of `--filter`) so partial runs don't wipe out
+  /// the rest of the baseline.
+  fn merge(&mut self, measurements: &[(BenchDescription, BenchStats)]) {
+    for (desc, stats) in measurements {
+      let bench = BaselineBench::new(desc, stats);
+      match self.benches.iter_mut().find(|b| b.matches(desc)) {
+        Some(existing) => *existing = bench,
+        None => self.benches.push(bench),
+      }
+    }
+  }
+}
+
+/// Baselines are stored as one JSON file per name in the Deno dir.
+#[derive(Debug, Clone)]
+pub struct BenchBaselineStore {
+  dir: PathBuf,
+}
+
+impl BenchBaselineStore {
+  pub fn new(deno_dir_root: &Path) -> Self {
+    Self {
+      dir: deno_dir_root.join("bench_baselines_v1"),
+    }
+  }
+
+  fn path(&self, name: &str) -> Result<PathBuf, AnyError> {
+    let is_valid = !name.is_empty()
+      && !name.starts_with('.')
+      && name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if !is_valid {
+      bail!(
+        "Invalid baseline name '{}'. Names may only contain ASCII letters, digits, '-', '_' and '.'",
+        name
+      );
+    }
+    Ok(self.dir.join(format!("{name}.json")))
+  }
+
+  pub fn load(&self, name: &str) -> Result<BenchBaseline, AnyError> {
+    let path = self.path(name)?;
+    let text = match std::fs::read_to_string(&path) {
+      Ok(text) => text,
+      Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+        bail!(
+          "Bench baseline '{}' was not found. Save one first with --save-baseline={}",
+          name,
+          name
+        );
+      }
+      Err(err) => {
+        return Err(err).with_context(|| {
+          format!("Failed reading bench baseline '{}'", path.display())
+        });
+      }
+    };
+    let baseline: BenchBaseline = serde_json::from_str(&text)
+      .with_context(|| format!("Failed parsing bench baseline '{name}'"))?;
+    if baseline.version != BASELINE_VERSION {
+      bail!(
+        "Bench baseline '{}' was saved by an incompatible version of Deno. Save it again with --save-baseline={}",
+        name,
+        name
+      );
+    }
+    Ok(baseline)
+  }
+
+  pub fn save(
+    &self,
+    name: &str,
+    measurements: &[(BenchDescription, BenchStats)],
+  ) -> Result<(), AnyError> {
+    let path = self.path(name)?;
+    let mut baseline = match self.load(name) {
+      Ok(baseline) => baseline,
+      Err(_) => BenchBaseline {
+        version: BASELINE_VERSION,
+        benches: Vec::new(),
+      },
+    };
+    baseline.merge(measurements);
+    std::fs::create_dir_all(&self.dir).with_context(|| {
+      format!("Failed creating directory '{}'", self.dir.display())
+    })?;
+    let json = serde_json::to_string(&baseline)?;
+    std::fs::write(&path, json).with_context(|| {
+      format!("Failed writing bench baseline '{}'", path.display())
+    })?;
+    Ok(())
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchBaselineOptions {
+  store: BenchBaselineStore,
+  save: Option<String>,
+  compare: Option<(String, Arc<BenchBaseline>)>,
+  regression_threshold: u32,
+}
+
+impl BenchBaselineOptions {
+  /// Validates names and loads the baseline to compare against up front, so
+  /// a typo fails before any benchmark runs.
+  pub fn resolve(
+    deno_dir_root: &Path,
+    save: Option<String>,
+    compare: Option<String>,
+    regression_threshold: Option<u32>,
+  ) -> Result<Self, AnyError> {
+    let store = BenchBaselineStore::new(deno_dir_root);
+    if let Some(name) = &save {
+      store.path(name)?;
+    }
+    let compare = match compare {
+      Some(name) => {
+        let baseline = store.load(&name)?;
+        Some((name, Arc::new(baseline)))
+      }
+      None => None,
+    };
+    Ok(Self {
+      store,
+      save,
+      compare,
+      regression_threshold: regression_threshold
+        .unwrap_or(DEFAULT_REGRESSION_THRESHOLD),
+    })
+  }
+
+  /// Compares the report against the selected baseline and saves it under a
+  /// new name, as requested. Errors if any benchmark regressed.
+  pub fn finish(
+    &self,
+    report: &BenchReport,
+    quiet: bool,
+  ) -> Result<(), AnyError> {
+    let mut regressions = 0;
+    if let Some((name, baseline)) = &self.compare {
+      let comparisons = compare_report(
+        baseline,
+        &report.measurements,
+        self.regression_threshold as f64,
+      );
+      regressions = comparisons
+        .iter()
+        .filter(|c| c.status == ComparisonStatus::Regressed)
+        .count();
+      if !quiet {
+        print_comparisons(name, &comparisons);
+      }
+    }
+
+    if let Some(name) = &self.save {
+      self.store.save(name, &report.measurements)?;
+      log::info!("{} bench baseline '{}'", colors::green("Saved"), name);
+    }
+
+    if regressions > 0 {
+      let (name, _) = self.compare.as_ref().unwrap();
+      bail!(
+        "Bench failed because {} {} regressed by more than {}% compared to baseline '{}'",
+        regressions,
+        if regressions == 1 {
+          "benchmark"
+        } else {
+          "benchmarks"
+        },
+        self.regression_threshold,
+        name
+      );
+    }
+
+    Ok(())
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComparisonStatus {
+  /// The benchmark isn't in the baseline.
+  New,
+  /// The difference is within the threshold or not significant.
+  Unchanged,
+  Improved,
+  Regressed,
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchComparison {
+  pub name: String,
+  pub baseline_avg: Option<f64>,
+  pub current_avg: f64,
+  /// Relative change of the average time, in percent. Positive is slower.
+  pub delta: Option<f64>,
+  pub significant: bool,
+  pub status: ComparisonStatus,
+}
+
+fn compare_report(
+  baseline: &BenchBaseline,
+  measurements: &[(BenchDescription, BenchStats)],
+  threshold: f64,
+) -> Vec<BenchComparison> {
+  measurements
+    .iter()
+    .map(|(desc, stats)| {
+      let name = match &desc.group {
+        Some(group) => format!("{} > {}", group, desc.name),
+        None => desc.name.clone(),
+      };
+      match baseline.find(desc) {
+        Some(previous) => compare_bench(name, previous, stats, threshold),
+        None => BenchComparison {
+          name,
+          baseline_avg: None,
+          current_avg: stats.avg,
+          delta: None,
+          significant: false,
+          status: ComparisonStatus::New,
+        },
+      }
+    })
+    .collect()
+}
+
+fn compare_bench(
+  name: String,
+  previous: &BaselineBench,
+  current: &BenchStats,
+  threshold: f64,
+) -> BenchComparison {
+  let delta = if previous.avg > 0.0 {
+    (current.avg - previous.avg) / previous.avg * 100.0
+  } else {
+    0.0
+  };
+  let significant = is_significant(&previous.samples, &current.samples);
+  let status = if !significant || delta.abs() <= threshold {
+    ComparisonStatus::Unchanged
+  } else if delta > 0.0 {
+    ComparisonStatus::Regressed
+  } else {
+    ComparisonStatus::Improved
+  };
+  BenchComparison {
+    name,
+    baseline_avg: Some(previous.avg),
+    current_avg: current.avg,
+    delta: Some(delta),
+    significant,
+    status,
+  }
+}
+
+/// Two-sided Welch's t-test at the 95% confidence level. Without at least
+/// two samples on each side the variance is unknown, so nothing is
+/// considered significant.
+fn is_significant(a: &[f64], b: &[f64]) -> bool {
+  if a.len() < 2 || b.len() < 2 {
+    return false;
+  }
+  let (mean_a, var_a) = mean_and_variance(a);
+  let (mean_b, var_b) = mean_and_variance(b);
+  let se_a = var_a / a.len() as f64;
+  let se_b = var_b / b.len() as f64;
+  let se = se_a + se_b;
+  if se == 0.0 {
+    return mean_a != mean_b;
+  }
+  let t = (mean_a - mean_b).abs() / se.sqrt();
+  // Welch–Satterthwaite approximation of the degrees of freedom
+  let df = se * se
+    / (se_a * se_a / (a.len() - 1) as f64 + se_b * se_b / (b.len() - 1) as f64);
+  t > t_critical_95(df)
+}
+
+fn mean_and_variance(samples: &[f64]) -> (f64, f64) {
+  let n = samples.len() as f64;
+  let mean = samples.iter().sum::<f64>() / n;
+  let variance =
+    samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+  (mean, variance)
+}
+
+/// Two-sided critical values of Student's t distribution for p = 0.05.
+fn t_critical_95(df: f64) -> f64 {
+  const TABLE: [f64; 30] = [
+    12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228,
+    2.201, 2.179, 2.160, 2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086,
+    2.080, 2.074, 2.069, 2.064, 2.060, 2.056, 2.052, 2.048, 2.045, 2.042,
+  ];
+  // round down so the test errs on the side of "not significant"
+  let df = df.floor();
+  if df < 1.0 {
+    TABLE[0]
+  } else if df <= 30.0 {
+    TABLE[df as usize - 1]
+  } else if df <= 60.0 {
+    2.021
+  } else if df <= 120.0 {
+    2.000
+  } else {
+    1.960
+  }
+}
+
+#[allow(clippy::print_stdout, reason = "reporter")]
+fn print_comparisons(baseline_name: &str, comparisons: &[BenchComparison]) {
+  if comparisons.is_empty() {
+    return;
+  }
+  let name_width = comparisons
+    .iter()
+    .map(|c| c.name.chars().count())
+    .max()
+    .unwrap_or(0)
+    .max("benchmark".len());
+
+  println!();
+  println!(
+    "{}",
+    colors::gray(format!("comparison against baseline '{baseline_name}'"))
+  );
+  println!();
+  println!(
+    "{:<name_width$}   {:>14}   {:>14}   {:>9}",
+    "benchmark", "baseline", "current", "delta",
+  );
+  println!("{}", "-".repeat(name_width + 48));
+  for comparison in comparisons {
+    let baseline = comparison
+      .baseline_avg
+      .map(fmt_duration)
+      .unwrap_or_else(|| "-".to_string());
+    let delta = match comparison.delta {
+      Some(delta) => format!("{delta:>+8.1}%"),
+      None => format!("{:>9}", "new"),
+    };
+    let delta = match comparison.status {
+      ComparisonStatus::Regressed => format!("{}", colors::red(&delta)),
+      ComparisonStatus::Improved => format!("{}", colors::green(&delta)),
+      ComparisonStatus::New | ComparisonStatus::Unchanged => {
+        if comparison.delta.is_some() && !comparison.significant {
+          format!("{} {}", delta, colors::gray("(noise)"))
+        } else {
+          delta
+        }
+      }
+    };
+    println!(
+      "{:<name_width$}   {:>14}   {:>14}   {}",
+      comparison.name,
+      baseline,
+      fmt_duration(comparison.current_avg),
+      delta,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn desc(name: &str) -> BenchDescription {
+    BenchDescription {
+      id: 0,
+      name: name.to_string(),
+      origin: "file:///bench.ts".to_string(),
+      baseline: false,
+      group: None,
+      ignore: false,
+      only: false,
+      warmup: false,
+    }
+  }
+
+  fn stats(samples: Vec<f64>) -> BenchStats {
+    let avg = samples.iter().sum::<f64>() / samples.len() as f64;
+    BenchStats {
+      n: samples.len() as u64,
+      min: samples.iter().cloned().fold(f64::INFINITY, f64::min),
+      max: samples.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+      avg,
+      p75: avg,
+      p99: avg,
+      p995: avg,
+      p999: avg,
+      high_precision: true,
+      used_explicit_timers: false,
+      samples,
+    }
+  }
+
+  fn around(center: f64, count: usize) -> Vec<f64> {
+    (0..count)
+      .map(|i| center + (i % 10) as f64 - 4.5)
+      .collect::<Vec<_>>()
+  }
+
+  #[test]
+  fn detects_significant_regression() {
+    let previous = BaselineBench::new(&desc("a"), &stats(around(100.0, 200)));
+    let comparison = compare_bench(
+      "a".to_string(),
+      &previous,
+      &stats(around(130.0, 200)),
+      10.0,
+    );
+    assert!(comparison.significant);
+    assert_eq!(comparison.status, ComparisonStatus::Regressed);
+    assert!((comparison.delta.unwrap() - 30.0).abs() < 0.01);
+  }
+
+  #[test]
+  fn ignores_changes_within_threshold() {
+    let previous = BaselineBench::new(&desc("a"), &stats(around(100.0, 200)));
+    let comparison = compare_bench(
+      "a".to_string(),
+      &previous,
+      &stats(around(105.0, 200)),
+      10.0,
+    );
+    assert!(comparison.significant);
+    assert_eq!(comparison.status, ComparisonStatus::Unchanged);
+
+    let comparison = compare_bench(
+      "a".to_string(),
+      &previous,
+      &stats(around(70.0, 200)),
+      10.0,
+    );
+    assert_eq!(comparison.status, ComparisonStatus::Improved);
+  }
+
+  #[test]
+  fn ignores_noisy_changes() {
+    // large spread and few samples, so a 20% shift of the average isn't
+    // distinguishable from noise
+    let previous =
+      BaselineBench::new(&desc("a"), &stats(vec![10.0, 190.0, 100.0]));
+    let comparison = compare_bench(
+      "a".to_string(),
+      &previous,
+      &stats(vec![20.0, 220.0, 120.0]),
+      10.0,
+    );
+    assert!(!comparison.significant);
+    assert_eq!(comparison.status, ComparisonStatus::Unchanged);
+  }
+
+  #[test]
+  fn merge_keeps_benches_that_did_not_run() {
+    let mut baseline = BenchBaseline {
+      version: BASELINE_VERSION,
+      benches: vec![],
+    };
+    baseline.merge(&[
+      (desc("a"), stats(vec![1.0, 2.0])),
+      (desc("b"), stats(vec![3.0, 4.0])),
+    ]);
+    baseline.merge(&[(desc("b"), stats(vec![5.0, 6.0]))]);
+    assert_eq!(baseline.benches.len(), 2);
+    assert_eq!(baseline.find(&desc("a")).unwrap().avg, 1.5);
+    assert_eq!(baseline.find(&desc("b")).unwrap().avg, 5.5);
+  }
+
+  #[test]
+  fn store_round_trip() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let store = BenchBaselineStore::new(temp_dir.path());
+    assert!(store.load("main").is_err());
+    store
+      .save("main", &[(desc("a"), stats(vec![1.0, 2.0]))])
+      .unwrap();
+    let baseline = store.load("main").unwrap();
+    assert_eq!(baseline.benches.len(), 1);
+    assert_eq!(baseline.benches[0].samples, vec![1.0, 2.0]);
+    assert_eq!(baseline.benches[0].p995, 1.5);
+    assert_eq!(baseline.benches[0].p999, 1.5);
+    assert!(store.load("../main").is_err());
+  }
+
+  #[test]
+  fn loads_entries_without_extreme_percentiles() {
+    let baseline: BenchBaseline = serde_json::from_str(
+      r#"{"version":1,"benches":[{"origin":"file:///bench.ts","group":null,"name":"a","n":2,"avg":1.5,"min":1.0,"max":2.0,"p75":2.0,"p99":2.0}]}"#,
+    )
+    .unwrap();
+    assert_eq!(baseline.benches[0].p995, 0.0);
+    assert_eq!(baseline.benches[0].p999, 0.0);
+  }
+}
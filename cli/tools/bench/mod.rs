@@ -35,6 +35,7 @@ use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::mpsc::unbounded_channel;
 
 use crate::args::BenchFlags;
+use crate::args::BenchReporterConfig;
 use crate::args::CliOptions;
 use crate::args::Flags;
 use crate::args::TypeCheckModeExt;
@@ -55,17 +56,27 @@ use crate::util::path::matches_pattern_or_exact_path;
 use crate::worker::CliMainWorkerFactory;
 use crate::worker::CreateCustomWorkerError;
 
+mod baseline;
 mod mitata;
 mod reporters;
 
+use baseline::BenchBaselineOptions;
 use reporters::BenchReporter;
+use reporters::CompoundReporter;
 use reporters::ConsoleReporter;
+use reporters::GithubReporter;
 use reporters::JsonReporter;
+use reporters::JunitReporter;
+use reporters::TapReporter;
 
 #[derive(Debug, Clone)]
 struct BenchSpecifierOptions {
+  cwd: ModuleSpecifier,
   filter: TestFilter,
   json: bool,
+  reporter: BenchReporterConfig,
+  junit_path: Option<String>,
+  baselines: BenchBaselineOptions,
   log_level: Option<log::Level>,
 }
 
@@ -129,6 +140,10 @@ pub struct BenchStats {
   pub p999: f64,
   pub high_precision: bool,
   pub used_explicit_timers: bool,
+  /// Downsampled, sorted measurements used for baseline comparisons.
+  #[serde(skip_serializing, default)]
+  #[from_v8(default)]
+  pub samples: Vec<f64>,
 }
 
 impl BenchReport {
@@ -143,13 +158,35 @@ impl BenchReport {
 }
 
 fn create_reporter(
-  show_output: bool,
-  json: bool,
+  options: &BenchSpecifierOptions,
 ) -> Box<dyn BenchReporter + Send> {
-  if json {
+  if options.json {
     return Box::new(JsonReporter::new());
   }
-  Box::new(ConsoleReporter::new(show_output))
+  let show_output = options.log_level != Some(Level::Error);
+  let reporter: Box<dyn BenchReporter + Send> = match options.reporter {
+    BenchReporterConfig::Pretty => Box::new(ConsoleReporter::new(show_output)),
+    BenchReporterConfig::Junit => {
+      Box::new(JunitReporter::new(options.cwd.clone(), "-".to_string()))
+    }
+    BenchReporterConfig::Tap => Box::new(TapReporter::new(options.cwd.clone())),
+    BenchReporterConfig::Github => {
+      Box::new(GithubReporter::new(options.cwd.clone(), show_output))
+    }
+  };
+
+  if let Some(junit_path) = &options.junit_path
+    // the JUnit reporter already writes the report to stdout
+    && !(options.reporter == BenchReporterConfig::Junit && junit_path == "-")
+  {
+    let junit = Box::new(JunitReporter::new(
+      options.cwd.clone(),
+      junit_path.to_string(),
+    ));
+    return Box::new(CompoundReporter::new(vec![reporter, junit]));
+  }
+
+  reporter
 }
 
 /// Run a single specifier as an executable bench module.
@@ -310,7 +347,6 @@ async fn bench_specifiers(
   options: BenchSpecifierOptions,
 ) -> Result<(), AnyError> {
   let (sender, mut receiver) = unbounded_channel::<BenchEvent>();
-  let log_level = options.log_level;
   let option_for_handles = options.clone();
 
   let join_handles = specifiers.into_iter().map(move |specifier| {
@@ -356,8 +392,7 @@ async fn bench_specifiers(
     spawn(async move {
       let mut used_only = false;
       let mut report = BenchReport::new();
-      let mut reporter =
-        create_reporter(log_level != Some(Level::Error), options.json);
+      let mut reporter = create_reporter(&options);
       let mut benches = IndexMap::new();
 
       while let Some(event) = receiver.recv().await {
@@ -418,6 +453,15 @@ async fn bench_specifiers(
         return Err(anyhow!("Bench failed"));
       }
 
+      // the comparison table would corrupt a report written to stdout
+      let quiet = options.json
+        || matches!(
+          options.reporter,
+          BenchReporterConfig::Tap | BenchReporterConfig::Junit
+        )
+        || options.junit_path.as_deref() == Some("-");
+      options.baselines.finish(&report, quiet)?;
+
       Ok(())
     })
   };
@@ -434,6 +478,19 @@ async fn bench_specifiers(
   Ok(())
 }
 
+fn resolve_cwd_url(
+  cli_options: &CliOptions,
+) -> Result<ModuleSpecifier, AnyError> {
+  ModuleSpecifier::from_directory_path(cli_options.initial_cwd()).map_err(
+    |_| {
+      anyhow!(
+        "Unable to construct URL from the path of cwd: {}",
+        cli_options.initial_cwd().to_string_lossy(),
+      )
+    },
+  )
+}
+
 /// Checks if the path has a basename and extension Deno supports for benches.
 fn is_supported_bench_path(entry: WalkEntry) -> bool {
   if !is_script_ext(entry.path) {
@@ -491,6 +548,13 @@ pub async fn run_benchmarks(
     return Err(anyhow!("No bench modules found"));
   }
 
+  let baselines = BenchBaselineOptions::resolve(
+    &factory.deno_dir()?.root,
+    workspace_bench_options.save_baseline.clone(),
+    workspace_bench_options.baseline.clone(),
+    workspace_bench_options.regression_threshold,
+  )?;
+
   let main_graph_container = factory.main_module_graph_container().await?;
   main_graph_container
     .check_specifiers(
@@ -519,8 +583,12 @@ pub async fn run_benchmarks(
     preload_modules,
     require_modules,
     BenchSpecifierOptions {
+      cwd: resolve_cwd_url(cli_options)?,
       filter: TestFilter::from_flag(&workspace_bench_options.filter),
       json: workspace_bench_options.json,
+      reporter: workspace_bench_options.reporter,
+      junit_path: workspace_bench_options.junit_path.clone(),
+      baselines,
       log_level,
     },
   )
@@ -660,8 +728,17 @@ pub async fn run_benchmarks_with_watch(
           preload_modules,
           require_modules,
           BenchSpecifierOptions {
+            cwd: resolve_cwd_url(cli_options)?,
             filter: TestFilter::from_flag(&workspace_bench_options.filter),
             json: workspace_bench_options.json,
+            reporter: workspace_bench_options.reporter,
+            junit_path: workspace_bench_options.junit_path.clone(),
+            baselines: BenchBaselineOptions::resolve(
+              &factory.deno_dir()?.root,
+              workspace_bench_options.save_baseline.clone(),
+              workspace_bench_options.baseline.clone(),
+              workspace_bench_options.regression_threshold,
+            )?,
             log_level,
           },
         )
@@ -1,10 +1,19 @@
 // Copyright 2018-2026 the Deno authors. MIT license.
 
+use std::io::Write;
+
+use deno_core::anyhow::Context;
+use deno_core::serde_json;
+use deno_core::url::Url;
 use deno_lib::version::DENO_VERSION_INFO;
 use serde::Serialize;
 
 use super::*;
 use crate::tools::test::TestFailureFormatOptions;
+use crate::tools::test::fmt::to_relative_path_or_remote_url;
+use crate::tools::test::reporters::format_time;
+use crate::tools::test::reporters::write_attr;
+use crate::tools::test::reporters::write_escaped;
 
 pub trait BenchReporter {
   fn report_group_summary(&mut self);
@@ -321,3 +330,492 @@ impl BenchReporter for ConsoleReporter {
     println!();
   }
 }
+
+/// Forwards every event to each of the wrapped reporters.
+pub struct CompoundReporter {
+  reporters: Vec<Box<dyn BenchReporter + Send>>,
+}
+
+impl CompoundReporter {
+  pub fn new(reporters: Vec<Box<dyn BenchReporter + Send>>) -> Self {
+    Self { reporters }
+  }
+}
+
+impl BenchReporter for CompoundReporter {
+  fn report_group_summary(&mut self) {
+    for reporter in &mut self.reporters {
+      reporter.report_group_summary();
+    }
+  }
+
+  fn report_plan(&mut self, plan: &BenchPlan) {
+    for reporter in &mut self.reporters {
+      reporter.report_plan(plan);
+    }
+  }
+
+  fn report_end(&mut self, report: &BenchReport) {
+    for reporter in &mut self.reporters {
+      reporter.report_end(report);
+    }
+  }
+
+  fn report_register(&mut self, desc: &BenchDescription) {
+    for reporter in &mut self.reporters {
+      reporter.report_register(desc);
+    }
+  }
+
+  fn report_wait(&mut self, desc: &BenchDescription) {
+    for reporter in &mut self.reporters {
+      reporter.report_wait(desc);
+    }
+  }
+
+  fn report_output(&mut self, output: &str) {
+    for reporter in &mut self.reporters {
+      reporter.report_output(output);
+    }
+  }
+
+  fn report_result(&mut self, desc: &BenchDescription, result: &BenchResult) {
+    for reporter in &mut self.reporters {
+      reporter.report_result(desc, result);
+    }
+  }
+
+  fn report_uncaught_error(&mut self, origin: &str, error: Box<JsError>) {
+    for reporter in &mut self.reporters {
+      reporter.report_uncaught_error(origin, error.clone());
+    }
+  }
+}
+
+fn bench_display_name(desc: &BenchDescription) -> String {
+  match &desc.group {
+    Some(group) => format!("{group} > {}", desc.name),
+    None => desc.name.clone(),
+  }
+}
+
+fn plain_failure_format_options(cwd: &Url) -> TestFailureFormatOptions {
+  TestFailureFormatOptions {
+    hide_stacktraces: false,
+    strip_ascii_color: true,
+    initial_cwd: Some(cwd.clone()),
+  }
+}
+
+enum JunitBenchOutcome {
+  Ok(BenchStats),
+  Failed(Box<JsError>),
+  UncaughtError(Box<JsError>),
+}
+
+struct JunitBenchCase {
+  name: String,
+  outcome: JunitBenchOutcome,
+}
+
+/// Writes the bench results as a JUnit XML report, one test suite per bench
+/// module and one test case per bench. The timing statistics of each bench
+/// are recorded as properties of its test case.
+pub struct JunitReporter {
+  cwd: Url,
+  output_path: String,
+  suites: IndexMap<String, Vec<JunitBenchCase>>,
+  started: std::time::Instant,
+}
+
+impl JunitReporter {
+  pub fn new(cwd: Url, output_path: String) -> Self {
+    Self {
+      cwd,
+      output_path,
+      suites: IndexMap::new(),
+      started: std::time::Instant::now(),
+    }
+  }
+
+  fn add_case(&mut self, origin: &str, case: JunitBenchCase) {
+    let suite_name = to_relative_path_or_remote_url(&self.cwd, origin);
+    self.suites.entry(suite_name).or_default().push(case);
+  }
+
+  fn serialize(&self, mut writer: impl Write) -> std::io::Result<()> {
+    let count = |suite: &[JunitBenchCase],
+                 filter: fn(&JunitBenchOutcome) -> bool| {
+      suite.iter().filter(|case| filter(&case.outcome)).count()
+    };
+    let is_failure =
+      |o: &JunitBenchOutcome| matches!(o, JunitBenchOutcome::Failed(_));
+    let is_error =
+      |o: &JunitBenchOutcome| matches!(o, JunitBenchOutcome::UncaughtError(_));
+    let tests = self.suites.values().map(|s| s.len()).sum::<usize>();
+    let failures = self
+      .suites
+      .values()
+      .map(|s| count(s, is_failure))
+      .sum::<usize>();
+    let errors = self
+      .suites
+      .values()
+      .map(|s| count(s, is_error))
+      .sum::<usize>();
+
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    write!(writer, "<testsuites")?;
+    write_attr(&mut writer, "name", "deno bench")?;
+    write_attr(&mut writer, "tests", &tests.to_string())?;
+    write_attr(&mut writer, "failures", &failures.to_string())?;
+    write_attr(&mut writer, "errors", &errors.to_string())?;
+    write_attr(&mut writer, "time", &format_time(self.started.elapsed()))?;
+    writeln!(writer, ">")?;
+    for (suite_name, cases) in &self.suites {
+      write!(writer, "    <testsuite")?;
+      write_attr(&mut writer, "name", suite_name)?;
+      write_attr(&mut writer, "tests", &cases.len().to_string())?;
+      write_attr(&mut writer, "disabled", "0")?;
+      write_attr(&mut writer, "errors", &count(cases, is_error).to_string())?;
+      write_attr(
+        &mut writer,
+        "failures",
+        &count(cases, is_failure).to_string(),
+      )?;
+      writeln!(writer, ">")?;
+      for case in cases {
+        self.serialize_case(&mut writer, suite_name, case)?;
+      }
+      writeln!(writer, "    </testsuite>")?;
+    }
+    writeln!(writer, "</testsuites>")
+  }
+
+  fn serialize_case(
+    &self,
+    mut writer: impl Write,
+    suite_name: &str,
+    case: &JunitBenchCase,
+  ) -> std::io::Result<()> {
+    write!(writer, "        <testcase")?;
+    write_attr(&mut writer, "name", &case.name)?;
+    write_attr(&mut writer, "classname", suite_name)?;
+    if let JunitBenchOutcome::Ok(stats) = &case.outcome {
+      // The stats are in nanoseconds per iteration.
+      let total_time =
+        Duration::from_nanos((stats.avg * stats.n as f64).round() as u64);
+      write_attr(&mut writer, "time", &format_time(total_time))?;
+    }
+    writeln!(writer, ">")?;
+    match &case.outcome {
+      JunitBenchOutcome::Ok(stats) => {
+        writeln!(writer, "            <properties>")?;
+        let properties = [
+          ("n", stats.n.to_string()),
+          ("min", stats.min.to_string()),
+          ("max", stats.max.to_string()),
+          ("avg", stats.avg.to_string()),
+          ("p75", stats.p75.to_string()),
+          ("p99", stats.p99.to_string()),
+          ("p995", stats.p995.to_string()),
+          ("p999", stats.p999.to_string()),
+        ];
+        for (name, value) in properties {
+          write!(writer, "                <property")?;
+          write_attr(&mut writer, "name", name)?;
+          write_attr(&mut writer, "value", &value)?;
+          writeln!(writer, "/>")?;
+        }
+        writeln!(writer, "            </properties>")?;
+      }
+      JunitBenchOutcome::Failed(error)
+      | JunitBenchOutcome::UncaughtError(error) => {
+        let tag_name = if matches!(case.outcome, JunitBenchOutcome::Failed(_)) {
+          "failure"
+        } else {
+          "error"
+        };
+        let description =
+          format_test_error(error, &plain_failure_format_options(&self.cwd));
+        write!(writer, "            <{tag_name}")?;
+        write_attr(&mut writer, "message", &error.exception_message)?;
+        write!(writer, ">")?;
+        write_escaped(&mut writer, &description)?;
+        writeln!(writer, "</{tag_name}>")?;
+      }
+    }
+    writeln!(writer, "        </testcase>")
+  }
+}
+
+impl BenchReporter for JunitReporter {
+  fn report_group_summary(&mut self) {}
+  fn report_plan(&mut self, _plan: &BenchPlan) {}
+
+  fn report_end(&mut self, _report: &BenchReport) {
+    let result = if self.output_path == "-" {
+      self
+        .serialize(std::io::stdout())
+        .context("Failed to write JUnit report to stdout")
+    } else {
+      crate::util::fs::create_file(Path::new(&self.output_path))
+        .context("Failed to open JUnit report file.")
+        .and_then(|file| {
+          self.serialize(file).with_context(|| {
+            format!("Failed to write JUnit report to {}", self.output_path)
+          })
+        })
+    };
+    if let Err(err) = result {
+      log::error!("{}: {:#}", colors::red_bold("error"), err);
+    }
+  }
+
+  fn report_register(&mut self, _desc: &BenchDescription) {}
+  fn report_wait(&mut self, _desc: &BenchDescription) {}
+  fn report_output(&mut self, _output: &str) {}
+
+  fn report_result(&mut self, desc: &BenchDescription, result: &BenchResult) {
+    if desc.warmup {
+      return;
+    }
+    let outcome = match result {
+      BenchResult::Ok(stats) => JunitBenchOutcome::Ok(stats.clone()),
+      BenchResult::Failed(error) => JunitBenchOutcome::Failed(error.clone()),
+    };
+    self.add_case(
+      &desc.origin,
+      JunitBenchCase {
+        name: bench_display_name(desc),
+        outcome,
+      },
+    );
+  }
+
+  fn report_uncaught_error(&mut self, origin: &str, error: Box<JsError>) {
+    self.add_case(
+      origin,
+      JunitBenchCase {
+        name: "Uncaught error".to_string(),
+        outcome: JunitBenchOutcome::UncaughtError(error),
+      },
+    );
+  }
+}
+
+#[derive(Serialize)]
+struct TapDiagnosticLocation {
+  file: String,
+}
+
+/// A bench reporter for the Test Anything Protocol as defined at
+/// https://testanything.org/tap-version-14-specification.html
+///
+/// The timing statistics of each bench are emitted as its YAML diagnostic.
+pub struct TapReporter {
+  cwd: Url,
+  header: bool,
+  n: usize,
+}
+
+#[allow(clippy::print_stdout, reason = "reporter")]
+impl TapReporter {
+  pub fn new(cwd: Url) -> Self {
+    Self {
+      cwd,
+      header: false,
+      n: 0,
+    }
+  }
+
+  fn escape_description(description: &str) -> String {
+    description
+      .replace('\\', "\\\\")
+      .replace('\n', "\\n")
+      .replace('\r', "\\r")
+      .replace('#', "\\#")
+  }
+
+  fn print_header(&mut self) {
+    if !self.header {
+      println!("TAP version 14");
+      self.header = true;
+    }
+  }
+
+  fn print_test_point(&mut self, ok: bool, description: &str) {
+    self.n += 1;
+    println!(
+      "{} {} - {}",
+      if ok { "ok" } else { "not ok" },
+      self.n,
+      Self::escape_description(description)
+    );
+  }
+
+  fn print_diagnostic(diagnostic: &serde_json::Value) {
+    // YAML is a superset of JSON, so we can avoid a YAML dependency here.
+    let diagnostic = serde_json::to_string(diagnostic)
+      .expect("failed to serialize TAP diagnostic");
+    println!("  ---");
+    println!("  {}", diagnostic);
+    println!("  ...");
+  }
+
+  fn print_failure(&self, origin: &str, error: &JsError) {
+    Self::print_diagnostic(&serde_json::json!({
+      "message": format_test_error(
+        error,
+        &plain_failure_format_options(&self.cwd)
+      ),
+      "severity": "fail",
+      "at": TapDiagnosticLocation {
+        file: to_relative_path_or_remote_url(&self.cwd, origin),
+      },
+    }));
+  }
+}
+
+#[allow(clippy::print_stdout, reason = "reporter")]
+impl BenchReporter for TapReporter {
+  fn report_group_summary(&mut self) {}
+
+  fn report_plan(&mut self, plan: &BenchPlan) {
+    self.print_header();
+    println!(
+      "# {}",
+      to_relative_path_or_remote_url(&self.cwd, &plan.origin)
+    );
+  }
+
+  fn report_end(&mut self, _report: &BenchReport) {
+    self.print_header();
+    println!("1..{}", self.n);
+  }
+
+  fn report_register(&mut self, _desc: &BenchDescription) {}
+  fn report_wait(&mut self, _desc: &BenchDescription) {}
+  fn report_output(&mut self, _output: &str) {}
+
+  fn report_result(&mut self, desc: &BenchDescription, result: &BenchResult) {
+    if desc.warmup {
+      return;
+    }
+    match result {
+      BenchResult::Ok(stats) => {
+        self.print_test_point(true, &bench_display_name(desc));
+        Self::print_diagnostic(&serde_json::json!({ "stats": stats }));
+      }
+      BenchResult::Failed(error) => {
+        self.print_test_point(false, &bench_display_name(desc));
+        self.print_failure(&desc.origin, error);
+      }
+    }
+  }
+
+  fn report_uncaught_error(&mut self, origin: &str, error: Box<JsError>) {
+    self.print_header();
+    self.print_test_point(
+      false,
+      &format!(
+        "Uncaught error from {}",
+        to_relative_path_or_remote_url(&self.cwd, origin)
+      ),
+    );
+    self.print_failure(origin, &error);
+  }
+}
+
+/// Prints the regular console output and additionally emits GitHub Actions
+/// workflow commands so that failing benches are annotated in the run.
+pub struct GithubReporter {
+  cwd: Url,
+  console: ConsoleReporter,
+}
+
+#[allow(clippy::print_stdout, reason = "reporter")]
+impl GithubReporter {
+  pub fn new(cwd: Url, show_output: bool) -> Self {
+    Self {
+      cwd,
+      console: ConsoleReporter::new(show_output),
+    }
+  }
+
+  fn escape_data(data: &str) -> String {
+    data
+      .replace('%', "%25")
+      .replace('\r', "%0D")
+      .replace('\n', "%0A")
+  }
+
+  fn escape_property(property: &str) -> String {
+    Self::escape_data(property)
+      .replace(':', "%3A")
+      .replace(',', "%2C")
+  }
+
+  fn print_error_annotation(&self, origin: &str, title: &str, error: &JsError) {
+    let mut properties = Vec::new();
+    if let Ok(url) = Url::parse(origin)
+      && url.scheme() == "file"
+    {
+      let file = to_relative_path_or_remote_url(&self.cwd, origin);
+      let file = file.strip_prefix("./").unwrap_or(&file).to_string();
+      properties.push(format!("file={}", Self::escape_property(&file)));
+    }
+    properties.push(format!("title={}", Self::escape_property(title)));
+    let message =
+      format_test_error(error, &plain_failure_format_options(&self.cwd));
+    println!(
+      "::error {}::{}",
+      properties.join(","),
+      Self::escape_data(&message)
+    );
+  }
+}
+
+impl BenchReporter for GithubReporter {
+  fn report_group_summary(&mut self) {
+    self.console.report_group_summary();
+  }
+
+  fn report_plan(&mut self, plan: &BenchPlan) {
+    self.console.report_plan(plan);
+  }
+
+  fn report_end(&mut self, report: &BenchReport) {
+    self.console.report_end(report);
+  }
+
+  fn report_register(&mut self, desc: &BenchDescription) {
+    self.console.report_register(desc);
+  }
+
+  fn report_wait(&mut self, desc: &BenchDescription) {
+    self.console.report_wait(desc);
+  }
+
+  fn report_output(&mut self, output: &str) {
+    self.console.report_output(output);
+  }
+
+  fn report_result(&mut self, desc: &BenchDescription, result: &BenchResult) {
+    self.console.report_result(desc, result);
+    if !desc.warmup
+      && let BenchResult::Failed(error) = result
+    {
+      self.print_error_annotation(
+        &desc.origin,
+        &format!("Bench failed: {}", bench_display_name(desc)),
+        error,
+      );
+    }
+  }
+
+  fn report_uncaught_error(&mut self, origin: &str, error: Box<JsError>) {
+    self.print_error_annotation(origin, "Uncaught error", &error);
+    self.console.report_uncaught_error(origin, error);
+  }
+}
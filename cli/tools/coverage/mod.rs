@@ -1,5 +1,7 @@
 // Copyright 2018-2026 the Deno authors. MIT license.
 
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fs;
 use std::fs::File;
 use std::path::Path;
@@ -38,6 +40,8 @@ use crate::factory::CliFactory;
 use crate::file_fetcher::TextDecodedFile;
 use crate::sys::CliSys;
 use crate::tools::test::is_supported_test_path;
+use crate::util::fs::canonicalize_path;
+use crate::util::git;
 use crate::util::text_encoding::source_map_from_code;
 
 mod ignore_directives;
@@ -589,38 +593,23 @@ fn filter_coverages(
     .collect::<Vec<cdp::ScriptCoverage>>()
 }
 
-#[allow(clippy::too_many_arguments, reason = "coverage entry point")]
-pub fn cover_files(
-  flags: Arc<Flags>,
-  files_include: Vec<String>,
-  files_ignore: Vec<String>,
+/// Reads the raw coverage profiles matched by `files`, merges them per
+/// module, and maps them back onto the original sources.
+fn collect_file_reports(
+  factory: &CliFactory,
+  files: FileFlags,
   include: Vec<String>,
   exclude: Vec<String>,
-  output: Option<String>,
-  cli_threshold: Option<f64>,
-  reporters: &[&dyn CoverageReporter],
-) -> Result<(), AnyError> {
-  if files_include.is_empty() {
-    return Err(anyhow!("No matching coverage profiles found"));
-  }
-
-  let factory = CliFactory::from_flags(flags);
+  output: &Option<PathBuf>,
+) -> Result<Vec<(CoverageReport, String)>, AnyError> {
   let cli_options = factory.cli_options()?;
   let in_npm_pkg_checker = factory.in_npm_pkg_checker()?;
   let file_fetcher = factory.file_fetcher()?;
   let emitter = factory.emitter()?;
   let cjs_tracker = factory.cjs_tracker()?;
 
-  // Use the first include path as the default output path.
-  let coverage_root = cli_options.initial_cwd().join(&files_include[0]);
-  let script_coverages = collect_coverages(
-    cli_options,
-    FileFlags {
-      include: files_include,
-      ignore: files_ignore,
-    },
-    cli_options.initial_cwd(),
-  )?;
+  let script_coverages =
+    collect_coverages(cli_options, files, cli_options.initial_cwd())?;
   if script_coverages.is_empty() {
     return Err(anyhow!("No coverage files found"));
   }
@@ -674,15 +663,6 @@ pub fn cover_files(
     vec![]
   };
 
-  let out_mode = match output {
-    Some(ref path) => match File::create(path) {
-      Ok(_) => Some(PathBuf::from(path)),
-      Err(e) => {
-        return Err(anyhow!("Failed to create output file: {}", e));
-      }
-    },
-    None => None,
-  };
   let get_message = |specifier: &ModuleSpecifier| -> String {
     format!(
       "Source not found for \"{}\" (was it deleted after coverage was collected?). Skipping.",
@@ -790,6 +770,101 @@ pub fn cover_files(
     return Err(anyhow!("No covered files included in the report"));
   }
 
+  Ok(file_reports)
+}
+
+/// The lines hit at least once in the raw coverage profiles under
+/// `coverage_dir`, by module. Mutation testing uses this to find the test
+/// modules that run a mutated line.
+pub fn covered_lines(
+  flags: Arc<Flags>,
+  coverage_dir: &Path,
+) -> Result<HashMap<ModuleSpecifier, HashSet<usize>>, AnyError> {
+  let factory = CliFactory::from_flags(flags);
+  let file_reports = collect_file_reports(
+    &factory,
+    FileFlags {
+      include: vec![coverage_dir.to_string_lossy().into_owned()],
+      ignore: vec![],
+    },
+    vec![],
+    vec![],
+    &None,
+  )?;
+  Ok(
+    file_reports
+      .into_iter()
+      .map(|(report, _)| {
+        let lines = report
+          .found_lines
+          .iter()
+          .filter(|(_, count)| *count > 0)
+          .map(|(line_index, _)| *line_index)
+          .collect();
+        (report.url, lines)
+      })
+      .collect(),
+  )
+}
+
+#[allow(clippy::too_many_arguments, reason = "coverage entry point")]
+pub fn cover_files(
+  flags: Arc<Flags>,
+  files_include: Vec<String>,
+  files_ignore: Vec<String>,
+  include: Vec<String>,
+  exclude: Vec<String>,
+  output: Option<String>,
+  cli_threshold: Option<f64>,
+  changed_since: Option<&str>,
+  reporters: &[&dyn CoverageReporter],
+) -> Result<(), AnyError> {
+  if files_include.is_empty() {
+    return Err(anyhow!("No matching coverage profiles found"));
+  }
+
+  let factory = CliFactory::from_flags(flags);
+  let cli_options = factory.cli_options()?;
+  // Use the first include path as the default output path.
+  let coverage_root = cli_options.initial_cwd().join(&files_include[0]);
+  let out_mode = match output {
+    Some(ref path) => match File::create(path) {
+      Ok(_) => Some(PathBuf::from(path)),
+      Err(e) => {
+        return Err(anyhow!("Failed to create output file: {}", e));
+      }
+    },
+    None => None,
+  };
+  let mut file_reports = collect_file_reports(
+    &factory,
+    FileFlags {
+      include: files_include,
+      ignore: files_ignore,
+    },
+    include,
+    exclude,
+    &out_mode,
+  )?;
+
+  let changed_lines_reporter;
+  let mut reporters = reporters.to_vec();
+  if let Some(base) = changed_since {
+    let changed_lines =
+      git::changed_line_ranges(cli_options.initial_cwd(), base)?;
+    file_reports = filter_to_changed_lines(file_reports, &changed_lines);
+    if file_reports.is_empty() {
+      log::info!("No covered lines changed since {base}");
+      for reporter in reporters {
+        reporter.done_empty(out_mode.as_deref());
+      }
+      return Ok(());
+    }
+    changed_lines_reporter =
+      reporter::ChangedLinesCoverageReporter::new(base.to_string());
+    reporters.push(&changed_lines_reporter);
+  }
+
   for reporter in reporters {
     reporter.done(&coverage_root, &file_reports);
   }
@@ -807,6 +882,46 @@ pub fn cover_files(
   Ok(())
 }
 
+/// Restricts the reports to the lines changed since a git ref. Branches and
+/// functions are kept when the line they start on changed. Files without any
+/// changed covered lines are dropped.
+fn filter_to_changed_lines(
+  file_reports: Vec<(CoverageReport, String)>,
+  changed_lines: &HashMap<PathBuf, git::ChangedLineRanges>,
+) -> Vec<(CoverageReport, String)> {
+  let changed_lines = changed_lines
+    .iter()
+    .map(|(path, ranges)| {
+      (
+        canonicalize_path(path).unwrap_or_else(|_| path.clone()),
+        ranges,
+      )
+    })
+    .collect::<HashMap<_, _>>();
+  file_reports
+    .into_iter()
+    .filter_map(|(mut report, file_text)| {
+      let path = report.url.to_file_path().ok()?;
+      let path = canonicalize_path(&path).unwrap_or(path);
+      let ranges = changed_lines.get(&path)?;
+      // Reports use 0-based line indexes, git uses 1-based line numbers.
+      let is_changed = |line_index: usize| {
+        ranges.iter().any(|r| r.contains(&(line_index + 1)))
+      };
+      report
+        .found_lines
+        .retain(|(line_index, _)| is_changed(*line_index));
+      report.branches.retain(|b| is_changed(b.line_index));
+      report.named_functions.retain(|f| is_changed(f.line_index));
+      if report.found_lines.is_empty() {
+        None
+      } else {
+        Some((report, file_text))
+      }
+    })
+    .collect()
+}
+
 /// Computes the aggregate line, branch, and function coverage percentages
 /// across all reported files. A metric with no measurable items counts as 100%
 /// (e.g. a `branches` threshold passes vacuously for files that have no
@@ -10,8 +10,11 @@ use std::path::Path;
 use std::path::PathBuf;
 
 use deno_core::error::AnyError;
+use deno_core::serde_json;
 use deno_core::url::Url;
 use deno_lib::version::DENO_VERSION_INFO;
+use indexmap::IndexMap;
+use serde::Serialize;
 
 use super::CoverageReport;
 use super::util;
@@ -60,6 +63,16 @@ impl CoverageStats<'_> {
       .filter(|f| f.execution_count == 0)
       .count();
   }
+
+  /// Adds the hit/miss counts of already aggregated stats.
+  pub fn add_report_stats(&mut self, stats: &CoverageStats) {
+    self.line_hit += stats.line_hit;
+    self.line_miss += stats.line_miss;
+    self.branch_hit += stats.branch_hit;
+    self.branch_miss += stats.branch_miss;
+    self.fn_hit += stats.fn_hit;
+    self.fn_miss += stats.fn_miss;
+  }
 }
 
 type CoverageSummary<'a> = HashMap<String, CoverageStats<'a>>;
@@ -70,6 +83,8 @@ pub fn create(kind: CoverageType) -> Box<dyn CoverageReporter + Send> {
     CoverageType::Lcov => Box::new(LcovCoverageReporter::new()),
     CoverageType::Detailed => Box::new(DetailedCoverageReporter::new()),
     CoverageType::Html => Box::new(HtmlCoverageReporter::new()),
+    CoverageType::Cobertura => Box::new(CoberturaCoverageReporter::new()),
+    CoverageType::Istanbul => Box::new(IstanbulCoverageReporter::new()),
   }
 }
 
@@ -80,6 +95,12 @@ pub trait CoverageReporter {
     file_reports: &[(CoverageReport, String)],
   );
 
+  /// Called instead of [`CoverageReporter::done`] when no file is left to
+  /// report on, e.g. when `--changed-since` finds no changed lines. Reporters
+  /// producing a document still write an empty one, so that tools consuming
+  /// it don't find it missing.
+  fn done_empty(&self, _output: Option<&Path>) {}
+
   /// Collects the coverage summary of each file or directory.
   fn collect_summary<'a>(
     &'a self,
@@ -258,6 +279,126 @@ impl CoverageReporter for SummaryCoverageReporter {
   }
 }
 
+/// Prints the uncovered lines among the lines changed since a git ref. The
+/// reports passed to this reporter are expected to already be restricted to
+/// the changed lines. The table is logged to stderr so it never ends up in an
+/// lcov, Cobertura or Istanbul document written to stdout.
+pub struct ChangedLinesCoverageReporter {
+  base: String,
+}
+
+impl CoverageReporter for ChangedLinesCoverageReporter {
+  fn done(
+    &self,
+    _coverage_root: &Path,
+    file_reports: &[(CoverageReport, String)],
+  ) {
+    let summary = self.collect_summary(file_reports);
+    let mut entries = summary
+      .iter()
+      .filter_map(|(node, stats)| {
+        stats.file_text.as_ref()?;
+        Some((node.replace('\\', "/"), stats))
+      })
+      .collect::<Vec<_>>();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let mut total = CoverageStats::default();
+    let rows = entries
+      .iter()
+      .map(|(node, stats)| {
+        total.add_report_stats(stats);
+        let uncovered = stats
+          .report
+          .map(|report| Self::format_uncovered_lines(report))
+          .unwrap_or_default();
+        (node.as_str(), *stats, uncovered)
+      })
+      .collect::<Vec<_>>();
+
+    let node_max = rows
+      .iter()
+      .map(|(node, _, _)| node.len())
+      .max()
+      .unwrap_or(0)
+      .max("All changed files".len());
+    let uncovered_max = rows
+      .iter()
+      .map(|(_, _, uncovered)| uncovered.len())
+      .max()
+      .unwrap_or(0)
+      .max("Uncovered changed lines".len());
+
+    log::info!("Coverage of lines changed since {}:", self.base);
+    log::info!(
+      "| {:node_max$} | Changed lines | Branch % | Line % | {:uncovered_max$} |",
+      "File",
+      "Uncovered changed lines"
+    );
+    log::info!(
+      "| {} | {} | {} | {} | {} |",
+      "-".repeat(node_max),
+      "-".repeat(13),
+      "-".repeat(8),
+      "-".repeat(6),
+      "-".repeat(uncovered_max)
+    );
+    let print_row = |node: &str, stats: &CoverageStats, uncovered: &str| {
+      let (changed_lines, line_percent, line_class) =
+        util::calc_coverage_display_info(stats.line_hit, stats.line_miss);
+      let (_, branch_percent, _) =
+        util::calc_coverage_display_info(stats.branch_hit, stats.branch_miss);
+      let node = format!("{node:node_max$}");
+      let node = if line_class == "high" {
+        format!("{}", colors::green(&node))
+      } else if line_class == "medium" {
+        format!("{}", colors::yellow(&node))
+      } else {
+        format!("{}", colors::red(&node))
+      };
+      log::info!(
+        "| {node} | {changed_lines:>13} | {branch_percent:>8.1} | {line_percent:>6.1} | {} |",
+        colors::red(&format!("{uncovered:uncovered_max$}"))
+      );
+    };
+    for (node, stats, uncovered) in &rows {
+      print_row(node, stats, uncovered);
+    }
+    print_row("All changed files", &total, "");
+  }
+}
+
+impl ChangedLinesCoverageReporter {
+  pub fn new(base: String) -> ChangedLinesCoverageReporter {
+    ChangedLinesCoverageReporter { base }
+  }
+
+  /// Formats the uncovered lines of a report as 1-based line ranges, e.g.
+  /// "3, 7-9".
+  fn format_uncovered_lines(report: &CoverageReport) -> String {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for (line_index, _) in
+      report.found_lines.iter().filter(|(_, count)| *count == 0)
+    {
+      let line = line_index + 1;
+      match ranges.last_mut() {
+        Some((_, end)) if *end + 1 == line => *end = line,
+        _ => ranges.push((line, line)),
+      }
+    }
+    ranges
+      .into_iter()
+      .map(|(start, end)| {
+        if start == end {
+          start.to_string()
+        } else {
+          format!("{start}-{end}")
+        }
+      })
+      .collect::<Vec<_>>()
+      .join(", ")
+  }
+}
+
 pub struct LcovCoverageReporter {}
 
 impl CoverageReporter for LcovCoverageReporter {
@@ -272,15 +413,15 @@ impl CoverageReporter for LcovCoverageReporter {
     if let Some((report, _)) = file_reports.first()
       && let Some(ref output) = report.output
     {
-      if let Ok(path) = canonicalize_path(output) {
-        let url = Url::from_file_path(path).unwrap();
-        log::info!("Lcov coverage report has been generated at {}", url);
-      } else {
-        log::error!(
-          "Failed to resolve the output path of Lcov report: {}",
-          output.display()
-        );
-      }
+      Self::log_generated(output);
+    }
+  }
+
+  fn done_empty(&self, output: Option<&Path>) {
+    // An empty lcov report has no records; the output file was already
+    // created (empty) before the reports were collected.
+    if let Some(output) = output {
+      Self::log_generated(output);
     }
   }
 }
@@ -290,6 +431,18 @@ impl LcovCoverageReporter {
     LcovCoverageReporter {}
   }
 
+  fn log_generated(output: &Path) {
+    if let Ok(path) = canonicalize_path(output) {
+      let url = Url::from_file_path(path).unwrap();
+      log::info!("Lcov coverage report has been generated at {}", url);
+    } else {
+      log::error!(
+        "Failed to resolve the output path of Lcov report: {}",
+        output.display()
+      );
+    }
+  }
+
   fn report(
     &self,
     coverage_report: &CoverageReport,
@@ -381,6 +534,427 @@ impl LcovCoverageReporter {
   }
 }
 
+/// The `--output` path the reports were collected with, if any.
+fn document_report_output(
+  file_reports: &[(CoverageReport, String)],
+) -> Option<&Path> {
+  file_reports
+    .first()
+    .and_then(|(report, _)| report.output.as_deref())
+}
+
+/// Writes a report that is produced as a single document (as opposed to the
+/// per-file records of lcov) to the output file, or to stdout if no output
+/// file was specified.
+fn write_document_report(
+  output: Option<&Path>,
+  format_name: &str,
+  contents: &str,
+) -> Result<(), AnyError> {
+  let Some(output) = output else {
+    let mut stdout = io::stdout();
+    stdout.write_all(contents.as_bytes())?;
+    writeln!(stdout)?;
+    return Ok(());
+  };
+
+  fs::write(output, contents)?;
+  if let Ok(path) = canonicalize_path(output) {
+    let url = Url::from_file_path(path).unwrap();
+    log::info!("{format_name} coverage report has been generated at {url}");
+  } else {
+    log::error!(
+      "Failed to resolve the output path of {format_name} report: {}",
+      output.display()
+    );
+  }
+  Ok(())
+}
+
+/// Returns the path of the covered file as it should appear in reports that
+/// identify files by path.
+fn report_file_path(report: &CoverageReport) -> String {
+  report
+    .url
+    .to_file_path()
+    .ok()
+    .and_then(|p| p.to_str().map(|p| p.to_string()))
+    .unwrap_or_else(|| report.url.to_string())
+}
+
+fn xml_escape(text: &str) -> String {
+  text
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+    .replace('\'', "&apos;")
+}
+
+fn coverage_rate(hit: usize, total: usize) -> String {
+  if total == 0 {
+    "1".to_string()
+  } else {
+    format!("{:.4}", hit as f64 / total as f64)
+  }
+}
+
+/// Outputs the coverage report in the Cobertura XML format, which is consumed
+/// by most CI dashboards and merge request widgets.
+pub struct CoberturaCoverageReporter {}
+
+impl CoverageReporter for CoberturaCoverageReporter {
+  fn done(
+    &self,
+    _coverage_root: &Path,
+    file_reports: &[(CoverageReport, String)],
+  ) {
+    let timestamp = chrono::Utc::now().timestamp_millis();
+    let xml = self.create_xml(file_reports, timestamp);
+    write_document_report(
+      document_report_output(file_reports),
+      "Cobertura",
+      &xml,
+    )
+    .unwrap();
+  }
+
+  fn done_empty(&self, output: Option<&Path>) {
+    let timestamp = chrono::Utc::now().timestamp_millis();
+    let xml = self.create_xml(&[], timestamp);
+    write_document_report(output, "Cobertura", &xml).unwrap();
+  }
+}
+
+impl CoberturaCoverageReporter {
+  pub fn new() -> CoberturaCoverageReporter {
+    CoberturaCoverageReporter {}
+  }
+
+  pub fn create_xml(
+    &self,
+    file_reports: &[(CoverageReport, String)],
+    timestamp: i64,
+  ) -> String {
+    let urls = file_reports.iter().map(|(report, _)| &report.url).collect();
+    let root = util::find_root(urls)
+      .and_then(|root| root.to_file_path().ok())
+      .unwrap_or_default();
+
+    // Cobertura groups classes (files) into packages (directories).
+    let mut packages: Vec<(String, Vec<&CoverageReport>)> = Vec::new();
+    for (report, _) in file_reports {
+      let package_name = report
+        .url
+        .to_file_path()
+        .ok()
+        .and_then(|path| {
+          path
+            .strip_prefix(&root)
+            .ok()
+            .and_then(|p| p.parent())
+            .and_then(|p| p.to_str())
+            .map(|p| p.replace(['/', '\\'], "."))
+        })
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "main".to_string());
+      match packages.iter_mut().find(|(name, _)| *name == package_name) {
+        Some((_, reports)) => reports.push(report),
+        None => packages.push((package_name, vec![report])),
+      }
+    }
+    packages.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut total = CoverageStats::default();
+    let mut packages_xml = Vec::with_capacity(packages.len());
+    for (package_name, reports) in &packages {
+      let mut package_stats = CoverageStats::default();
+      let mut classes_xml = Vec::with_capacity(reports.len());
+      for report in reports {
+        package_stats.add_report(report);
+        classes_xml.push(self.create_class_xml(report, &root));
+      }
+      total.add_report_stats(&package_stats);
+      packages_xml.push(format!(
+        "    <package name=\"{name}\" line-rate=\"{line_rate}\" branch-rate=\"{branch_rate}\" complexity=\"0\">\n      <classes>\n{classes}\n      </classes>\n    </package>",
+        name = xml_escape(package_name),
+        line_rate = coverage_rate(
+          package_stats.line_hit,
+          package_stats.line_hit + package_stats.line_miss
+        ),
+        branch_rate = coverage_rate(
+          package_stats.branch_hit,
+          package_stats.branch_hit + package_stats.branch_miss
+        ),
+        classes = classes_xml.join("\n"),
+      ));
+    }
+
+    let lines_valid = total.line_hit + total.line_miss;
+    let branches_valid = total.branch_hit + total.branch_miss;
+    format!(
+      "<?xml version=\"1.0\" ?>
+<!DOCTYPE coverage SYSTEM \"http://cobertura.sourceforge.net/xml/coverage-04.dtd\">
+<coverage line-rate=\"{line_rate}\" branch-rate=\"{branch_rate}\" lines-covered=\"{lines_covered}\" lines-valid=\"{lines_valid}\" branches-covered=\"{branches_covered}\" branches-valid=\"{branches_valid}\" complexity=\"0\" version=\"{version}\" timestamp=\"{timestamp}\">
+  <sources>
+    <source>{source}</source>
+  </sources>
+  <packages>
+{packages}
+  </packages>
+</coverage>",
+      line_rate = coverage_rate(total.line_hit, lines_valid),
+      branch_rate = coverage_rate(total.branch_hit, branches_valid),
+      lines_covered = total.line_hit,
+      branches_covered = total.branch_hit,
+      version = DENO_VERSION_INFO.deno,
+      source = xml_escape(&root.to_string_lossy()),
+      packages = packages_xml.join("\n"),
+    )
+  }
+
+  fn create_class_xml(&self, report: &CoverageReport, root: &Path) -> String {
+    let file_path = report_file_path(report);
+    let filename = report
+      .url
+      .to_file_path()
+      .ok()
+      .and_then(|path| {
+        path
+          .strip_prefix(root)
+          .ok()
+          .and_then(|p| p.to_str())
+          .map(|p| p.replace('\\', "/"))
+      })
+      .unwrap_or(file_path);
+    let mut stats = CoverageStats::default();
+    stats.add_report(report);
+
+    let methods = report
+      .named_functions
+      .iter()
+      .map(|function| {
+        let hit = usize::from(function.execution_count > 0);
+        format!(
+          "            <method name=\"{name}\" hits=\"{hits}\" signature=\"\" line-rate=\"{hit}\" branch-rate=\"1\">\n              <lines>\n                <line number=\"{line}\" hits=\"{hits}\"/>\n              </lines>\n            </method>",
+          name = xml_escape(&function.name),
+          hits = function.execution_count,
+          line = function.line_index + 1,
+        )
+      })
+      .collect::<Vec<_>>();
+
+    let lines = report
+      .found_lines
+      .iter()
+      .map(|(line_index, count)| {
+        let branches = report
+          .branches
+          .iter()
+          .filter(|b| b.line_index == *line_index)
+          .collect::<Vec<_>>();
+        if branches.is_empty() {
+          format!(
+            "            <line number=\"{}\" hits=\"{count}\" branch=\"false\"/>",
+            line_index + 1
+          )
+        } else {
+          let branches_hit = branches.iter().filter(|b| b.is_hit).count();
+          let (_, percent, _) = util::calc_coverage_display_info(
+            branches_hit,
+            branches.len() - branches_hit,
+          );
+          format!(
+            "            <line number=\"{}\" hits=\"{count}\" branch=\"true\" condition-coverage=\"{percent:.0}% ({branches_hit}/{})\"/>",
+            line_index + 1,
+            branches.len()
+          )
+        }
+      })
+      .collect::<Vec<_>>();
+
+    let name = Path::new(&filename)
+      .file_name()
+      .and_then(|name| name.to_str())
+      .unwrap_or(&filename)
+      .to_string();
+    format!(
+      "        <class name=\"{name}\" filename=\"{filename}\" line-rate=\"{line_rate}\" branch-rate=\"{branch_rate}\" complexity=\"0\">\n          <methods>\n{methods}\n          </methods>\n          <lines>\n{lines}\n          </lines>\n        </class>",
+      name = xml_escape(&name),
+      filename = xml_escape(&filename),
+      line_rate =
+        coverage_rate(stats.line_hit, stats.line_hit + stats.line_miss),
+      branch_rate =
+        coverage_rate(stats.branch_hit, stats.branch_hit + stats.branch_miss),
+      methods = methods.join("\n"),
+      lines = lines.join("\n"),
+    )
+  }
+}
+
+#[derive(Serialize)]
+struct IstanbulPosition {
+  line: usize,
+  column: usize,
+}
+
+#[derive(Serialize)]
+struct IstanbulLocation {
+  start: IstanbulPosition,
+  end: IstanbulPosition,
+}
+
+#[derive(Serialize)]
+struct IstanbulFunction {
+  name: String,
+  decl: IstanbulLocation,
+  loc: IstanbulLocation,
+  line: usize,
+}
+
+#[derive(Serialize)]
+struct IstanbulBranch {
+  loc: IstanbulLocation,
+  #[serde(rename = "type")]
+  kind: &'static str,
+  locations: Vec<IstanbulLocation>,
+  line: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct IstanbulFileCoverage {
+  path: String,
+  statement_map: IndexMap<usize, IstanbulLocation>,
+  fn_map: IndexMap<usize, IstanbulFunction>,
+  branch_map: IndexMap<usize, IstanbulBranch>,
+  s: IndexMap<usize, i64>,
+  f: IndexMap<usize, i64>,
+  b: IndexMap<usize, Vec<i64>>,
+}
+
+/// Outputs the coverage report in the Istanbul `coverage-final.json` format.
+///
+/// Coverage is collected per line, so every covered line is reported as one
+/// statement spanning the whole line.
+pub struct IstanbulCoverageReporter {}
+
+impl CoverageReporter for IstanbulCoverageReporter {
+  fn done(
+    &self,
+    _coverage_root: &Path,
+    file_reports: &[(CoverageReport, String)],
+  ) {
+    let json = self.create_json(file_reports);
+    write_document_report(
+      document_report_output(file_reports),
+      "Istanbul",
+      &json,
+    )
+    .unwrap();
+  }
+
+  fn done_empty(&self, output: Option<&Path>) {
+    let json = self.create_json(&[]);
+    write_document_report(output, "Istanbul", &json).unwrap();
+  }
+}
+
+impl IstanbulCoverageReporter {
+  pub fn new() -> IstanbulCoverageReporter {
+    IstanbulCoverageReporter {}
+  }
+
+  pub fn create_json(
+    &self,
+    file_reports: &[(CoverageReport, String)],
+  ) -> String {
+    let coverage = file_reports
+      .iter()
+      .map(|(report, file_text)| {
+        let file_coverage = self.create_file_coverage(report, file_text);
+        (file_coverage.path.clone(), file_coverage)
+      })
+      .collect::<IndexMap<_, _>>();
+    serde_json::to_string(&coverage).unwrap()
+  }
+
+  fn create_file_coverage(
+    &self,
+    report: &CoverageReport,
+    file_text: &str,
+  ) -> IstanbulFileCoverage {
+    let lines = file_text.split('\n').collect::<Vec<_>>();
+    let line_location = |line_index: usize| IstanbulLocation {
+      start: IstanbulPosition {
+        line: line_index + 1,
+        column: 0,
+      },
+      end: IstanbulPosition {
+        line: line_index + 1,
+        column: lines
+          .get(line_index)
+          .map(|line| line.trim_end_matches('\r').chars().count())
+          .unwrap_or(0),
+      },
+    };
+
+    let mut statement_map = IndexMap::new();
+    let mut s = IndexMap::new();
+    for (id, (line_index, count)) in report.found_lines.iter().enumerate() {
+      statement_map.insert(id, line_location(*line_index));
+      s.insert(id, *count);
+    }
+
+    let mut fn_map = IndexMap::new();
+    let mut f = IndexMap::new();
+    for (id, function) in report.named_functions.iter().enumerate() {
+      fn_map.insert(
+        id,
+        IstanbulFunction {
+          name: function.name.clone(),
+          decl: line_location(function.line_index),
+          loc: line_location(function.line_index),
+          line: function.line_index + 1,
+        },
+      );
+      f.insert(id, function.execution_count);
+    }
+
+    // Branch arms sharing a line and block belong to the same branch point.
+    let mut branch_map = IndexMap::new();
+    let mut b: IndexMap<usize, Vec<i64>> = IndexMap::new();
+    let mut branch_ids: IndexMap<(usize, usize), usize> = IndexMap::new();
+    for branch in &report.branches {
+      let next_id = branch_ids.len();
+      let id = *branch_ids
+        .entry((branch.line_index, branch.block_number))
+        .or_insert(next_id);
+      branch_map
+        .entry(id)
+        .or_insert_with(|| IstanbulBranch {
+          loc: line_location(branch.line_index),
+          kind: "branch",
+          locations: Vec::new(),
+          line: branch.line_index + 1,
+        })
+        .locations
+        .push(line_location(branch.line_index));
+      b.entry(id).or_default().push(branch.taken.unwrap_or(0));
+    }
+
+    IstanbulFileCoverage {
+      path: report_file_path(report),
+      statement_map,
+      fn_map,
+      branch_map,
+      s,
+      f,
+      b,
+    }
+  }
+}
+
 struct DetailedCoverageReporter {}
 
 impl CoverageReporter for DetailedCoverageReporter {
@@ -830,6 +1404,8 @@ impl HtmlCoverageReporter {
 
 #[cfg(test)]
 mod tests {
+  use super::super::BranchCoverageItem;
+  use super::super::FunctionCoverageItem;
   use super::*;
 
   // Regression test for https://github.com/denoland/deno/issues/30924.
@@ -856,4 +1432,95 @@ mod tests {
     // Must not panic.
     reporter.done(Path::new("coverage"), &file_reports);
   }
+
+  fn branch(
+    line_index: usize,
+    block_number: usize,
+    branch_number: usize,
+    taken: Option<i64>,
+  ) -> BranchCoverageItem {
+    BranchCoverageItem {
+      line_index,
+      block_number,
+      branch_number,
+      taken,
+      is_hit: taken.is_some_and(|t| t > 0),
+    }
+  }
+
+  #[test]
+  fn changed_lines_reporter_formats_uncovered_ranges() {
+    let report = CoverageReport {
+      url: Url::parse("file:///a/mod.ts").unwrap(),
+      named_functions: Vec::new(),
+      branches: Vec::new(),
+      found_lines: vec![(0, 0), (2, 0), (3, 0), (4, 0), (5, 1), (7, 0)],
+      output: None,
+    };
+    assert_eq!(
+      ChangedLinesCoverageReporter::format_uncovered_lines(&report),
+      "1, 3-5, 8"
+    );
+  }
+
+  #[test]
+  fn cobertura_reporter_groups_files_by_directory() {
+    let report = |url: &str| CoverageReport {
+      url: Url::parse(url).unwrap(),
+      named_functions: vec![FunctionCoverageItem {
+        name: "<a&b>".to_string(),
+        line_index: 0,
+        execution_count: 1,
+      }],
+      branches: vec![branch(1, 0, 0, Some(1)), branch(1, 0, 1, Some(0))],
+      found_lines: vec![(0, 1), (1, 1), (2, 0)],
+      output: None,
+    };
+    let file_reports = vec![
+      (report("file:///a/mod.ts"), String::new()),
+      (report("file:///a/src/util.ts"), String::new()),
+    ];
+
+    let xml = CoberturaCoverageReporter::new().create_xml(&file_reports, 0);
+    assert!(xml.contains(
+      "lines-covered=\"4\" lines-valid=\"6\" branches-covered=\"2\" branches-valid=\"4\""
+    ));
+    assert!(xml.contains("<package name=\"main\""));
+    assert!(xml.contains("<package name=\"src\""));
+    assert!(xml.contains("filename=\"src/util.ts\""));
+    assert!(xml.contains("<method name=\"&lt;a&amp;b&gt;\" hits=\"1\""));
+    assert!(xml.contains(
+      "<line number=\"2\" hits=\"1\" branch=\"true\" condition-coverage=\"50% (1/2)\"/>"
+    ));
+    assert!(xml.contains("<line number=\"3\" hits=\"0\" branch=\"false\"/>"));
+  }
+
+  #[test]
+  fn istanbul_reporter_groups_branch_arms() {
+    let report = CoverageReport {
+      url: Url::parse("https://example.com/main.ts").unwrap(),
+      named_functions: Vec::new(),
+      branches: vec![
+        branch(0, 0, 0, Some(2)),
+        branch(0, 0, 1, None),
+        branch(1, 1, 0, Some(1)),
+      ],
+      found_lines: vec![(0, 2), (1, 1)],
+      output: None,
+    };
+    let file_reports = vec![(report, "if (a) {\n  b();\n".to_string())];
+
+    let json = IstanbulCoverageReporter::new().create_json(&file_reports);
+    let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let file = &json["https://example.com/main.ts"];
+    assert_eq!(file["s"], serde_json::json!({ "0": 2, "1": 1 }));
+    assert_eq!(file["b"], serde_json::json!({ "0": [2, 0], "1": [1] }));
+    assert_eq!(
+      file["statementMap"]["1"],
+      serde_json::json!({
+        "start": { "line": 2, "column": 0 },
+        "end": { "line": 2, "column": 6 },
+      })
+    );
+  }
 }
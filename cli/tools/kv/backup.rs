@@ -0,0 +1,41 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+use std::path::PathBuf;
+
+use deno_core::error::AnyError;
+use deno_core::unsync::spawn_blocking;
+use deno_runtime::deno_kv::backup;
+use deno_runtime::deno_kv::backup::KvBackupError;
+use deno_runtime::deno_kv::dynamic::RcDynamicDb;
+
+use crate::args::KvBackupFlags;
+use crate::args::KvRestoreFlags;
+
+pub async fn backup(
+  db: &RcDynamicDb,
+  flags: KvBackupFlags,
+) -> Result<(), AnyError> {
+  let db_path = local_path(db)?;
+  let output = PathBuf::from(&flags.output);
+  spawn_blocking(move || backup::backup(&db_path, &output)).await??;
+  log::info!("Backed up the database to {}", flags.output);
+  Ok(())
+}
+
+pub async fn restore(
+  db: &RcDynamicDb,
+  flags: KvRestoreFlags,
+) -> Result<(), AnyError> {
+  let db_path = local_path(db)?;
+  let input = PathBuf::from(&flags.input);
+  spawn_blocking(move || backup::restore(&input, &db_path)).await??;
+  log::info!("Restored the database from {}", flags.input);
+  Ok(())
+}
+
+fn local_path(db: &RcDynamicDb) -> Result<PathBuf, KvBackupError> {
+  db.as_sqlite()
+    .and_then(|db| db.path())
+    .map(|path| path.to_path_buf())
+    .ok_or(KvBackupError::NotLocal)
+}
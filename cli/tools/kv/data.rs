@@ -0,0 +1,229 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+use std::io::Write;
+use std::num::NonZeroU32;
+
+use deno_core::anyhow::anyhow;
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+use deno_core::futures::StreamExt;
+use deno_core::serde_json;
+use deno_core::serde_json::Value;
+use deno_core::serde_json::json;
+use deno_runtime::deno_kv::dynamic::RcDynamicDb;
+use denokv_proto::AtomicWrite;
+use denokv_proto::Consistency;
+use denokv_proto::Database;
+use denokv_proto::Key;
+use denokv_proto::KvEntry;
+use denokv_proto::Mutation;
+use denokv_proto::MutationKind;
+use denokv_proto::ReadRange;
+use denokv_proto::SnapshotReadOptions;
+use denokv_proto::WatchKeyOutput;
+
+use super::key;
+use super::value::ValueCodec;
+use crate::args::KvDeleteFlags;
+use crate::args::KvGetFlags;
+use crate::args::KvListFlags;
+use crate::args::KvSetFlags;
+use crate::args::KvWatchFlags;
+
+/// How many entries to read at a time. This is the most a KV Connect
+/// server returns for a read.
+pub const PAGE_SIZE: u32 = 1000;
+
+pub async fn list(
+  db: &RcDynamicDb,
+  flags: KvListFlags,
+) -> Result<(), AnyError> {
+  let prefix = match &flags.prefix {
+    Some(prefix) => key::parse_key(prefix)?,
+    None => Key(Vec::new()),
+  };
+  let mut codec = ValueCodec::new()?;
+  let mut stdout = std::io::stdout().lock();
+  let mut remaining = flags.limit;
+  let mut pages = PrefixPages::new(&key::encode(&prefix)?, flags.reverse);
+  while remaining != Some(0) {
+    let page_size =
+      remaining.map_or(PAGE_SIZE, |n| n.min(PAGE_SIZE as usize) as u32);
+    let entries = pages.next(db, page_size).await?;
+    for entry in &entries {
+      writeln!(stdout, "{}", entry_to_json(&mut codec, entry)?)?;
+    }
+    remaining = remaining.map(|n| n - entries.len());
+    if entries.len() < page_size as usize {
+      break;
+    }
+  }
+  Ok(())
+}
+
+pub async fn get(db: &RcDynamicDb, flags: KvGetFlags) -> Result<(), AnyError> {
+  let key = key::parse_key(&flags.key)?;
+  let Some(entry) = read_key(db, key::encode(&key)?).await? else {
+    bail!("Key {} not found", flags.key);
+  };
+  let value = ValueCodec::new()?.to_json(&entry.value)?;
+  println!("{}", serde_json::to_string_pretty(&value)?);
+  Ok(())
+}
+
+pub async fn set(db: &RcDynamicDb, flags: KvSetFlags) -> Result<(), AnyError> {
+  let key = key::parse_key(&flags.key)?;
+  let value = serde_json::from_str::<Value>(&flags.value)
+    .map_err(|err| anyhow!("Invalid value '{}': {err}", flags.value))?;
+  let value = ValueCodec::new()?.from_json(value)?;
+  write(db, key::encode(&key)?, MutationKind::Set(value)).await
+}
+
+pub async fn delete(
+  db: &RcDynamicDb,
+  flags: KvDeleteFlags,
+) -> Result<(), AnyError> {
+  let key = key::parse_key(&flags.key)?;
+  write(db, key::encode(&key)?, MutationKind::Delete).await
+}
+
+pub async fn watch(
+  db: &RcDynamicDb,
+  flags: KvWatchFlags,
+) -> Result<(), AnyError> {
+  let keys = flags
+    .keys
+    .iter()
+    .map(|text| key::encode(&key::parse_key(text)?))
+    .collect::<Result<Vec<_>, _>>()?;
+  let mut codec = ValueCodec::new()?;
+  let mut last = vec![Value::Null; keys.len()];
+  let mut stream = db.watch(keys.clone());
+  while let Some(outputs) = stream.next().await {
+    for (i, output) in outputs?.into_iter().enumerate() {
+      if let WatchKeyOutput::Changed { entry } = output {
+        last[i] = match entry {
+          Some(entry) => entry_to_json(&mut codec, &entry)?,
+          None => json!({
+            "key": key::key_to_json(&key::decode(&keys[i])?),
+            "value": null,
+            "versionstamp": null,
+          }),
+        };
+      }
+    }
+    println!("{}", Value::Array(last.clone()));
+  }
+  Ok(())
+}
+
+/// Pages through the keys that start with a prefix, like `Deno.Kv.list()`
+/// does with its cursor.
+pub struct PrefixPages {
+  start: Vec<u8>,
+  end: Vec<u8>,
+  reverse: bool,
+}
+
+impl PrefixPages {
+  pub fn new(prefix: &[u8], reverse: bool) -> Self {
+    Self {
+      start: prefix.iter().copied().chain(Some(0)).collect(),
+      end: prefix.iter().copied().chain(Some(0xff)).collect(),
+      reverse,
+    }
+  }
+
+  pub async fn next(
+    &mut self,
+    db: &RcDynamicDb,
+    limit: u32,
+  ) -> Result<Vec<KvEntry>, AnyError> {
+    if self.start >= self.end {
+      return Ok(Vec::new());
+    }
+    let range = ReadRange {
+      start: self.start.clone(),
+      end: self.end.clone(),
+      limit: NonZeroU32::new(limit).unwrap(),
+      reverse: self.reverse,
+    };
+    let entries = db
+      .snapshot_read(
+        vec![range],
+        SnapshotReadOptions {
+          consistency: Consistency::Strong,
+        },
+      )
+      .await?
+      .pop()
+      .map(|output| output.entries)
+      .unwrap_or_default();
+    if let Some(last) = entries.last() {
+      if self.reverse {
+        self.end = last.key.clone();
+      } else {
+        self.start = last.key.iter().copied().chain(Some(0)).collect();
+      }
+    }
+    Ok(entries)
+  }
+}
+
+async fn read_key(
+  db: &RcDynamicDb,
+  key: Vec<u8>,
+) -> Result<Option<KvEntry>, AnyError> {
+  let end = key.iter().copied().chain(Some(0)).collect();
+  let range = ReadRange {
+    start: key,
+    end,
+    limit: NonZeroU32::new(1).unwrap(),
+    reverse: false,
+  };
+  let outputs = db
+    .snapshot_read(
+      vec![range],
+      SnapshotReadOptions {
+        consistency: Consistency::Strong,
+      },
+    )
+    .await?;
+  Ok(
+    outputs
+      .into_iter()
+      .next()
+      .and_then(|o| o.entries.into_iter().next()),
+  )
+}
+
+async fn write(
+  db: &RcDynamicDb,
+  key: Vec<u8>,
+  kind: MutationKind,
+) -> Result<(), AnyError> {
+  let write = AtomicWrite {
+    checks: Vec::new(),
+    mutations: vec![Mutation {
+      key,
+      kind,
+      expire_at: None,
+    }],
+    enqueues: Vec::new(),
+  };
+  if db.atomic_write(write).await?.is_none() {
+    bail!("The write was rejected by the database");
+  }
+  Ok(())
+}
+
+fn entry_to_json(
+  codec: &mut ValueCodec,
+  entry: &KvEntry,
+) -> Result<Value, AnyError> {
+  Ok(json!({
+    "key": key::key_to_json(&key::decode(&entry.key)?),
+    "value": codec.to_json(&entry.value)?,
+    "versionstamp": faster_hex::hex_string(&entry.versionstamp),
+  }))
+}
@@ -0,0 +1,225 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! `deno kv export` and `deno kv import` move entries as newline-delimited
+//! JSON, one `{ "key", "value", "versionstamp" }` object per line. Values
+//! keep the encoding they are stored in, so an import restores them exactly
+//! (but with new versionstamps).
+
+use std::io::BufRead;
+use std::io::BufWriter;
+use std::io::Write;
+
+use deno_core::anyhow::Context;
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+use deno_core::serde_json::Value;
+use deno_runtime::deno_kv::KvConfig;
+use deno_runtime::deno_kv::dynamic::RcDynamicDb;
+use denokv_proto::AtomicWrite;
+use denokv_proto::Database;
+use denokv_proto::Key;
+use denokv_proto::KvValue;
+use denokv_proto::Mutation;
+use denokv_proto::MutationKind;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::data::PAGE_SIZE;
+use super::data::PrefixPages;
+use super::key;
+use super::value::ExportedValue;
+use crate::args::KvExportFlags;
+use crate::args::KvImportFlags;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ExportedEntry {
+  key: Value,
+  value: ExportedValue,
+  versionstamp: String,
+}
+
+pub async fn export(
+  db: &RcDynamicDb,
+  flags: KvExportFlags,
+) -> Result<(), AnyError> {
+  let prefix = match &flags.prefix {
+    Some(prefix) => key::parse_key(prefix)?,
+    None => Key(Vec::new()),
+  };
+  let mut writer: Box<dyn Write> = match &flags.output {
+    Some(output) => Box::new(BufWriter::new(
+      std::fs::File::create(output)
+        .with_context(|| format!("Failed to create '{output}'"))?,
+    )),
+    None => Box::new(BufWriter::new(std::io::stdout().lock())),
+  };
+  let mut pages = PrefixPages::new(&key::encode(&prefix)?, false);
+  let mut count = 0;
+  loop {
+    let entries = pages.next(db, PAGE_SIZE).await?;
+    for entry in &entries {
+      let exported = ExportedEntry {
+        key: key::key_to_json(&key::decode(&entry.key)?),
+        value: ExportedValue::from(&entry.value),
+        versionstamp: faster_hex::hex_string(&entry.versionstamp),
+      };
+      serde_json::to_writer(&mut writer, &exported)?;
+      writer.write_all(b"\n")?;
+    }
+    count += entries.len();
+    if entries.len() < PAGE_SIZE as usize {
+      break;
+    }
+  }
+  writer.flush()?;
+  if let Some(output) = &flags.output {
+    log::info!("Exported {count} entries to {output}");
+  }
+  Ok(())
+}
+
+pub async fn import(
+  db: &RcDynamicDb,
+  flags: KvImportFlags,
+) -> Result<(), AnyError> {
+  let reader: Box<dyn BufRead> = match &flags.input {
+    Some(input) => Box::new(std::io::BufReader::new(
+      std::fs::File::open(input)
+        .with_context(|| format!("Failed to open '{input}'"))?,
+    )),
+    None => Box::new(std::io::stdin().lock()),
+  };
+  let mut batch = MutationBatch::new(KvConfig::builder().build());
+  let mut count = 0;
+  for (i, line) in reader.lines().enumerate() {
+    let line = line?;
+    if line.trim().is_empty() {
+      continue;
+    }
+    let mutation = parse_entry(&line)
+      .with_context(|| format!("Invalid entry on line {}", i + 1))?;
+    if let Some(write) = batch.push(mutation) {
+      commit(db, write).await?;
+    }
+    count += 1;
+  }
+  if let Some(write) = batch.finish() {
+    commit(db, write).await?;
+  }
+  log::info!("Imported {count} entries");
+  Ok(())
+}
+
+fn parse_entry(line: &str) -> Result<Mutation, AnyError> {
+  let entry = serde_json::from_str::<ExportedEntry>(line)?;
+  let key = key::encode(&key::key_from_json(&entry.key)?)?;
+  Ok(Mutation {
+    key,
+    kind: MutationKind::Set(KvValue::try_from(entry.value)?),
+    expire_at: None,
+  })
+}
+
+async fn commit(db: &RcDynamicDb, write: AtomicWrite) -> Result<(), AnyError> {
+  if db.atomic_write(write).await?.is_none() {
+    bail!("The import was rejected by the database");
+  }
+  Ok(())
+}
+
+/// Groups mutations into atomic writes that stay within the limits a
+/// `Deno.Kv` write has, which KV Connect servers enforce.
+struct MutationBatch {
+  config: KvConfig,
+  mutations: Vec<Mutation>,
+  size: usize,
+}
+
+impl MutationBatch {
+  fn new(config: KvConfig) -> Self {
+    Self {
+      config,
+      mutations: Vec::new(),
+      size: 0,
+    }
+  }
+
+  /// Adds a mutation, returning the previous batch if it didn't fit.
+  fn push(&mut self, mutation: Mutation) -> Option<AtomicWrite> {
+    let size = mutation.key.len()
+      + match &mutation.kind {
+        MutationKind::Set(value) => value_size(value),
+        _ => 0,
+      };
+    let full = self.mutations.len() >= self.config.max_mutations
+      || self.size + size > self.config.max_total_mutation_size_bytes;
+    let write = if full { self.finish() } else { None };
+    self.size += size;
+    self.mutations.push(mutation);
+    write
+  }
+
+  fn finish(&mut self) -> Option<AtomicWrite> {
+    if self.mutations.is_empty() {
+      return None;
+    }
+    self.size = 0;
+    Some(AtomicWrite {
+      checks: Vec::new(),
+      mutations: std::mem::take(&mut self.mutations),
+      enqueues: Vec::new(),
+    })
+  }
+}
+
+fn value_size(value: &KvValue) -> usize {
+  match value {
+    KvValue::V8(bytes) | KvValue::Bytes(bytes) => bytes.len(),
+    KvValue::U64(_) => 8,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn set(key: &str, len: usize) -> Mutation {
+    Mutation {
+      key: key.as_bytes().to_vec(),
+      kind: MutationKind::Set(KvValue::Bytes(vec![0; len])),
+      expire_at: None,
+    }
+  }
+
+  #[test]
+  fn batches_within_limits() {
+    let mut config = KvConfig::builder();
+    config.max_mutations(2).max_total_mutation_size_bytes(100);
+    let mut batch = MutationBatch::new(config.build());
+    assert!(batch.push(set("a", 10)).is_none());
+    assert!(batch.push(set("b", 10)).is_none());
+    // too many mutations
+    let write = batch.push(set("c", 60)).unwrap();
+    assert_eq!(write.mutations.len(), 2);
+    // too large
+    let write = batch.push(set("d", 60)).unwrap();
+    assert_eq!(write.mutations.len(), 1);
+    assert_eq!(batch.finish().unwrap().mutations.len(), 1);
+    assert!(batch.finish().is_none());
+  }
+
+  #[test]
+  fn parses_exported_entries() {
+    let mutation = parse_entry(
+      r#"{"key":["a",1],"value":{"type":"u64","value":"5"},"versionstamp":"00000000000000010000"}"#,
+    )
+    .unwrap();
+    assert_eq!(
+      key::decode(&mutation.key).unwrap().0,
+      key::parse_key(r#"["a",1]"#).unwrap().0
+    );
+    assert!(matches!(mutation.kind, MutationKind::Set(KvValue::U64(5))));
+    assert!(parse_entry(r#"{"key":["a"]}"#).is_err());
+  }
+}
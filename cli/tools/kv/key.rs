@@ -0,0 +1,130 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Keys on the command line and in exports are JSON arrays. Strings,
+//! numbers and booleans are written as themselves, and the key parts JSON
+//! can't represent as `{ "type": "bigint" | "bytes", "value": string }`,
+//! with bytes in base64.
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use deno_core::anyhow::Context;
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+use deno_core::serde_json::Value;
+use deno_core::serde_json::json;
+use denokv_proto::Key;
+use denokv_proto::KeyPart;
+use denokv_proto::decode_key;
+use denokv_proto::encode_key;
+
+/// Parses a key given on the command line, such as `["users", 1]`.
+pub fn parse_key(text: &str) -> Result<Key, AnyError> {
+  let value = serde_json::from_str::<Value>(text)
+    .with_context(|| format!("Invalid key '{text}', expected a JSON array"))?;
+  key_from_json(&value).with_context(|| format!("Invalid key '{text}'"))
+}
+
+pub fn key_from_json(value: &Value) -> Result<Key, AnyError> {
+  let Value::Array(parts) = value else {
+    bail!("expected a JSON array");
+  };
+  let parts = parts
+    .iter()
+    .map(key_part_from_json)
+    .collect::<Result<Vec<_>, _>>()?;
+  Ok(Key(parts))
+}
+
+fn key_part_from_json(value: &Value) -> Result<KeyPart, AnyError> {
+  Ok(match value {
+    Value::String(s) => KeyPart::String(s.clone()),
+    Value::Bool(true) => KeyPart::True,
+    Value::Bool(false) => KeyPart::False,
+    Value::Number(n) => KeyPart::Float(
+      n.as_f64()
+        .with_context(|| format!("number {n} can't be a key part"))?,
+    ),
+    Value::Object(object) => {
+      let value = object.get("value").and_then(Value::as_str);
+      match (object.get("type").and_then(Value::as_str), value) {
+        (Some("bigint"), Some(value)) => KeyPart::Int(
+          value
+            .parse()
+            .with_context(|| format!("invalid bigint '{value}'"))?,
+        ),
+        (Some("bytes"), Some(value)) => KeyPart::Bytes(
+          BASE64_STANDARD
+            .decode(value)
+            .with_context(|| format!("invalid base64 '{value}'"))?,
+        ),
+        _ => bail!(
+          "unsupported key part {value}, objects must be {{ \"type\": \"bigint\" | \"bytes\", \"value\": string }}"
+        ),
+      }
+    }
+    Value::Null | Value::Array(_) => bail!("unsupported key part {value}"),
+  })
+}
+
+pub fn key_to_json(key: &Key) -> Value {
+  Value::Array(key.0.iter().map(key_part_to_json).collect())
+}
+
+fn key_part_to_json(part: &KeyPart) -> Value {
+  match part {
+    KeyPart::String(s) => Value::String(s.clone()),
+    KeyPart::True => Value::Bool(true),
+    KeyPart::False => Value::Bool(false),
+    // JSON has no NaN or infinities, so those fall back to `null`.
+    KeyPart::Float(n) => json!(n),
+    KeyPart::Int(n) => json!({ "type": "bigint", "value": n.to_string() }),
+    KeyPart::Bytes(bytes) => {
+      json!({ "type": "bytes", "value": BASE64_STANDARD.encode(bytes) })
+    }
+  }
+}
+
+pub fn encode(key: &Key) -> Result<Vec<u8>, AnyError> {
+  Ok(encode_key(key)?)
+}
+
+pub fn decode(bytes: &[u8]) -> Result<Key, AnyError> {
+  Ok(decode_key(bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn round_trips_key_parts() {
+    let key = parse_key(
+      r#"["users", 1.5, true, false, {"type": "bigint", "value": "-12"}, {"type": "bytes", "value": "AQID"}]"#,
+    )
+    .unwrap();
+    assert_eq!(
+      key.0,
+      vec![
+        KeyPart::String("users".to_string()),
+        KeyPart::Float(1.5),
+        KeyPart::True,
+        KeyPart::False,
+        KeyPart::Int((-12).into()),
+        KeyPart::Bytes(vec![1, 2, 3]),
+      ]
+    );
+    let json = key_to_json(&key);
+    assert_eq!(key_from_json(&json).unwrap().0, key.0);
+    assert_eq!(decode(&encode(&key).unwrap()).unwrap().0, key.0);
+  }
+
+  #[test]
+  fn rejects_invalid_keys() {
+    assert!(parse_key("users").is_err());
+    assert!(parse_key(r#"{"a": 1}"#).is_err());
+    assert!(parse_key("[null]").is_err());
+    assert!(parse_key(r#"[{"type": "date", "value": "x"}]"#).is_err());
+    assert!(parse_key(r#"[{"type": "bigint", "value": "1.5"}]"#).is_err());
+  }
+}
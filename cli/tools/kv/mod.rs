@@ -0,0 +1,115 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use deno_core::OpState;
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+use deno_lib::version::DENO_VERSION_INFO;
+use deno_runtime::deno_kv::DatabaseHandler;
+use deno_runtime::deno_kv::dynamic::MultiBackendDbHandler;
+use deno_runtime::deno_kv::dynamic::RcDynamicDb;
+use deno_runtime::deno_kv::remote::HttpOptions;
+use deno_runtime::deno_permissions::PermissionsContainer;
+use deno_runtime::deno_tls::TlsKeys;
+
+use crate::args::Flags;
+use crate::args::KvFlags;
+use crate::factory::CliFactory;
+
+mod backup;
+mod data;
+mod export;
+mod key;
+mod serve;
+mod value;
+
+pub async fn kv(flags: Arc<Flags>, kv_flags: KvFlags) -> Result<(), AnyError> {
+  let factory = CliFactory::from_flags(flags);
+  match kv_flags {
+    KvFlags::Serve(serve_flags) => serve::serve(&factory, serve_flags).await,
+    KvFlags::List(flags) => {
+      let db = open_required_database(&factory, &flags.path).await?;
+      data::list(&db, flags).await
+    }
+    KvFlags::Get(flags) => {
+      let db = open_required_database(&factory, &flags.path).await?;
+      data::get(&db, flags).await
+    }
+    KvFlags::Set(flags) => {
+      let db = open_required_database(&factory, &flags.path).await?;
+      data::set(&db, flags).await
+    }
+    KvFlags::Delete(flags) => {
+      let db = open_required_database(&factory, &flags.path).await?;
+      data::delete(&db, flags).await
+    }
+    KvFlags::Watch(flags) => {
+      let db = open_required_database(&factory, &flags.path).await?;
+      data::watch(&db, flags).await
+    }
+    KvFlags::Export(flags) => {
+      let db = open_required_database(&factory, &flags.path).await?;
+      export::export(&db, flags).await
+    }
+    KvFlags::Import(flags) => {
+      let db = open_required_database(&factory, &flags.path).await?;
+      export::import(&db, flags).await
+    }
+    KvFlags::Backup(flags) => {
+      let db = open_required_database(&factory, &flags.path).await?;
+      backup::backup(&db, flags).await
+    }
+    KvFlags::Restore(flags) => {
+      let db = open_required_database(&factory, &flags.path).await?;
+      backup::restore(&db, flags).await
+    }
+  }
+}
+
+/// Opens the database at `--path`, or at `DENO_KV_DEFAULT_PATH`. Unlike
+/// `Deno.openKv()`, there's no per-project default to fall back to.
+async fn open_required_database(
+  factory: &CliFactory,
+  path: &Option<String>,
+) -> Result<RcDynamicDb, AnyError> {
+  let has_default_path =
+    std::env::var("DENO_KV_DEFAULT_PATH").is_ok_and(|path| !path.is_empty());
+  if path.is_none() && !has_default_path {
+    bail!(
+      "Missing database. Pass --path or set the DENO_KV_DEFAULT_PATH environment variable."
+    );
+  }
+  open_database(factory, path.clone()).await
+}
+
+/// Opens a database the way `Deno.openKv()` does, so `path` may be a file,
+/// `:memory:` or a KV Connect URL, but with all permissions granted.
+async fn open_database(
+  factory: &CliFactory,
+  path: Option<String>,
+) -> Result<RcDynamicDb, AnyError> {
+  let cli_options = factory.cli_options()?;
+  let handler = MultiBackendDbHandler::remote_or_sqlite(
+    None,
+    cli_options.seed(),
+    HttpOptions {
+      user_agent: DENO_VERSION_INFO.user_agent.to_string(),
+      root_cert_store_provider: Some(
+        factory.root_cert_store_provider().clone(),
+      ),
+      proxy: None,
+      unsafely_ignore_certificate_errors: cli_options
+        .unsafely_ignore_certificate_errors()
+        .clone(),
+      client_cert_chain_and_key: TlsKeys::Null,
+    },
+  );
+  let mut state = OpState::new(None);
+  state.put(PermissionsContainer::allow_all(
+    factory.permission_desc_parser()?.clone(),
+  ));
+  Ok(handler.open(Rc::new(RefCell::new(state)), path).await?)
+}
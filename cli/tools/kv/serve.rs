@@ -0,0 +1,429 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! `deno kv serve` hosts a database over KV Connect, the protocol
+//! `Deno.openKv()` uses for remote databases, so that several local
+//! processes can share one database the way they would in production.
+
+use std::convert::Infallible;
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use deno_core::anyhow::Context;
+use deno_core::anyhow::anyhow;
+use deno_core::error::AnyError;
+use deno_core::futures::StreamExt;
+use deno_core::futures::future;
+use deno_core::serde_json;
+use deno_core::serde_json::json;
+use deno_core::unsync::spawn;
+use deno_core::url::Url;
+use deno_runtime::deno_kv::dynamic::RcDynamicDb;
+use deno_terminal::colors;
+use denokv_proto::AtomicWrite;
+use denokv_proto::Consistency;
+use denokv_proto::Database;
+use denokv_proto::ReadRange;
+use denokv_proto::SnapshotReadOptions;
+use denokv_proto::datapath as pb;
+use http::Method;
+use http::Request;
+use http::Response;
+use http::StatusCode;
+use http::header::AUTHORIZATION;
+use http::header::CONTENT_TYPE;
+use http_body_util::BodyExt;
+use http_body_util::Full;
+use http_body_util::StreamBody;
+use hyper::body::Body;
+use hyper::body::Bytes;
+use hyper::body::Frame;
+use hyper::body::Incoming;
+use hyper_util::rt::TokioIo;
+use prost_denokv::Message;
+use serde::Deserialize;
+use subtle::ConstantTimeEq;
+use tokio::net::TcpListener;
+use tokio::net::TcpStream;
+
+use crate::args::KvServeFlags;
+use crate::factory::CliFactory;
+
+/// Version 2 added per-endpoint consistency and version 3 added watching
+/// keys.
+const SUPPORTED_PROTOCOL_VERSIONS: [u64; 3] = [1, 2, 3];
+
+const ACCESS_TOKEN_ENV_VAR: &str = "DENO_KV_ACCESS_TOKEN";
+
+type ResponseBody = Pin<Box<dyn Body<Data = Bytes, Error = Infallible>>>;
+
+struct ServerState {
+  db: RcDynamicDb,
+  database_id: uuid::Uuid,
+  access_token: String,
+  /// Where clients send their data requests, from the metadata exchange.
+  endpoint_url: String,
+}
+
+pub async fn serve(
+  factory: &CliFactory,
+  serve_flags: KvServeFlags,
+) -> Result<(), AnyError> {
+  let access_token = match serve_flags.access_token {
+    Some(token) => token,
+    None => std::env::var(ACCESS_TOKEN_ENV_VAR).map_err(|_| {
+      anyhow!(
+        "Missing access token. Pass --access-token or set the {} environment variable.",
+        ACCESS_TOKEN_ENV_VAR
+      )
+    })?,
+  };
+  let path = serve_flags.path.unwrap_or_else(|| ":memory:".to_string());
+  let db = super::open_database(factory, Some(path.clone())).await?;
+  let listener =
+    TcpListener::bind((serve_flags.host.as_str(), serve_flags.port))
+      .await
+      .with_context(|| {
+        format!(
+          "Failed to listen on {}:{}",
+          serve_flags.host, serve_flags.port
+        )
+      })?;
+  let local_addr = listener.local_addr()?;
+  let public_url = match serve_flags.public_url {
+    Some(url) => Url::parse(&url)
+      .with_context(|| format!("Invalid public URL '{url}'"))?
+      .to_string(),
+    None => format!("http://{}/", connectable_addr(local_addr)),
+  };
+  log::info!(
+    "{} {} on http://{}/ (open it with Deno.openKv(\"{}\"))",
+    colors::green("Serving"),
+    path,
+    local_addr,
+    public_url,
+  );
+
+  let state = Rc::new(ServerState {
+    db,
+    database_id: uuid::Uuid::new_v5(
+      &uuid::Uuid::NAMESPACE_URL,
+      path.as_bytes(),
+    ),
+    access_token,
+    endpoint_url: format!("{}/v2", public_url.trim_end_matches('/')),
+  });
+  loop {
+    let (stream, _) = listener.accept().await?;
+    let state = state.clone();
+    spawn(async move {
+      if let Err(err) = serve_connection(stream, state).await {
+        log::debug!("KV Connect connection error: {err:?}");
+      }
+    });
+  }
+}
+
+/// The address clients on this machine connect to, which for a wildcard
+/// address is the loopback address.
+fn connectable_addr(addr: SocketAddr) -> SocketAddr {
+  let ip = match addr.ip() {
+    IpAddr::V4(ip) if ip.is_unspecified() => IpAddr::V4(Ipv4Addr::LOCALHOST),
+    IpAddr::V6(ip) if ip.is_unspecified() => IpAddr::V6(Ipv6Addr::LOCALHOST),
+    ip => ip,
+  };
+  SocketAddr::new(ip, addr.port())
+}
+
+async fn serve_connection(
+  stream: TcpStream,
+  state: Rc<ServerState>,
+) -> Result<(), AnyError> {
+  let io = TokioIo::new(stream);
+  let service = hyper::service::service_fn(move |req| {
+    let state = state.clone();
+    async move { Ok::<_, Infallible>(handle_request(req, &state).await) }
+  });
+
+  // `Deno.openKv()` speaks HTTP/2 without TLS, while other clients may
+  // still use HTTP/1.1.
+  hyper_util::server::conn::auto::Builder::new(LocalExecutor)
+    .serve_connection(io, service)
+    .await
+    .map_err(|e| anyhow!("hyper serve error: {e}"))?;
+  Ok(())
+}
+
+/// Runs HTTP/2 streams on the current thread, as the database isn't `Send`.
+#[derive(Clone, Copy)]
+struct LocalExecutor;
+
+impl<F> hyper::rt::Executor<F> for LocalExecutor
+where
+  F: Future + 'static,
+  F::Output: 'static,
+{
+  fn execute(&self, fut: F) {
+    spawn(fut);
+  }
+}
+
+struct ApiError {
+  status: StatusCode,
+  message: String,
+}
+
+impl ApiError {
+  fn bad_request(message: impl Into<String>) -> Self {
+    Self {
+      status: StatusCode::BAD_REQUEST,
+      message: message.into(),
+    }
+  }
+
+  fn internal(err: impl std::fmt::Display) -> Self {
+    Self {
+      status: StatusCode::INTERNAL_SERVER_ERROR,
+      message: err.to_string(),
+    }
+  }
+}
+
+async fn handle_request(
+  req: Request<Incoming>,
+  state: &ServerState,
+) -> Response<ResponseBody> {
+  match route_request(req, state).await {
+    Ok(response) => response,
+    Err(err) => {
+      if err.status.is_server_error() {
+        log::warn!("KV Connect request failed: {}", err.message);
+      }
+      Response::builder()
+        .status(err.status)
+        .header(CONTENT_TYPE, "text/plain")
+        .body(full_body(err.message))
+        .unwrap()
+    }
+  }
+}
+
+async fn route_request(
+  req: Request<Incoming>,
+  state: &ServerState,
+) -> Result<Response<ResponseBody>, ApiError> {
+  if req.method() != Method::POST {
+    return Err(ApiError {
+      status: StatusCode::METHOD_NOT_ALLOWED,
+      message: "Method not allowed".to_string(),
+    });
+  }
+  let token = req
+    .headers()
+    .get(AUTHORIZATION)
+    .and_then(|value| value.to_str().ok())
+    .and_then(|value| value.strip_prefix("Bearer "));
+  let is_authorized = token.is_some_and(|token| {
+    token.as_bytes().ct_eq(state.access_token.as_bytes()).into()
+  });
+  if !is_authorized {
+    return Err(ApiError {
+      status: StatusCode::UNAUTHORIZED,
+      message: "Invalid access token".to_string(),
+    });
+  }
+  let path = req.uri().path().to_string();
+  let body = req
+    .into_body()
+    .collect()
+    .await
+    .map_err(|err| ApiError::bad_request(err.to_string()))?
+    .to_bytes();
+
+  match path.as_str() {
+    "/" | "/v2" => metadata_exchange(state, &body),
+    "/v2/snapshot_read" => snapshot_read(state, &body).await,
+    "/v2/atomic_write" => atomic_write(state, &body).await,
+    "/v2/watch" => watch(state, &body),
+    _ => Err(ApiError {
+      status: StatusCode::NOT_FOUND,
+      message: "Not found".to_string(),
+    }),
+  }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MetadataExchangeRequest {
+  #[serde(default)]
+  supported_versions: Vec<u64>,
+}
+
+/// The newest protocol version both sides speak. Clients that predate
+/// version negotiation only speak version 1.
+fn negotiate_protocol_version(client_versions: &[u64]) -> Option<u64> {
+  if client_versions.is_empty() {
+    return Some(1);
+  }
+  client_versions
+    .iter()
+    .copied()
+    .filter(|version| SUPPORTED_PROTOCOL_VERSIONS.contains(version))
+    .max()
+}
+
+fn metadata_exchange(
+  state: &ServerState,
+  body: &[u8],
+) -> Result<Response<ResponseBody>, ApiError> {
+  let request = if body.is_empty() {
+    MetadataExchangeRequest {
+      supported_versions: Vec::new(),
+    }
+  } else {
+    serde_json::from_slice::<MetadataExchangeRequest>(body).map_err(|err| {
+      ApiError::bad_request(format!("Invalid metadata exchange request: {err}"))
+    })?
+  };
+  let version = negotiate_protocol_version(&request.supported_versions)
+    .ok_or_else(|| {
+      ApiError::bad_request(format!(
+        "No supported protocol version, the server supports {SUPPORTED_PROTOCOL_VERSIONS:?}"
+      ))
+    })?;
+  let expires_at = chrono::Utc::now() + chrono::Duration::days(1);
+  let metadata = json!({
+    "version": version,
+    "databaseId": state.database_id.to_string(),
+    "endpoints": [{
+      "url": state.endpoint_url,
+      "consistency": "strong",
+    }],
+    "token": state.access_token,
+    "expiresAt": expires_at
+      .to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+  });
+  Ok(
+    Response::builder()
+      .header(CONTENT_TYPE, "application/json")
+      .body(full_body(metadata.to_string()))
+      .unwrap(),
+  )
+}
+
+async fn snapshot_read(
+  state: &ServerState,
+  body: &[u8],
+) -> Result<Response<ResponseBody>, ApiError> {
+  let request = pb::SnapshotRead::decode(body)
+    .map_err(|err| ApiError::bad_request(err.to_string()))?;
+  let ranges: Vec<ReadRange> = request
+    .try_into()
+    .map_err(|err| ApiError::bad_request(format!("{err:?}")))?;
+  let outputs = state
+    .db
+    .snapshot_read(
+      ranges,
+      SnapshotReadOptions {
+        consistency: Consistency::Strong,
+      },
+    )
+    .await
+    .map_err(ApiError::internal)?;
+  let output: pb::SnapshotReadOutput = outputs.into();
+  Ok(protobuf_response(&output))
+}
+
+async fn atomic_write(
+  state: &ServerState,
+  body: &[u8],
+) -> Result<Response<ResponseBody>, ApiError> {
+  let request = pb::AtomicWrite::decode(body)
+    .map_err(|err| ApiError::bad_request(err.to_string()))?;
+  let write: AtomicWrite = request
+    .try_into()
+    .map_err(|err| ApiError::bad_request(format!("{err:?}")))?;
+  let result = state
+    .db
+    .atomic_write(write)
+    .await
+    .map_err(ApiError::internal)?;
+  let output: pb::AtomicWriteOutput = result.into();
+  Ok(protobuf_response(&output))
+}
+
+fn watch(
+  state: &ServerState,
+  body: &[u8],
+) -> Result<Response<ResponseBody>, ApiError> {
+  let request = pb::Watch::decode(body)
+    .map_err(|err| ApiError::bad_request(err.to_string()))?;
+  let keys: Vec<Vec<u8>> = request
+    .try_into()
+    .map_err(|err| ApiError::bad_request(format!("{err:?}")))?;
+  // The stream ends on the first error; the client then reconnects.
+  let stream = state
+    .db
+    .watch(keys)
+    .take_while(|outputs| future::ready(outputs.is_ok()))
+    .filter_map(|outputs| {
+      future::ready(outputs.ok().map(|outputs| {
+        let output: pb::WatchOutput = outputs.into();
+        Ok::<_, Infallible>(Frame::data(encode_watch_frame(&output)))
+      }))
+    });
+  let body: ResponseBody = Box::pin(StreamBody::new(stream));
+  Ok(
+    Response::builder()
+      .header(CONTENT_TYPE, "application/octet-stream")
+      .body(body)
+      .unwrap(),
+  )
+}
+
+/// Watch responses are a stream of messages, each prefixed with its length
+/// as a little endian `u32`.
+fn encode_watch_frame(output: &pb::WatchOutput) -> Bytes {
+  let message = output.encode_to_vec();
+  let mut frame = Vec::with_capacity(4 + message.len());
+  frame.extend_from_slice(&(message.len() as u32).to_le_bytes());
+  frame.extend_from_slice(&message);
+  frame.into()
+}
+
+fn protobuf_response(message: &impl Message) -> Response<ResponseBody> {
+  Response::builder()
+    .header(CONTENT_TYPE, "application/x-protobuf")
+    .body(full_body(message.encode_to_vec()))
+    .unwrap()
+}
+
+fn full_body(data: impl Into<Bytes>) -> ResponseBody {
+  Box::pin(Full::new(data.into()))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn negotiates_newest_common_version() {
+    assert_eq!(negotiate_protocol_version(&[1, 2, 3]), Some(3));
+    assert_eq!(negotiate_protocol_version(&[1, 2]), Some(2));
+    assert_eq!(negotiate_protocol_version(&[2, 3, 4]), Some(3));
+    assert_eq!(negotiate_protocol_version(&[]), Some(1));
+    assert_eq!(negotiate_protocol_version(&[4]), None);
+  }
+
+  #[test]
+  fn watch_frames_are_length_prefixed() {
+    let output = pb::WatchOutput::default();
+    let frame = encode_watch_frame(&output);
+    let len = u32::from_le_bytes(frame[..4].try_into().unwrap()) as usize;
+    assert_eq!(len, frame.len() - 4);
+    assert_eq!(pb::WatchOutput::decode(&frame[4..]).unwrap(), output);
+  }
+}
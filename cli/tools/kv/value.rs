@@ -0,0 +1,266 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use deno_core::JsRuntime;
+use deno_core::RuntimeOptions;
+use deno_core::anyhow::Context;
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+use deno_core::serde_json::Value;
+use deno_core::serde_json::json;
+use deno_core::v8;
+use denokv_proto::KvValue;
+
+/// Installs the functions `ValueCodec` calls. Values JSON can't represent
+/// are written as `{ "type", "value" }` objects, like key parts are, with
+/// bytes as arrays of numbers that are converted to and from base64 in Rust.
+const CODEC_SCRIPT: &str = r#"
+globalThis.__kvCodec = {
+  toJson(bytes) {
+    const value = Deno.core.deserialize(new Uint8Array(bytes), {
+      forStorage: true,
+    });
+    return JSON.stringify(value, (_key, value) => {
+      if (typeof value === "bigint") {
+        return { type: "bigint", value: value.toString() };
+      }
+      if (value instanceof Uint8Array) {
+        return { type: "bytes", value: Array.from(value) };
+      }
+      if (value instanceof Map) {
+        return { type: "map", value: Array.from(value) };
+      }
+      if (value instanceof Set) {
+        return { type: "set", value: Array.from(value) };
+      }
+      if (value === undefined) {
+        return { type: "undefined" };
+      }
+      return value;
+    }) ?? "null";
+  },
+  fromJson(text) {
+    const value = JSON.parse(text, (_key, value) => {
+      if (value !== null && typeof value === "object" && !Array.isArray(value)) {
+        switch (value.type) {
+          case "bigint":
+            return BigInt(value.value);
+          case "bytes":
+            return new Uint8Array(value.value);
+          case "map":
+            return new Map(value.value);
+          case "set":
+            return new Set(value.value);
+          case "undefined":
+            return undefined;
+        }
+      }
+      return value;
+    });
+    return JSON.stringify(
+      Array.from(Deno.core.serialize(value, { forStorage: true })),
+    );
+  },
+};
+"#;
+
+/// Converts values between the V8 serialization `Deno.openKv()` stores them
+/// in and JSON, for printing them and setting them from the command line.
+///
+/// `KvU64` values are written as `{ "type": "u64", "value": string }`.
+pub struct ValueCodec {
+  runtime: JsRuntime,
+}
+
+impl ValueCodec {
+  pub fn new() -> Result<Self, AnyError> {
+    let mut runtime = JsRuntime::new(RuntimeOptions::default());
+    runtime.execute_script("kv_codec.js", CODEC_SCRIPT)?;
+    Ok(Self { runtime })
+  }
+
+  pub fn to_json(&mut self, value: &KvValue) -> Result<Value, AnyError> {
+    Ok(match value {
+      KvValue::V8(bytes) => {
+        let text = self.call("toJson", &serde_json::to_string(bytes)?)?;
+        let mut value = serde_json::from_str(&text)?;
+        bytes_to_base64(&mut value);
+        value
+      }
+      KvValue::Bytes(bytes) => {
+        json!({ "type": "bytes", "value": BASE64_STANDARD.encode(bytes) })
+      }
+      KvValue::U64(n) => json!({ "type": "u64", "value": n.to_string() }),
+    })
+  }
+
+  pub fn from_json(&mut self, mut value: Value) -> Result<KvValue, AnyError> {
+    if let Some(n) = tagged_value(&value, "u64") {
+      return Ok(KvValue::U64(
+        n.parse().with_context(|| format!("Invalid u64 '{n}'"))?,
+      ));
+    }
+    // `Deno.Kv` stores top-level `Uint8Array`s as raw bytes
+    if let Some(encoded) = tagged_value(&value, "bytes") {
+      return Ok(KvValue::Bytes(
+        BASE64_STANDARD
+          .decode(encoded)
+          .with_context(|| format!("Invalid base64 '{encoded}'"))?,
+      ));
+    }
+    base64_to_bytes(&mut value)?;
+    let text = serde_json::to_string(&value)?;
+    let bytes = self.call("fromJson", &serde_json::to_string(&text)?)?;
+    Ok(KvValue::V8(serde_json::from_str(&bytes)?))
+  }
+
+  /// Calls a codec function with an argument given as JS source, returning
+  /// the string it returns.
+  fn call(&mut self, function: &str, arg: &str) -> Result<String, AnyError> {
+    let result = self.runtime.execute_script(
+      "kv_codec_call.js",
+      format!("globalThis.__kvCodec.{function}({arg})"),
+    )?;
+    deno_core::scope!(scope, &mut self.runtime);
+    let result = v8::Local::new(scope, result);
+    Ok(result.to_rust_string_lossy(scope))
+  }
+}
+
+fn tagged_value<'a>(value: &'a Value, ty: &str) -> Option<&'a str> {
+  let object = value.as_object()?;
+  if object.get("type")?.as_str()? != ty {
+    return None;
+  }
+  object.get("value")?.as_str()
+}
+
+fn bytes_to_base64(value: &mut Value) {
+  match value {
+    Value::Array(items) => items.iter_mut().for_each(bytes_to_base64),
+    Value::Object(object) => {
+      if object.get("type").and_then(Value::as_str) == Some("bytes")
+        && let Some(Value::Array(items)) = object.get("value")
+      {
+        let bytes = items
+          .iter()
+          .map(|n| n.as_u64().unwrap_or_default() as u8)
+          .collect::<Vec<_>>();
+        object.insert("value".into(), BASE64_STANDARD.encode(bytes).into());
+      } else {
+        object.values_mut().for_each(bytes_to_base64);
+      }
+    }
+    _ => {}
+  }
+}
+
+fn base64_to_bytes(value: &mut Value) -> Result<(), AnyError> {
+  match value {
+    Value::Array(items) => {
+      for item in items {
+        base64_to_bytes(item)?;
+      }
+    }
+    Value::Object(object) => {
+      if object.get("type").and_then(Value::as_str) == Some("bytes") {
+        let Some(encoded) = object.get("value").and_then(Value::as_str) else {
+          bail!("Invalid bytes value, expected a base64 string");
+        };
+        let bytes = BASE64_STANDARD
+          .decode(encoded)
+          .with_context(|| format!("Invalid base64 '{encoded}'"))?;
+        object.insert("value".into(), json!(bytes));
+      } else {
+        for item in object.values_mut() {
+          base64_to_bytes(item)?;
+        }
+      }
+    }
+    _ => {}
+  }
+  Ok(())
+}
+
+/// A value as exported by `deno kv export`, in the encoding it is stored
+/// in so that importing it restores it exactly.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum ExportedValue {
+  /// Base64 of the V8 serialized value.
+  V8(String),
+  /// Base64 of a `Uint8Array` value.
+  Bytes(String),
+  U64(String),
+}
+
+impl From<&KvValue> for ExportedValue {
+  fn from(value: &KvValue) -> Self {
+    match value {
+      KvValue::V8(bytes) => Self::V8(BASE64_STANDARD.encode(bytes)),
+      KvValue::Bytes(bytes) => Self::Bytes(BASE64_STANDARD.encode(bytes)),
+      KvValue::U64(n) => Self::U64(n.to_string()),
+    }
+  }
+}
+
+impl TryFrom<ExportedValue> for KvValue {
+  type Error = AnyError;
+
+  fn try_from(value: ExportedValue) -> Result<Self, Self::Error> {
+    Ok(match value {
+      ExportedValue::V8(data) => KvValue::V8(BASE64_STANDARD.decode(data)?),
+      ExportedValue::Bytes(data) => {
+        KvValue::Bytes(BASE64_STANDARD.decode(data)?)
+      }
+      ExportedValue::U64(n) => KvValue::U64(n.parse()?),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn converts_nested_bytes() {
+    let mut value = json!({
+      "a": [{ "type": "bytes", "value": [1, 2, 3] }],
+      "b": { "type": "bigint", "value": "1" },
+    });
+    bytes_to_base64(&mut value);
+    assert_eq!(
+      value,
+      json!({
+        "a": [{ "type": "bytes", "value": "AQID" }],
+        "b": { "type": "bigint", "value": "1" },
+      })
+    );
+    base64_to_bytes(&mut value).unwrap();
+    assert_eq!(value["a"][0]["value"], json!([1, 2, 3]));
+    assert!(
+      base64_to_bytes(&mut json!({ "type": "bytes", "value": 1 })).is_err()
+    );
+  }
+
+  #[test]
+  fn exported_values_round_trip() {
+    for value in [
+      KvValue::V8(vec![255, 15, 73, 2]),
+      KvValue::Bytes(vec![1, 2]),
+      KvValue::U64(u64::MAX),
+    ] {
+      let exported = ExportedValue::from(&value);
+      let json = serde_json::to_value(&exported).unwrap();
+      let parsed: ExportedValue = serde_json::from_value(json).unwrap();
+      let restored = KvValue::try_from(parsed).unwrap();
+      assert_eq!(ExportedValue::from(&restored), ExportedValue::from(&value));
+    }
+    assert_eq!(
+      serde_json::to_value(ExportedValue::U64("1".to_string())).unwrap(),
+      json!({ "type": "u64", "value": "1" })
+    );
+  }
+}
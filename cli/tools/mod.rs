@@ -18,6 +18,7 @@ pub mod info;
 pub mod init;
 pub mod installer;
 pub mod jupyter;
+pub mod kv;
 pub mod lint;
 pub mod pack;
 pub mod pm;
@@ -27,6 +28,7 @@ pub mod run;
 pub mod serve;
 pub mod task;
 pub mod task_cache;
+pub mod task_cache_remote;
 pub mod test;
 pub mod transpile;
 pub mod unfurl_utils;
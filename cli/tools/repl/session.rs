@@ -610,6 +610,7 @@ impl ReplSession {
       let report_tests_handle = spawn(report_tests(
         self.test_event_receiver.take().unwrap(),
         (self.test_reporter_factory)(),
+        Default::default(),
       ));
       let event_tracker =
         TestEventTracker::new(self.worker.js_runtime.op_state());
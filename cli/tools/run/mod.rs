@@ -25,6 +25,7 @@ use crate::util;
 use crate::util::file_watcher::WatcherRestartMode;
 
 pub mod hmr;
+pub mod permissions_learn;
 
 pub fn check_permission_before_script(flags: &Flags) {
   if !flags.has_permission() && flags.has_permission_in_argv() {
@@ -182,6 +183,9 @@ pub async fn run_script(
   let factory = CliFactory::from_flags(flags.clone());
   let cli_options = factory.cli_options()?;
   crate::boot_phase("after cli_options");
+  if let Some(set_name) = &flags.permissions_learn {
+    permissions_learn::start(cli_options, set_name.clone())?;
+  }
   let deno_dir = factory.deno_dir()?;
   let http_client = factory.http_client_provider();
   let workspace_resolver = factory.workspace_resolver().await?;
@@ -241,6 +245,9 @@ pub async fn run_script(
     .await
     .inspect_err(|e| deno_telemetry::report_event("uncaught_exception", e))?;
   crate::boot_phase("after worker.run (exit)");
+  if flags.permissions_learn.is_some() {
+    permissions_learn::finish()?;
+  }
   Ok(exit_code)
 }
 
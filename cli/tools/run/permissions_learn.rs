@@ -0,0 +1,216 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! `deno run --permissions-learn` runs a program with all permissions and
+//! turns the descriptors it used into a permission set in the config file,
+//! or into `--allow-*` flags when there is no config file.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use deno_core::parking_lot::Mutex;
+use deno_path_util::url_to_file_path;
+use deno_runtime::deno_permissions::learn::LearnedPermission;
+use deno_runtime::deno_permissions::learn::LearnedPermissions;
+use deno_runtime::deno_permissions::learn::start_learning_permissions;
+use deno_runtime::deno_permissions::learn::take_learned_permissions;
+use jsonc_parser::cst::CstInputValue;
+use jsonc_parser::cst::CstRootNode;
+
+use crate::args::CliOptions;
+use crate::colors;
+
+/// The permissions in the order they are written out.
+const PERMISSION_NAMES: [&str; 8] =
+  ["read", "write", "net", "env", "sys", "run", "ffi", "import"];
+
+struct LearnOutput {
+  set_name: String,
+  config_file: Option<PathBuf>,
+}
+
+static LEARN_OUTPUT: Mutex<Option<LearnOutput>> = Mutex::new(None);
+
+/// Starts recording the permissions used. They are written out by [`finish`],
+/// or when the process exits early, e.g. with `Deno.exit()` or Ctrl+C.
+pub fn start(
+  cli_options: &CliOptions,
+  set_name: String,
+) -> Result<(), AnyError> {
+  let config_file = cli_options
+    .start_dir
+    .member_or_root_deno_json()
+    .map(|config| url_to_file_path(&config.specifier))
+    .transpose()?;
+  *LEARN_OUTPUT.lock() = Some(LearnOutput {
+    set_name,
+    config_file,
+  });
+  start_learning_permissions();
+  deno_signals::before_exit(finish_on_exit);
+  Ok(())
+}
+
+fn finish_on_exit() {
+  if let Err(err) = finish() {
+    log::error!(
+      "{} Failed writing the learned permissions: {:#}",
+      colors::red("error:"),
+      err
+    );
+  }
+}
+
+pub fn finish() -> Result<(), AnyError> {
+  let Some(output) = LEARN_OUTPUT.lock().take() else {
+    return Ok(());
+  };
+  let learned = take_learned_permissions();
+  match &output.config_file {
+    Some(config_file) => {
+      write_permission_set(config_file, &output.set_name, &learned)?;
+      let flag = if output.set_name == "default" {
+        "-P".to_string()
+      } else {
+        format!("--permission-set={}", output.set_name)
+      };
+      log::info!(
+        "{} the permissions used to the \"{}\" permission set in {}. Run with {} to use them.",
+        colors::green("Wrote"),
+        output.set_name,
+        config_file.display(),
+        colors::bold(flag),
+      );
+    }
+    None => {
+      let flags = learned_flags(&learned);
+      log::info!(
+        "{} {}",
+        colors::green("Permissions used:"),
+        if flags.is_empty() {
+          "none".to_string()
+        } else {
+          flags.join(" ")
+        }
+      );
+    }
+  }
+  Ok(())
+}
+
+fn learned_flags(learned: &LearnedPermissions) -> Vec<String> {
+  PERMISSION_NAMES
+    .iter()
+    .filter_map(|name| match learned.get(*name)? {
+      LearnedPermission::All => Some(format!("--allow-{name}")),
+      LearnedPermission::Some(values) => Some(format!(
+        "--allow-{}={}",
+        name,
+        values.iter().cloned().collect::<Vec<_>>().join(",")
+      )),
+    })
+    .collect()
+}
+
+fn write_permission_set(
+  config_file: &Path,
+  set_name: &str,
+  learned: &LearnedPermissions,
+) -> Result<(), AnyError> {
+  let text = std::fs::read_to_string(config_file).with_context(|| {
+    format!("Reading config file '{}'", config_file.display())
+  })?;
+  let cst =
+    CstRootNode::parse(&text, &Default::default()).with_context(|| {
+      format!("Parsing config file '{}'", config_file.display())
+    })?;
+  let base_dir = config_file.parent().unwrap();
+  let set = CstInputValue::Object(
+    PERMISSION_NAMES
+      .iter()
+      .filter_map(|name| {
+        let value = match learned.get(*name)? {
+          LearnedPermission::All => CstInputValue::Bool(true),
+          LearnedPermission::Some(values) => CstInputValue::Array(
+            values
+              .iter()
+              .map(|value| {
+                CstInputValue::String(config_value(name, value, base_dir))
+              })
+              .collect(),
+          ),
+        };
+        Some((name.to_string(), value))
+      })
+      .collect(),
+  );
+
+  let root = cst.object_value_or_set();
+  let permissions = root.object_value_or_set("permissions");
+  match permissions.get(set_name) {
+    Some(prop) => prop.set_value(set),
+    None => permissions.append(set_name, set),
+  }
+  root.ensure_multiline();
+  std::fs::write(config_file, cst.to_string()).with_context(|| {
+    format!("Failed writing to '{}'", config_file.display())
+  })?;
+  Ok(())
+}
+
+/// Paths in a permission set are relative to the config file, so paths
+/// inside its directory are written relative to it.
+fn config_value(name: &str, value: &str, base_dir: &Path) -> String {
+  if !matches!(name, "read" | "write" | "ffi") {
+    return value.to_string();
+  }
+  match Path::new(value).strip_prefix(base_dir) {
+    Ok(relative) if relative.as_os_str().is_empty() => ".".to_string(),
+    Ok(relative) => {
+      format!("./{}", relative.to_string_lossy().replace('\\', "/"))
+    }
+    Err(_) => value.to_string(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeSet;
+
+  use super::*;
+
+  #[test]
+  fn flags_from_learned() {
+    let learned = LearnedPermissions::from([
+      ("env".to_string(), LearnedPermission::All),
+      (
+        "net".to_string(),
+        LearnedPermission::Some(BTreeSet::from([
+          "deno.land:443".to_string(),
+          "localhost:8000".to_string(),
+        ])),
+      ),
+    ]);
+    assert_eq!(
+      learned_flags(&learned),
+      vec![
+        "--allow-net=deno.land:443,localhost:8000".to_string(),
+        "--allow-env".to_string(),
+      ]
+    );
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn relative_config_values() {
+    let base = Path::new("/project");
+    assert_eq!(
+      config_value("read", "/project/data.txt", base),
+      "./data.txt"
+    );
+    assert_eq!(config_value("read", "/project", base), ".");
+    assert_eq!(config_value("write", "/tmp/out", base), "/tmp/out");
+    assert_eq!(config_value("env", "/project", base), "/project");
+  }
+}
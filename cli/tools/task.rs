@@ -26,6 +26,7 @@ use deno_core::futures::StreamExt;
 use deno_core::futures::future::LocalBoxFuture;
 use deno_core::futures::stream::futures_unordered;
 use deno_core::serde_json::Value;
+use deno_core::serde_json::json;
 use deno_core::url::Url;
 use deno_npm_installer::PackageCaching;
 use deno_path_util::normalize_path;
@@ -34,11 +35,13 @@ use deno_task_shell::ShellCommand;
 use indexmap::IndexMap;
 use indexmap::IndexSet;
 use regex::Regex;
+use serde::Serialize;
 
 use crate::args::CliLockfile;
 use crate::args::CliOptions;
 use crate::args::Flags;
 use crate::args::TaskFlags;
+use crate::args::TaskGraphFormat;
 use crate::colors;
 use crate::factory::CliFactory;
 use crate::node::CliNodeResolver;
@@ -46,6 +49,9 @@ use crate::npm::CliNpmInstaller;
 use crate::npm::CliNpmResolver;
 use crate::task_runner;
 use crate::task_runner::run_future_forwarding_signals;
+use crate::util::file_watcher;
+use crate::util::file_watcher::WatcherCommunicator;
+use crate::util::file_watcher::WatcherRestartMode;
 use crate::util::fs::canonicalize_path;
 use crate::util::progress_bar::ProgressBar;
 
@@ -58,6 +64,41 @@ struct PackageTaskInfo {
 pub async fn execute_script(
   flags: Arc<Flags>,
   task_flags: TaskFlags,
+) -> Result<i32, AnyError> {
+  if let Some(watch_flags) = &task_flags.watch {
+    let watch_flags = watch_flags.clone();
+    file_watcher::watch_recv(
+      flags,
+      file_watcher::PrintConfig::new("Task", !watch_flags.no_clear_screen),
+      WatcherRestartMode::Manual,
+      move |flags, watcher_communicator, _changed_paths| {
+        let task_flags = task_flags.clone();
+        // A config change forces a full restart, which switches the watcher
+        // to automatic mode; rerunning individual tasks needs manual mode.
+        watcher_communicator.change_restart_mode(WatcherRestartMode::Manual);
+        Ok(async move {
+          let result =
+            run_script(flags, &task_flags, Some(watcher_communicator.clone()))
+              .await;
+          // Only reached when the tasks couldn't be resolved, so restart
+          // from scratch on the next change.
+          watcher_communicator
+            .change_restart_mode(WatcherRestartMode::Automatic);
+          result
+        })
+      },
+    )
+    .await?;
+    return Ok(0);
+  }
+
+  run_script(flags, &task_flags, None).await
+}
+
+async fn run_script(
+  flags: Arc<Flags>,
+  task_flags: &TaskFlags,
+  watcher_communicator: Option<Arc<WatcherCommunicator>>,
 ) -> Result<i32, AnyError> {
   let factory = CliFactory::from_flags(flags.clone());
   let cli_options = factory.cli_options()?;
@@ -190,6 +231,21 @@ pub async fn execute_script(
   let progress_bar = factory.text_only_progress_bar();
   let task_cache =
     crate::tools::task_cache::TaskCache::new(&factory.deno_dir()?.root);
+  let remote_task_cache = match cli_options
+    .workspace()
+    .root_deno_json()
+    .map(|deno_json| deno_json.to_task_cache_config())
+    .transpose()?
+    .and_then(|config| config.remote)
+  {
+    Some(config) => {
+      Some(crate::tools::task_cache_remote::RemoteTaskCache::new(
+        factory.http_client_provider().get_or_create()?,
+        &config,
+      ))
+    }
+    None => None,
+  };
   let mut env_vars = task_runner::real_env_vars();
 
   if flags.tunnel {
@@ -210,7 +266,7 @@ pub async fn execute_script(
     .unwrap_or_else(|| NonZeroUsize::new(2).unwrap());
 
   let task_runner = TaskRunner {
-    task_flags: &task_flags,
+    task_flags,
     npm_installer: npm_installer.map(|n| n.as_ref()),
     npm_resolver,
     node_resolver: node_resolver.as_ref(),
@@ -220,7 +276,9 @@ pub async fn execute_script(
     maybe_lockfile,
     concurrency: no_of_concurrent_tasks.into(),
     task_cache: &task_cache,
+    remote_task_cache: remote_task_cache.as_ref(),
     task_fingerprints: std::cell::RefCell::new(HashMap::new()),
+    watcher_communicator,
   };
 
   let kill_signal = KillSignal::default();
@@ -337,6 +395,19 @@ fn task_label_name<'a>(
   workspace_folder_dir_name(task.task_or_script.folder_url())
 }
 
+/// `pkg#task` for tasks of a workspace member, otherwise just the task name.
+fn task_display_label(
+  task: &ResolvedTask<'_>,
+  cli_options: &CliOptions,
+) -> String {
+  let workspace_root_url: &Url =
+    cli_options.workspace().root_dir_url().as_ref();
+  match task_label_name(task, workspace_root_url) {
+    Some(package) => format!("{}#{}", package, task.name),
+    None => task.name.to_string(),
+  }
+}
+
 /// Length of the inner part of the prefix label (without the surrounding
 /// brackets) — used to right-align brackets across concurrent tasks.
 fn label_inner_len(
@@ -399,11 +470,18 @@ struct TaskRunner<'a> {
   maybe_lockfile: Option<Arc<CliLockfile>>,
   concurrency: usize,
   task_cache: &'a crate::tools::task_cache::TaskCache,
+  /// Shared store consulted on a local cache miss and populated after a
+  /// successful run, when `taskCache.remote` is configured.
+  remote_task_cache:
+    Option<&'a crate::tools::task_cache_remote::RemoteTaskCache>,
   /// Per-task cache fingerprints, keyed by `ResolvedTask::id`, recorded as
   /// each task is consulted/run so dependents can fold them into their own
   /// cache key (the dependency cascade). `None` marks a task that always runs
   /// (non-cacheable), which forces its dependents to be non-cacheable too.
   task_fingerprints: std::cell::RefCell<HashMap<usize, Option<String>>>,
+  /// Set under `--watch`, where tasks are rerun as their inputs change
+  /// instead of running once.
+  watcher_communicator: Option<Arc<WatcherCommunicator>>,
 }
 
 impl<'a> TaskRunner<'a> {
@@ -457,6 +535,27 @@ impl<'a> TaskRunner<'a> {
       return Ok(1);
     }
 
+    if self.task_flags.dry_run || self.task_flags.graph.is_some() {
+      let planned = self.plan_tasks(&sorted, argv)?;
+      let mut stdout = std::io::stdout();
+      match self.task_flags.graph {
+        Some(TaskGraphFormat::Dot) => {
+          write_task_graph_dot(&mut stdout, &planned)?
+        }
+        Some(TaskGraphFormat::Json) => {
+          crate::display::write_json_to_stdout(&json!({ "tasks": planned }))?
+        }
+        None => write_task_plan(&mut stdout, &planned)?,
+      }
+      return Ok(0);
+    }
+
+    if let Some(watcher_communicator) = &self.watcher_communicator {
+      return self
+        .watch_tasks(sorted, kill_signal, argv, watcher_communicator)
+        .await;
+    }
+
     self.run_tasks_in_parallel(sorted, kill_signal, argv).await
   }
 
@@ -472,25 +571,25 @@ impl<'a> TaskRunner<'a> {
     )
   }
 
-  async fn run_tasks_in_parallel(
+  /// Prefix label settings for each task that could run concurrently with
+  /// another one, keyed by task id.
+  fn task_parallel_infos(
     &self,
-    tasks: Vec<ResolvedTask<'a>>,
-    kill_signal: &KillSignal,
-    args: &[String],
-  ) -> Result<i32, deno_core::anyhow::Error> {
+    tasks: &[ResolvedTask<'a>],
+  ) -> HashMap<usize, ParallelInfo> {
+    if self.task_flags.no_prefix {
+      return HashMap::new();
+    }
     let workspace_root_url: &Url =
       self.cli_options.workspace().root_dir_url().as_ref();
-    let concurrent_task_color_indices = if self.task_flags.no_prefix {
-      HashMap::new()
-    } else {
-      compute_concurrent_task_color_indices(&tasks)
-    };
+    let concurrent_task_color_indices =
+      compute_concurrent_task_color_indices(tasks);
     // Include the package name in prefix labels when concurrent tasks span
     // more than one workspace member.
     let include_package = {
       let mut seen: HashSet<&Url> = HashSet::new();
       let mut multi = false;
-      for t in &tasks {
+      for t in tasks {
         if !concurrent_task_color_indices.contains_key(&t.id) {
           continue;
         }
@@ -507,15 +606,83 @@ impl<'a> TaskRunner<'a> {
       .map(|t| label_inner_len(t, include_package, workspace_root_url))
       .max()
       .unwrap_or(0);
+    concurrent_task_color_indices
+      .into_iter()
+      .map(|(id, color_index)| {
+        (
+          id,
+          ParallelInfo {
+            color_index,
+            label_pad_width: max_label_len,
+            include_package,
+          },
+        )
+      })
+      .collect()
+  }
+
+  fn run_resolved_task<'b>(
+    &'b self,
+    task: &'b ResolvedTask<'a>,
+    kill_signal: KillSignal,
+    args: &'a [String],
+    parallel_info: Option<ParallelInfo>,
+  ) -> LocalBoxFuture<'b, Result<i32, AnyError>> {
+    let workspace_root_url: &Url =
+      self.cli_options.workspace().root_dir_url().as_ref();
+    let label_name = task_label_name(task, workspace_root_url);
+    let dep_fingerprints = self.dependency_fingerprints(&task.dependencies);
+    async move {
+      match task.task_or_script {
+        TaskOrScript::Task { task: def, .. } => {
+          self
+            .run_deno_task(
+              task.task_or_script.folder_url(),
+              task.task_or_script.package_name(),
+              label_name,
+              task.name,
+              def,
+              kill_signal,
+              args,
+              parallel_info,
+              task.id,
+              dep_fingerprints,
+            )
+            .await
+        }
+        TaskOrScript::Script { details, .. } => {
+          // npm scripts never participate in the input cache; mark
+          // non-cacheable so dependents don't cache against them.
+          self.record_fingerprint(task.id, None);
+          self
+            .run_npm_script(
+              task.task_or_script.folder_url(),
+              task.task_or_script.package_name(),
+              label_name,
+              task.name,
+              &details.tasks,
+              kill_signal,
+              args,
+              parallel_info,
+            )
+            .await
+        }
+      }
+    }
+    .boxed_local()
+  }
 
+  async fn run_tasks_in_parallel(
+    &self,
+    tasks: Vec<ResolvedTask<'a>>,
+    kill_signal: &KillSignal,
+    args: &'a [String],
+  ) -> Result<i32, deno_core::anyhow::Error> {
     struct PendingTasksContext<'a> {
       completed: HashSet<usize>,
       running: HashSet<usize>,
       tasks: &'a [ResolvedTask<'a>],
-      concurrent_task_color_indices: HashMap<usize, usize>,
-      max_label_len: usize,
-      include_package: bool,
-      workspace_root_url: &'a Url,
+      parallel_infos: HashMap<usize, ParallelInfo>,
     }
 
     impl<'a> PendingTasksContext<'a> {
@@ -562,58 +729,12 @@ impl<'a> TaskRunner<'a> {
           }
 
           self.running.insert(task.id);
-          let kill_signal = kill_signal.clone();
-          let parallel_info = self
-            .concurrent_task_color_indices
-            .get(&task.id)
-            .map(|&color_index| ParallelInfo {
-              color_index,
-              label_pad_width: self.max_label_len,
-              include_package: self.include_package,
-            });
-          let label_name = task_label_name(task, self.workspace_root_url);
-          let dep_fingerprints =
-            runner.dependency_fingerprints(&task.dependencies);
+          let parallel_info = self.parallel_infos.get(&task.id).copied();
           return Some(
-            async move {
-              match task.task_or_script {
-                TaskOrScript::Task { task: def, .. } => {
-                  runner
-                    .run_deno_task(
-                      task.task_or_script.folder_url(),
-                      task.task_or_script.package_name(),
-                      label_name,
-                      task.name,
-                      def,
-                      kill_signal,
-                      args,
-                      parallel_info,
-                      task.id,
-                      dep_fingerprints,
-                    )
-                    .await
-                }
-                TaskOrScript::Script { details, .. } => {
-                  // npm scripts never participate in the input cache; mark
-                  // non-cacheable so dependents don't cache against them.
-                  runner.record_fingerprint(task.id, None);
-                  runner
-                    .run_npm_script(
-                      task.task_or_script.folder_url(),
-                      task.task_or_script.package_name(),
-                      label_name,
-                      task.name,
-                      &details.tasks,
-                      kill_signal,
-                      args,
-                      parallel_info,
-                    )
-                    .await
-                }
-              }
-              .map(|exit_code| (exit_code, task))
-            }
-            .boxed_local(),
+            runner
+              .run_resolved_task(task, kill_signal.clone(), args, parallel_info)
+              .map(move |result| result.map(|exit_code| (exit_code, task)))
+              .boxed_local(),
           );
         }
         None
@@ -624,10 +745,7 @@ impl<'a> TaskRunner<'a> {
       completed: HashSet::with_capacity(tasks.len()),
       running: HashSet::with_capacity(self.concurrency),
       tasks: &tasks,
-      concurrent_task_color_indices,
-      max_label_len,
-      include_package,
-      workspace_root_url,
+      parallel_infos: self.task_parallel_infos(&tasks),
     };
 
     let mut queue = futures_unordered::FuturesUnordered::new();
@@ -661,6 +779,199 @@ impl<'a> TaskRunner<'a> {
     Ok(0)
   }
 
+  /// Run the tasks, then keep watching the `files` inputs they declare. On a
+  /// change, only the tasks whose fingerprint changed, and the tasks that
+  /// depend on them, are stopped (if still running) and run again; tasks
+  /// that aren't affected, including long-running ones, are left alone.
+  async fn watch_tasks(
+    &self,
+    tasks: Vec<ResolvedTask<'a>>,
+    kill_signal: &KillSignal,
+    argv: &'a [String],
+    watcher_communicator: &WatcherCommunicator,
+  ) -> Result<i32, AnyError> {
+    // Changing the config (or an env file) can change the tasks themselves,
+    // so those changes restart everything from scratch.
+    let config_paths = self
+      .cli_options
+      .watch_paths()
+      .into_iter()
+      .map(|path| canonicalize_path(&path).unwrap_or(path))
+      .collect::<HashSet<_>>();
+    let _ =
+      watcher_communicator.watch_paths(config_paths.iter().cloned().collect());
+    let mut input_paths = Vec::new();
+    for task in &tasks {
+      if let TaskOrScript::Task { task: def, .. } = task.task_or_script {
+        let cwd =
+          self.resolve_deno_task_cwd(task.task_or_script.folder_url())?;
+        input_paths.extend(crate::tools::task_cache::input_watch_paths(
+          &cwd, &def.files,
+        ));
+      }
+    }
+    if input_paths.is_empty() {
+      log::warn!(
+        "{} None of these tasks declare \"files\", so only config changes are watched.",
+        colors::yellow("Warning"),
+      );
+    }
+    let _ = watcher_communicator.watch_paths(input_paths);
+
+    let parallel_infos = self.task_parallel_infos(&tasks);
+    let labels = tasks
+      .iter()
+      .map(|task| task_display_label(task, self.cli_options))
+      .collect::<Vec<_>>();
+    let mut fingerprints = self
+      .plan_tasks(&tasks, argv)?
+      .into_iter()
+      .map(|planned| planned.fingerprint)
+      .collect::<Vec<_>>();
+    // task ids are indexes into `tasks`; a task is in at most one of
+    // `pending`, `running` and `completed`, or in none of them after it
+    // failed, until its inputs change
+    let mut pending =
+      (0..tasks.len()).collect::<std::collections::BTreeSet<_>>();
+    let mut running: HashMap<usize, KillSignal> = HashMap::new();
+    let mut completed: HashSet<usize> = HashSet::new();
+    // bumped whenever a task is restarted, so the result of the stopped run
+    // is ignored
+    let mut generations = vec![0usize; tasks.len()];
+    let mut queue = futures_unordered::FuturesUnordered::new();
+    let mut reported_idle = false;
+
+    loop {
+      for task in &tasks {
+        if running.len() >= self.concurrency {
+          break;
+        }
+        if !pending.contains(&task.id)
+          || !task.dependencies.iter().all(|id| completed.contains(id))
+        {
+          continue;
+        }
+        pending.remove(&task.id);
+        // like in a regular run, only the last task receives the CLI
+        // arguments
+        let args: &'a [String] = if task.id == tasks.len() - 1 {
+          argv
+        } else {
+          &[]
+        };
+        let task_kill_signal = kill_signal.child_signal();
+        running.insert(task.id, task_kill_signal.clone());
+        let generation = generations[task.id];
+        let id = task.id;
+        queue.push(
+          self
+            .run_resolved_task(
+              task,
+              task_kill_signal,
+              args,
+              parallel_infos.get(&task.id).copied(),
+            )
+            .map(move |result| (id, generation, result)),
+        );
+      }
+
+      if running.is_empty() && !reported_idle {
+        reported_idle = true;
+        watcher_communicator
+          .print("Tasks finished. Rerunning on file change...".to_string());
+      }
+
+      tokio::select! {
+        Some((id, generation, result)) = queue.next() => {
+          if generation != generations[id] {
+            continue;
+          }
+          running.remove(&id);
+          match result {
+            Ok(0) => {
+              completed.insert(id);
+            }
+            Ok(exit_code) => {
+              log::error!(
+                "Task {} failed with exit code {}",
+                labels[id],
+                exit_code
+              );
+            }
+            Err(err) => {
+              log::error!("{}: {:#}", colors::red_bold("error"), err);
+            }
+          }
+        }
+        changed_paths = watcher_communicator.watch_for_changed_paths() => {
+          let changed_paths = changed_paths.ok().flatten().unwrap_or_default();
+          if changed_paths.iter().any(|path| config_paths.contains(path)) {
+            kill_signal.send(deno_task_shell::SignalKind::SIGTERM);
+            let _ = watcher_communicator.force_restart();
+            return Ok(0);
+          }
+
+          // A failure here (e.g. a task's `--cwd` went away) is reported, and
+          // the tasks are left as they are until the next change.
+          let planned = match self.plan_tasks(&tasks, argv) {
+            Ok(planned) => planned,
+            Err(err) => {
+              log::error!("{}: {:#}", colors::red_bold("error"), err);
+              watcher_communicator.print(
+                "Failed to check the tasks. Rerunning on file change..."
+                  .to_string(),
+              );
+              continue;
+            }
+          };
+          let mut affected = vec![false; tasks.len()];
+          for task in &tasks {
+            let inputs_changed = match &planned[task.id].fingerprint {
+              Some(fingerprint) => {
+                fingerprints[task.id].as_ref() != Some(fingerprint)
+              }
+              // A task that can't be cached (e.g. because a dependency has
+              // no `files`) has no fingerprint to compare, so look at its own
+              // inputs instead.
+              None => {
+                fingerprints[task.id].is_some()
+                  || self.task_inputs_changed(task, &changed_paths)
+              }
+            };
+            affected[task.id] = inputs_changed
+              || task.dependencies.iter().any(|id| affected[*id]);
+          }
+          fingerprints = planned
+            .into_iter()
+            .map(|planned| planned.fingerprint)
+            .collect();
+          if !affected.contains(&true) {
+            continue;
+          }
+
+          let mut rerun = Vec::new();
+          for task in &tasks {
+            if !affected[task.id] {
+              continue;
+            }
+            if let Some(task_kill_signal) = running.remove(&task.id) {
+              task_kill_signal.send(deno_task_shell::SignalKind::SIGTERM);
+            }
+            generations[task.id] += 1;
+            completed.remove(&task.id);
+            pending.insert(task.id);
+            rerun.push(labels[task.id].as_str());
+          }
+          reported_idle = false;
+          watcher_communicator.print(format!(
+            "File change detected! Rerunning: {}",
+            rerun.join(", ")
+          ));
+        }
+      }
+    }
+  }
+
   #[allow(
     clippy::too_many_arguments,
     reason = "parallel_info was added to an already-large signature; refactoring into a struct is deferred"
@@ -674,6 +985,24 @@ impl<'a> TaskRunner<'a> {
       .insert(task_id, fingerprint);
   }
 
+  /// Whether one of the changed paths is among the task's own `files`.
+  fn task_inputs_changed(
+    &self,
+    task: &ResolvedTask<'a>,
+    changed_paths: &[PathBuf],
+  ) -> bool {
+    let TaskOrScript::Task { task: def, .. } = task.task_or_script else {
+      return false;
+    };
+    let Ok(cwd) = self.resolve_deno_task_cwd(task.task_or_script.folder_url())
+    else {
+      return false;
+    };
+    changed_paths.iter().any(|path| {
+      crate::tools::task_cache::is_input_path(&cwd, &def.files, path)
+    })
+  }
+
   /// Collect the fingerprints of a task's direct dependencies for the cache
   /// cascade. Returns `None` when any dependency is non-cacheable (always
   /// runs) or has not recorded a fingerprint, which forces the dependent task
@@ -690,6 +1019,144 @@ impl<'a> TaskRunner<'a> {
     Some(fingerprints)
   }
 
+  fn resolve_deno_task_cwd(&self, dir_url: &Url) -> Result<PathBuf, AnyError> {
+    Ok(match &self.task_flags.cwd {
+      Some(path) => canonicalize_path(Path::new(path))
+        .context("failed canonicalizing --cwd")?,
+      None => {
+        normalize_path(Cow::Owned(dir_url.to_file_path().unwrap())).into_owned()
+      }
+    })
+  }
+
+  /// Snapshot the env vars a task lists for its cache key. Only the listed
+  /// vars are read, and only for cacheable tasks, so the common
+  /// (non-cacheable) path doesn't clone the whole environment.
+  fn task_env_snapshot(
+    &self,
+    definition: &TaskDefinition,
+  ) -> std::collections::BTreeMap<String, String> {
+    if definition.files.is_empty() || definition.env.is_empty() {
+      return std::collections::BTreeMap::new();
+    }
+    self
+      .env_vars
+      .iter()
+      .filter_map(|(k, v)| {
+        let k = k.to_str()?;
+        if definition.env.iter().any(|name| name == k) {
+          Some((k.to_string(), v.to_str()?.to_string()))
+        } else {
+          None
+        }
+      })
+      .collect()
+  }
+
+  /// Work out what running the sorted tasks would do without running them:
+  /// which ones the input cache would skip, and for the others, why they
+  /// would run. Fingerprints cascade to dependents just like in a real run,
+  /// assuming a task that would run doesn't change another task's inputs.
+  fn plan_tasks(
+    &self,
+    tasks: &[ResolvedTask<'a>],
+    argv: &'a [String],
+  ) -> Result<Vec<PlannedTask>, AnyError> {
+    use crate::tools::task_cache::CacheExplanation;
+
+    let labels = tasks
+      .iter()
+      .map(|task| task_display_label(task, self.cli_options))
+      .collect::<Vec<_>>();
+    // task ids are indexes into the sorted list
+    let mut fingerprints: Vec<Option<String>> = Vec::with_capacity(tasks.len());
+    let mut planned = Vec::with_capacity(tasks.len());
+    for (index, task) in tasks.iter().enumerate() {
+      // like in a real run, only the last task receives the CLI arguments
+      let args: &[String] = if index == tasks.len() - 1 { argv } else { &[] };
+      let dependencies = task
+        .dependencies
+        .iter()
+        .map(|id| labels[*id].clone())
+        .collect::<Vec<_>>();
+      let (command, status, fingerprint) = match task.task_or_script {
+        TaskOrScript::Task { task: def, .. } => match &def.command {
+          Some(command) => {
+            let cwd =
+              self.resolve_deno_task_cwd(task.task_or_script.folder_url())?;
+            let env = self.task_env_snapshot(def);
+            let dep_fingerprints = task
+              .dependencies
+              .iter()
+              .map(|id| fingerprints[*id].clone())
+              .collect::<Option<Vec<_>>>();
+            let key = crate::tools::task_cache::TaskCacheKey {
+              package_name: task.task_or_script.package_name(),
+              task_name: task.name,
+              cwd: &cwd,
+              command,
+              argv: args,
+              files: &def.files,
+              output: &def.output,
+              env_names: &def.env,
+              env: &env,
+              dep_fingerprints: dep_fingerprints.as_deref(),
+            };
+            let (status, fingerprint) = match self.task_cache.explain(&key) {
+              CacheExplanation::Hit(fingerprint) => {
+                (PlannedTaskStatus::Skip, Some(fingerprint))
+              }
+              CacheExplanation::Miss {
+                fingerprint,
+                reasons,
+              } => {
+                let reasons = reasons
+                  .iter()
+                  .map(|reason| describe_miss_reason(reason, &dependencies))
+                  .collect();
+                (PlannedTaskStatus::Run(reasons), Some(fingerprint))
+              }
+              CacheExplanation::NotCacheable(reason) => (
+                PlannedTaskStatus::Run(vec![
+                  describe_not_cacheable_reason(&reason).to_string(),
+                ]),
+                None,
+              ),
+            };
+            (
+              Some(task_runner::get_script_with_args(command, args)),
+              status,
+              fingerprint,
+            )
+          }
+          None => (
+            None,
+            PlannedTaskStatus::Run(vec![
+              "no command, only runs its dependencies".to_string(),
+            ]),
+            None,
+          ),
+        },
+        TaskOrScript::Script { .. } => (
+          None,
+          PlannedTaskStatus::Run(vec![
+            "package.json scripts are not cached".to_string(),
+          ]),
+          None,
+        ),
+      };
+      fingerprints.push(fingerprint.clone());
+      planned.push(PlannedTask {
+        name: labels[index].clone(),
+        command,
+        dependencies,
+        status,
+        fingerprint,
+      });
+    }
+    Ok(planned)
+  }
+
   #[allow(
     clippy::too_many_arguments,
     reason = "task execution threads several independent inputs; refactoring into a struct is deferred"
@@ -722,13 +1189,7 @@ impl<'a> TaskRunner<'a> {
 
     self.maybe_npm_install().await?;
 
-    let cwd = match &self.task_flags.cwd {
-      Some(path) => canonicalize_path(Path::new(path))
-        .context("failed canonicalizing --cwd")?,
-      None => {
-        normalize_path(Cow::Owned(dir_url.to_file_path().unwrap())).into_owned()
-      }
-    };
+    let cwd = self.resolve_deno_task_cwd(dir_url)?;
 
     let node_modules_bin_dirs =
       task_runner::resolve_task_node_modules_bin_dirs(self.npm_resolver, &cwd);
@@ -739,26 +1200,8 @@ impl<'a> TaskRunner<'a> {
     )?;
 
     // Input-based cache: if the task declares `files`, hash inputs +
-    // command + listed env values and skip on match. Only snapshot the env
-    // vars the task actually lists, and only for cacheable tasks, so the
-    // common (non-cacheable) path doesn't clone the whole environment.
-    let env_snapshot: std::collections::BTreeMap<String, String> =
-      if definition.files.is_empty() || definition.env.is_empty() {
-        std::collections::BTreeMap::new()
-      } else {
-        self
-          .env_vars
-          .iter()
-          .filter_map(|(k, v)| {
-            let k = k.to_str()?;
-            if definition.env.iter().any(|name| name == k) {
-              Some((k.to_string(), v.to_str()?.to_string()))
-            } else {
-              None
-            }
-          })
-          .collect()
-      };
+    // command + listed env values and skip on match.
+    let env_snapshot = self.task_env_snapshot(definition);
     let cache_key = crate::tools::task_cache::TaskCacheKey {
       package_name,
       task_name,
@@ -785,9 +1228,36 @@ impl<'a> TaskRunner<'a> {
         return Ok(0);
       }
       crate::tools::task_cache::CacheLookup::Miss(fp) => {
-        // Remove artifacts from a previous run so the rebuild doesn't mix
-        // stale and fresh outputs.
+        // Remove artifacts from a previous run so the rebuild (or the remote
+        // restore) doesn't mix stale and fresh outputs.
         self.task_cache.clean_stale_outputs(&cache_key, &fp);
+        if let Some(remote) = self.remote_task_cache
+          && let Some(archive) = remote.get(&fp.fingerprint).await
+        {
+          match self.task_cache.restore_archive(&cache_key, &fp, &archive) {
+            Ok(()) => {
+              self.record_fingerprint(task_id, Some(fp.fingerprint));
+              self.output_task(
+                task_name,
+                package_name,
+                &format!(
+                  "{} (cached remotely, inputs unchanged)",
+                  colors::gray(task_runner::get_script_with_args(
+                    command, argv
+                  ))
+                ),
+              );
+              return Ok(0);
+            }
+            Err(err) => {
+              log::warn!(
+                "{} Ignoring invalid remote task cache entry: {:#}",
+                colors::yellow("Warning"),
+                err
+              );
+            }
+          }
+        }
         Some(fp)
       }
       crate::tools::task_cache::CacheLookup::NotCacheable => {
@@ -818,6 +1288,14 @@ impl<'a> TaskRunner<'a> {
     {
       self.record_fingerprint(task_id, Some(fp.fingerprint.clone()));
       self.task_cache.store(&cache_key, &fp);
+      if let Some(remote) = self.remote_task_cache {
+        match self.task_cache.archive_outputs(&cache_key) {
+          Ok(archive) => remote.put(&fp.fingerprint, archive).await,
+          Err(err) => {
+            log::debug!("failed to archive task outputs: {err:#}");
+          }
+        }
+      }
     }
     Ok(exit_code)
   }
@@ -1029,6 +1507,146 @@ enum TaskError {
   TaskDepCycle { path: Vec<String> },
 }
 
+/// A task as `--dry-run` and `--graph` report it.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PlannedTask {
+  name: String,
+  command: Option<String>,
+  dependencies: Vec<String>,
+  #[serde(flatten)]
+  status: PlannedTaskStatus,
+  /// The task's cache fingerprint for its current inputs, or `None` when it
+  /// isn't cacheable.
+  #[serde(skip)]
+  fingerprint: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "status", content = "reasons", rename_all = "camelCase")]
+enum PlannedTaskStatus {
+  /// The input cache would skip the task.
+  Skip,
+  /// The task would run, for these reasons.
+  Run(Vec<String>),
+}
+
+fn describe_not_cacheable_reason(
+  reason: &crate::tools::task_cache::NotCacheableReason,
+) -> &'static str {
+  use crate::tools::task_cache::NotCacheableReason;
+  match reason {
+    NotCacheableReason::NoFiles => "not cached, no \"files\" declared",
+    NotCacheableReason::NoMatchingFiles => {
+      "not cached, \"files\" doesn't match any file"
+    }
+    NotCacheableReason::DependencyNotCacheable => {
+      "not cached, depends on a task that always runs"
+    }
+  }
+}
+
+fn describe_miss_reason(
+  reason: &crate::tools::task_cache::MissReason,
+  dependencies: &[String],
+) -> String {
+  use crate::tools::task_cache::MissReason;
+  match reason {
+    MissReason::NoPreviousRun => "no previous successful run".to_string(),
+    MissReason::Platform => "Deno version or platform changed".to_string(),
+    MissReason::Command => "command changed".to_string(),
+    MissReason::Arguments => "arguments changed".to_string(),
+    MissReason::Outputs => "outputs changed".to_string(),
+    MissReason::EnvVar(name) => format!("env var changed: {name}"),
+    MissReason::Dependency(index) => {
+      format!("dependency changed: {}", dependencies[*index])
+    }
+    MissReason::FileAdded(path) => format!("file added: {path}"),
+    MissReason::FileRemoved(path) => format!("file removed: {path}"),
+    MissReason::FileChanged(path) => format!("file changed: {path}"),
+    MissReason::Unknown => "inputs changed".to_string(),
+  }
+}
+
+fn write_task_plan(
+  writer: &mut dyn std::io::Write,
+  planned: &[PlannedTask],
+) -> Result<(), std::io::Error> {
+  writeln!(writer, "{}", colors::green("Task graph (dry run):"))?;
+  for task in planned {
+    writeln!(writer, "- {}", colors::cyan(&task.name))?;
+    if let Some(command) = &task.command {
+      writeln!(
+        writer,
+        "    {}",
+        strip_ansi_codes_and_escape_control_chars(command)
+      )?;
+    }
+    if !task.dependencies.is_empty() {
+      writeln!(
+        writer,
+        "    {} {}",
+        colors::gray("depends on:"),
+        colors::cyan(task.dependencies.join(", "))
+      )?;
+    }
+    match &task.status {
+      PlannedTaskStatus::Skip => {
+        writeln!(writer, "    {} inputs unchanged", colors::gray("skip:"))?;
+      }
+      PlannedTaskStatus::Run(reasons) => {
+        for reason in reasons {
+          writeln!(writer, "    {} {}", colors::yellow("run:"), reason)?;
+        }
+      }
+    }
+  }
+  let skipped = planned
+    .iter()
+    .filter(|task| matches!(task.status, PlannedTaskStatus::Skip))
+    .count();
+  writeln!(
+    writer,
+    "{} would run, {} would be skipped",
+    planned.len() - skipped,
+    skipped
+  )?;
+  Ok(())
+}
+
+/// Writes the graph in Graphviz format, with edges pointing from a task to
+/// its dependencies and skipped tasks drawn dashed.
+fn write_task_graph_dot(
+  writer: &mut dyn std::io::Write,
+  planned: &[PlannedTask],
+) -> Result<(), std::io::Error> {
+  fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+  }
+
+  writeln!(writer, "digraph tasks {{")?;
+  for task in planned {
+    let style = match task.status {
+      PlannedTaskStatus::Skip => ", style=dashed",
+      PlannedTaskStatus::Run(_) => "",
+    };
+    writeln!(
+      writer,
+      "  {} [label={}{}];",
+      quote(&task.name),
+      quote(&task.name),
+      style
+    )?;
+  }
+  for task in planned {
+    for dependency in &task.dependencies {
+      writeln!(writer, "  {} -> {};", quote(&task.name), quote(dependency))?;
+    }
+  }
+  writeln!(writer, "}}")?;
+  Ok(())
+}
+
 struct ResolvedTask<'a> {
   id: usize,
   name: &'a str,
@@ -38,19 +38,27 @@
 //! captured outputs; an unrecognized or older payload simply fails to parse
 //! and is treated as a miss, so the schema can evolve without migration.
 //!
+//! The fingerprint doubles as a content address for sharing entries across
+//! machines: see [`crate::tools::task_cache_remote`] for the optional remote
+//! store configured through `taskCache.remote` in `deno.json`.
+//!
 //! Caveat: the fingerprint is captured before the run and stored afterwards,
 //! so a task that writes into its own `files` (formatters, codegen) changes
 //! its inputs as a side effect and will never match on the next run. It stays
 //! correct, just never caches.
 
 use std::collections::BTreeMap;
+use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
 use std::time::UNIX_EPOCH;
 
 use deno_config::glob::FileCollector;
 use deno_config::glob::FilePatterns;
+use deno_config::glob::PathKind;
 use deno_config::glob::PathOrPatternSet;
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
 use deno_core::serde_json;
 use serde::Deserialize;
 use serde::Serialize;
@@ -73,6 +81,50 @@ pub enum CacheLookup {
   NotCacheable,
 }
 
+/// Result of [`TaskCache::explain`]: what a run would do for a task, and why.
+pub enum CacheExplanation {
+  /// The task always runs.
+  NotCacheable(NotCacheableReason),
+  /// The stored fingerprint matches, so the task would be skipped.
+  Hit(String),
+  /// The task would run. Carries the fingerprint it would be stored under so
+  /// dependents can be explained against it too.
+  Miss {
+    fingerprint: String,
+    reasons: Vec<MissReason>,
+  },
+}
+
+pub enum NotCacheableReason {
+  /// The task doesn't declare `files`.
+  NoFiles,
+  /// The `files` globs are invalid or match nothing.
+  NoMatchingFiles,
+  /// A dependency always runs, so this task can't be skipped either.
+  DependencyNotCacheable,
+}
+
+/// An input that differs from the one recorded by the last successful run.
+#[derive(Debug, PartialEq, Eq)]
+pub enum MissReason {
+  /// The task has never completed successfully.
+  NoPreviousRun,
+  /// Deno version, OS or architecture.
+  Platform,
+  Command,
+  Arguments,
+  Outputs,
+  EnvVar(String),
+  /// Index into the task's dependencies, in the order their fingerprints
+  /// were passed in [`TaskCacheKey::dep_fingerprints`].
+  Dependency(usize),
+  FileAdded(String),
+  FileRemoved(String),
+  FileChanged(String),
+  /// The entry predates per-input tracking, so the changed input is unknown.
+  Unknown,
+}
+
 /// A freshly computed fingerprint, ready to be persisted after a successful
 /// run.
 pub struct Fingerprint {
@@ -82,6 +134,8 @@ pub struct Fingerprint {
   static_hash: String,
   content_hash: String,
   files: Vec<FileStat>,
+  static_inputs: StaticInputs,
+  file_hashes: BTreeMap<String, String>,
   /// Outputs captured by the previous successful run, if any. Removed before
   /// the re-run so stale artifacts don't linger.
   prior_outputs: Vec<String>,
@@ -132,8 +186,9 @@ impl TaskCache {
 
     // Slow path: something drifted. Read contents to tell a real edit from an
     // mtime-only touch.
-    let content_hash = compute_content_hash(key, &inputs);
+    let (content_hash, file_hashes) = compute_content_hash(key, &inputs);
     let fingerprint = combine_fingerprint(&static_hash, &content_hash);
+    let static_inputs = StaticInputs::new(key, dep_fingerprints);
 
     if let Some(stored) = &stored
       && stored.static_hash == static_hash
@@ -146,6 +201,8 @@ impl TaskCache {
         static_hash,
         content_hash,
         files: stats,
+        static_inputs: Some(static_inputs),
+        file_hashes,
         outputs: stored.outputs.clone(),
       };
       self.write_manifest(key, &manifest);
@@ -158,10 +215,79 @@ impl TaskCache {
       static_hash,
       content_hash,
       files: stats,
+      static_inputs,
+      file_hashes,
       prior_outputs: stored.map(|s| s.outputs).unwrap_or_default(),
     })
   }
 
+  /// Like [`TaskCache::lookup`], but without touching the cache or the
+  /// working tree, and reporting which inputs changed on a miss. Always
+  /// reads the input contents, since it also has to name changed files.
+  pub fn explain(&self, key: &TaskCacheKey<'_>) -> CacheExplanation {
+    if key.files.is_empty() {
+      return CacheExplanation::NotCacheable(NotCacheableReason::NoFiles);
+    }
+    let Some(dep_fingerprints) = key.dep_fingerprints else {
+      return CacheExplanation::NotCacheable(
+        NotCacheableReason::DependencyNotCacheable,
+      );
+    };
+    let Some(inputs) = collect_inputs(key) else {
+      return CacheExplanation::NotCacheable(
+        NotCacheableReason::NoMatchingFiles,
+      );
+    };
+    let static_hash = compute_static_hash(key, dep_fingerprints);
+    let (content_hash, file_hashes) = compute_content_hash(key, &inputs);
+    let fingerprint = combine_fingerprint(&static_hash, &content_hash);
+
+    let stored = std::fs::read_to_string(self.manifest_path(key))
+      .ok()
+      .and_then(|s| serde_json::from_str::<CacheManifest>(&s).ok());
+    let Some(stored) = stored else {
+      return CacheExplanation::Miss {
+        fingerprint,
+        reasons: vec![MissReason::NoPreviousRun],
+      };
+    };
+    if stored.static_hash == static_hash && stored.content_hash == content_hash
+    {
+      return CacheExplanation::Hit(fingerprint);
+    }
+
+    let mut reasons = Vec::new();
+    if stored.static_hash != static_hash {
+      match &stored.static_inputs {
+        Some(previous) => {
+          let current = StaticInputs::new(key, dep_fingerprints);
+          diff_static_inputs(
+            previous,
+            &current,
+            dep_fingerprints,
+            &mut reasons,
+          );
+        }
+        None => reasons.push(MissReason::Unknown),
+      }
+    }
+    if stored.content_hash != content_hash {
+      if stored.file_hashes.is_empty() {
+        reasons.push(MissReason::Unknown);
+      } else {
+        diff_file_hashes(&stored.file_hashes, &file_hashes, &mut reasons);
+      }
+    }
+    if reasons.is_empty() {
+      reasons.push(MissReason::Unknown);
+    }
+    reasons.dedup();
+    CacheExplanation::Miss {
+      fingerprint,
+      reasons,
+    }
+  }
+
   /// Remove the outputs captured by a previous run before re-running, so a
   /// fresh build does not mix stale and new artifacts. Only files this task
   /// produced itself (recorded in the previous manifest) are removed.
@@ -187,9 +313,70 @@ impl TaskCache {
       static_hash: fingerprint.static_hash.clone(),
       content_hash: fingerprint.content_hash.clone(),
       files: fingerprint.files.clone(),
+      static_inputs: Some(fingerprint.static_inputs.clone()),
+      file_hashes: fingerprint.file_hashes.clone(),
+      outputs,
+    };
+    self.write_manifest(key, &manifest);
+  }
+
+  /// Pack the outputs captured by [`TaskCache::store`] into a gzipped tarball
+  /// for upload to a remote cache.
+  pub fn archive_outputs(
+    &self,
+    key: &TaskCacheKey<'_>,
+  ) -> Result<Vec<u8>, AnyError> {
+    let manifest: CacheManifest =
+      serde_json::from_str(&std::fs::read_to_string(self.manifest_path(key))?)?;
+    let outputs_dir = self.outputs_dir(key);
+    let mut builder = tar::Builder::new(Vec::new());
+    for rel in &manifest.outputs {
+      builder.append_path_with_name(outputs_dir.join(rel), rel)?;
+    }
+    let tar = builder.into_inner()?;
+    let mut encoder =
+      flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(&tar)?;
+    Ok(encoder.finish()?)
+  }
+
+  /// Populate the local entry from an archive downloaded from a remote cache
+  /// and restore its outputs to the working tree, as on a local hit.
+  pub fn restore_archive(
+    &self,
+    key: &TaskCacheKey<'_>,
+    fingerprint: &Fingerprint,
+    archive: &[u8],
+  ) -> Result<(), AnyError> {
+    let outputs_dir = self.outputs_dir(key);
+    remove_if_exists(&outputs_dir)?;
+    std::fs::create_dir_all(&outputs_dir)?;
+    let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(archive));
+    let mut outputs = Vec::new();
+    for entry in archive.entries()? {
+      let mut entry = entry?;
+      if !entry.header().entry_type().is_file() {
+        continue;
+      }
+      let rel = entry.path()?.to_string_lossy().into_owned();
+      // `unpack_in` refuses entries that would land outside the directory
+      if !entry.unpack_in(&outputs_dir)? {
+        bail!("Remote task cache archive contains an invalid path: {rel}");
+      }
+      outputs.push(rel);
+    }
+    let manifest = CacheManifest {
+      fingerprint: fingerprint.fingerprint.clone(),
+      static_hash: fingerprint.static_hash.clone(),
+      content_hash: fingerprint.content_hash.clone(),
+      files: fingerprint.files.clone(),
+      static_inputs: Some(fingerprint.static_inputs.clone()),
+      file_hashes: fingerprint.file_hashes.clone(),
       outputs,
     };
     self.write_manifest(key, &manifest);
+    self.restore_outputs(key, &manifest);
+    Ok(())
   }
 
   /// Copy the files matching the task's `output` globs into the cache so they
@@ -337,12 +524,118 @@ struct CacheManifest {
   /// Per-input stat snapshot, enabling the size+mtime fast path that skips
   /// re-reading contents when nothing has changed.
   files: Vec<FileStat>,
+  /// The inputs folded into `static_hash`, kept so `deno task --dry-run` can
+  /// name the one that changed. Missing in entries written by older versions.
+  #[serde(default)]
+  static_inputs: Option<StaticInputs>,
+  /// Per-file content hashes keyed by relative path, for the same purpose.
+  #[serde(default)]
+  file_hashes: BTreeMap<String, String>,
   /// Relative paths of the outputs captured under `outputs/`, for restoration
   /// on a hit and cleanup before a re-run.
   #[serde(default)]
   outputs: Vec<String>,
 }
 
+/// The individual inputs behind [`compute_static_hash`]. Env values are
+/// stored hashed as they may hold secrets.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+struct StaticInputs {
+  platform: String,
+  command: String,
+  argv: Vec<String>,
+  #[serde(default)]
+  output: Vec<String>,
+  env: BTreeMap<String, Option<String>>,
+  dependencies: Vec<String>,
+}
+
+impl StaticInputs {
+  fn new(key: &TaskCacheKey<'_>, dep_fingerprints: &[String]) -> Self {
+    let env = key
+      .env_names
+      .iter()
+      .map(|name| {
+        let value = key.env.get(name).map(|value| {
+          let mut hasher = FingerprintHasher::new("deno-task-cache-env-v1");
+          hasher.write_str(value);
+          hasher.finish_hex()
+        });
+        (name.clone(), value)
+      })
+      .collect();
+    Self {
+      platform: format!(
+        "{} {} {}",
+        env!("CARGO_PKG_VERSION"),
+        std::env::consts::OS,
+        std::env::consts::ARCH
+      ),
+      command: key.command.to_string(),
+      argv: key.argv.to_vec(),
+      output: key.output.to_vec(),
+      env,
+      dependencies: dep_fingerprints.to_vec(),
+    }
+  }
+}
+
+fn diff_static_inputs(
+  previous: &StaticInputs,
+  current: &StaticInputs,
+  dep_fingerprints: &[String],
+  reasons: &mut Vec<MissReason>,
+) {
+  if previous.platform != current.platform {
+    reasons.push(MissReason::Platform);
+  }
+  if previous.command != current.command {
+    reasons.push(MissReason::Command);
+  }
+  if previous.argv != current.argv {
+    reasons.push(MissReason::Arguments);
+  }
+  if previous.output != current.output {
+    reasons.push(MissReason::Outputs);
+  }
+  let names = previous
+    .env
+    .keys()
+    .chain(current.env.keys())
+    .collect::<std::collections::BTreeSet<_>>();
+  for name in names {
+    if previous.env.get(name) != current.env.get(name) {
+      reasons.push(MissReason::EnvVar(name.clone()));
+    }
+  }
+  for (index, fingerprint) in dep_fingerprints.iter().enumerate() {
+    if !previous.dependencies.contains(fingerprint) {
+      reasons.push(MissReason::Dependency(index));
+    }
+  }
+}
+
+fn diff_file_hashes(
+  previous: &BTreeMap<String, String>,
+  current: &BTreeMap<String, String>,
+  reasons: &mut Vec<MissReason>,
+) {
+  for (path, hash) in current {
+    match previous.get(path) {
+      None => reasons.push(MissReason::FileAdded(path.clone())),
+      Some(previous_hash) if previous_hash != hash => {
+        reasons.push(MissReason::FileChanged(path.clone()))
+      }
+      Some(_) => {}
+    }
+  }
+  for path in previous.keys() {
+    if !current.contains_key(path) {
+      reasons.push(MissReason::FileRemoved(path.clone()));
+    }
+  }
+}
+
 #[derive(Clone, PartialEq, Serialize, Deserialize)]
 struct FileStat {
   /// Path relative to the task's cwd.
@@ -414,9 +707,9 @@ fn collect_files(cwd: &Path, globs: &[String]) -> Option<Vec<PathBuf>> {
   Some(files)
 }
 
-/// Hash everything that isn't an input file's contents: the command, the
-/// appended CLI args, the listed env values, the dependency fingerprints, and
-/// a platform/version salt.
+/// Hash everything that isn't an input file's contents: the task's name, the
+/// command, the appended CLI args, the output globs, the listed env values,
+/// the dependency fingerprints, and a platform/version salt.
 fn compute_static_hash(
   key: &TaskCacheKey<'_>,
   dep_fingerprints: &[String],
@@ -429,6 +722,10 @@ fn compute_static_hash(
   hasher.write_str(env!("CARGO_PKG_VERSION"));
   hasher.write_str(std::env::consts::OS);
   hasher.write_str(std::env::consts::ARCH);
+  // The fingerprint alone addresses a remote cache entry, so tasks that only
+  // differ by name must not restore each other's outputs.
+  hasher.write_str(key.package_name.unwrap_or(""));
+  hasher.write_str(key.task_name);
   hasher.write_str(key.command);
 
   // Appended CLI args materially change what runs, so fold them in.
@@ -437,6 +734,13 @@ fn compute_static_hash(
     hasher.write_str(arg);
   }
 
+  // The output globs decide what is captured and restored, so a hit must
+  // not hand back an archive captured with different ones.
+  hasher.write_u64(key.output.len() as u64);
+  for glob in key.output {
+    hasher.write_str(glob);
+  }
+
   // Dependency fingerprints, sorted for determinism. A change upstream
   // (an input edit that made a dependency re-run) bubbles down here.
   let mut deps = dep_fingerprints.to_vec();
@@ -468,29 +772,36 @@ fn compute_static_hash(
 }
 
 /// Hash the input file set: each file's relative path mixed with its contents,
-/// in the sorted order produced by [`collect_inputs`].
+/// in the sorted order produced by [`collect_inputs`]. Also returns a hash of
+/// each file's contents on its own, keyed by relative path.
 fn compute_content_hash(
   key: &TaskCacheKey<'_>,
   inputs: &[InputFile],
-) -> String {
+) -> (String, BTreeMap<String, String>) {
   let mut hasher = FingerprintHasher::new("deno-task-cache-content-v1");
+  let mut file_hashes = BTreeMap::new();
   for input in inputs {
     let rel = input
       .abs_path
       .strip_prefix(key.cwd)
       .unwrap_or(&input.abs_path);
     hasher.write_bytes(rel.as_os_str().as_encoded_bytes());
+    let mut file_hasher = FingerprintHasher::new("deno-task-cache-file-v1");
     match std::fs::read(&input.abs_path) {
       Ok(bytes) => {
         hasher.write_u8(1);
         hasher.write_bytes(&bytes);
+        file_hasher.write_u8(1);
+        file_hasher.write_bytes(&bytes);
       }
       Err(_) => {
         hasher.write_u8(0);
+        file_hasher.write_u8(0);
       }
     }
+    file_hashes.insert(input.stat.path.clone(), file_hasher.finish_hex());
   }
-  hasher.finish_hex()
+  (hasher.finish_hex(), file_hashes)
 }
 
 /// Combine the static and content hashes into the task's overall fingerprint.
@@ -501,6 +812,23 @@ fn combine_fingerprint(static_hash: &str, content_hash: &str) -> String {
   hasher.finish_hex()
 }
 
+/// Paths to watch so that a change to any of a task's `files` inputs is
+/// noticed, including files created after the watcher started.
+pub fn input_watch_paths(cwd: &Path, files: &[String]) -> Vec<PathBuf> {
+  match build_file_patterns(cwd, files) {
+    Some(patterns) => {
+      crate::util::file_watcher::watch_paths_for_file_patterns(&patterns)
+    }
+    None => Vec::new(),
+  }
+}
+
+/// Whether `path` is one of the files matched by a task's `files` inputs.
+pub fn is_input_path(cwd: &Path, files: &[String], path: &Path) -> bool {
+  build_file_patterns(cwd, files)
+    .is_some_and(|patterns| patterns.matches_path(path, PathKind::File))
+}
+
 fn build_file_patterns(cwd: &Path, entries: &[String]) -> Option<FilePatterns> {
   let include =
     PathOrPatternSet::from_include_relative_path_or_patterns(cwd, entries)
@@ -0,0 +1,142 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Remote backend for the `deno task` input cache.
+//!
+//! The local cache in [`crate::tools::task_cache`] keys each entry by the
+//! task's fingerprint, which already folds in the task's name, its output
+//! globs, the Deno version, OS and architecture. That makes the fingerprint a
+//! safe content address to share between machines: when a task misses
+//! locally, the runner asks the remote store for `GET <url>/<fingerprint>`,
+//! and after a successful run it uploads the captured outputs with
+//! `PUT <url>/<fingerprint>` as a gzipped tarball.
+//!
+//! The remote store is an optimization, so it never fails a task: network
+//! errors and unexpected responses are reported as warnings and treated as a
+//! miss (or a skipped upload).
+
+use deno_config::deno_json::RemoteTaskCacheConfig;
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+use deno_core::url::Url;
+use deno_runtime::deno_fetch;
+use http::StatusCode;
+use http_body_util::BodyExt;
+
+use crate::colors;
+use crate::http_util::HttpClient;
+use crate::http_util::RequestBuilder;
+
+pub struct RemoteTaskCache {
+  client: HttpClient,
+  base_url: Url,
+  /// `Authorization` header value, read once from the configured env var.
+  authorization: Option<String>,
+  read_only: bool,
+}
+
+impl RemoteTaskCache {
+  pub fn new(client: HttpClient, config: &RemoteTaskCacheConfig) -> Self {
+    let authorization = std::env::var(&config.token_env)
+      .ok()
+      .filter(|token| !token.is_empty())
+      .map(|token| format!("Bearer {token}"));
+    let mut base_url = config.url.clone();
+    // ensure `join` appends the fingerprint rather than replacing the last
+    // path segment
+    if !base_url.path().ends_with('/') {
+      base_url.set_path(&format!("{}/", base_url.path()));
+    }
+    Self {
+      client,
+      base_url,
+      authorization,
+      read_only: config.read_only,
+    }
+  }
+
+  /// Download the archive stored for a fingerprint, if any.
+  pub async fn get(&self, fingerprint: &str) -> Option<Vec<u8>> {
+    match self.get_inner(fingerprint).await {
+      Ok(archive) => archive,
+      Err(err) => {
+        log::warn!(
+          "{} Failed reading from the remote task cache: {:#}",
+          colors::yellow("Warning"),
+          err
+        );
+        None
+      }
+    }
+  }
+
+  async fn get_inner(
+    &self,
+    fingerprint: &str,
+  ) -> Result<Option<Vec<u8>>, AnyError> {
+    let url = self.entry_url(fingerprint)?;
+    let response = self
+      .authorize(self.client.get(url.clone())?)?
+      .send()
+      .await?;
+    let status = response.status();
+    if status == StatusCode::NOT_FOUND {
+      return Ok(None);
+    }
+    if !status.is_success() {
+      bail!("GET {} responded with status {}", url, status);
+    }
+    let bytes = response.into_body().collect().await?.to_bytes();
+    Ok(Some(bytes.to_vec()))
+  }
+
+  /// Upload the archive for a fingerprint. Does nothing for a read-only
+  /// cache.
+  pub async fn put(&self, fingerprint: &str, archive: Vec<u8>) {
+    if self.read_only {
+      return;
+    }
+    if let Err(err) = self.put_inner(fingerprint, archive).await {
+      log::warn!(
+        "{} Failed writing to the remote task cache: {:#}",
+        colors::yellow("Warning"),
+        err
+      );
+    }
+  }
+
+  async fn put_inner(
+    &self,
+    fingerprint: &str,
+    archive: Vec<u8>,
+  ) -> Result<(), AnyError> {
+    let url = self.entry_url(fingerprint)?;
+    let body = deno_fetch::ReqBody::full(archive.into());
+    let request = self.client.put(url.clone(), body)?.header(
+      http::header::CONTENT_TYPE,
+      "application/gzip".parse().map_err(http::Error::from)?,
+    );
+    let response = self.authorize(request)?.send().await?;
+    let status = response.status();
+    if !status.is_success() {
+      bail!("PUT {} responded with status {}", url, status);
+    }
+    Ok(())
+  }
+
+  fn entry_url(&self, fingerprint: &str) -> Result<Url, AnyError> {
+    Ok(self.base_url.join(fingerprint)?)
+  }
+
+  fn authorize(
+    &self,
+    request: RequestBuilder,
+  ) -> Result<RequestBuilder, AnyError> {
+    Ok(match &self.authorization {
+      Some(authorization) => request.header(
+        http::header::AUTHORIZATION,
+        authorization.parse().map_err(http::Error::from)?,
+      ),
+      None => request,
+    })
+  }
+}
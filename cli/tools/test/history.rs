@@ -0,0 +1,285 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Per-test outcome history for `deno test --history`.
+//!
+//! A retry hides a flaky test in a single run, but across runs the same
+//! test failing now and then (or only passing after a retry) stands out.
+//! Each run appends every test's outcome to a JSON file, which is either in
+//! the Deno dir or a path the user gives (e.g. one that CI caches between
+//! runs), and the reporters are told about the tests with a flaky history.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+use deno_core::url::Url;
+use deno_lib::util::hash::FastInsecureHasher;
+use deno_path_util::url_to_file_path;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::TestDescription;
+use super::TestFailureDescription;
+use super::fmt::to_relative_path_or_remote_url;
+use crate::factory::CliFactory;
+
+/// Only the most recent runs of each test are kept, so the flakiness rate
+/// reflects the test's current state rather than its whole life.
+const MAX_RUNS_PER_TEST: usize = 50;
+
+const HISTORY_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TestOutcome {
+  Passed,
+  Failed,
+  /// Failed at first, then passed on a retry.
+  PassedAfterRetry,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TestRun {
+  outcome: TestOutcome,
+  duration_ms: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct HistoryFile {
+  version: u32,
+  /// Keyed by `<module path> > <test name>`, oldest run first.
+  tests: BTreeMap<String, Vec<TestRun>>,
+}
+
+/// A test from the current run whose history has both passing and failing
+/// runs, or runs that only passed after a retry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlakyTest {
+  pub description: TestFailureDescription,
+  /// Runs that failed or only passed after a retry.
+  pub flaky_runs: usize,
+  pub total_runs: usize,
+}
+
+impl FlakyTest {
+  pub fn rate(&self) -> f64 {
+    self.flaky_runs as f64 / self.total_runs as f64
+  }
+
+  pub fn format_rate(&self) -> String {
+    format!(
+      "{} of {} runs flaky, {:.0}%",
+      self.flaky_runs,
+      self.total_runs,
+      self.rate() * 100.0
+    )
+  }
+}
+
+/// Resolves where `--history` records outcomes: the given path, or a file
+/// in the Deno dir for the workspace.
+pub fn resolve_history_path(
+  factory: &CliFactory,
+  history: &Option<Option<String>>,
+) -> Result<Option<PathBuf>, AnyError> {
+  let cli_options = factory.cli_options()?;
+  Ok(match history {
+    None => None,
+    Some(Some(path)) => Some(cli_options.initial_cwd().join(path)),
+    Some(None) => {
+      let root = url_to_file_path(cli_options.workspace().root_dir_url())?;
+      let hash = FastInsecureHasher::new_without_deno_version()
+        .write_str(&root.to_string_lossy())
+        .finish();
+      Some(
+        factory
+          .deno_dir()?
+          .root
+          .join("test_history_v1")
+          .join(format!("{hash:016x}.json")),
+      )
+    }
+  })
+}
+
+#[derive(Debug, Clone)]
+pub struct TestHistory {
+  path: PathBuf,
+  cwd: Url,
+  tests: BTreeMap<String, Vec<TestRun>>,
+  /// Tests recorded in this run, to report only on those.
+  recorded: Vec<(String, TestFailureDescription)>,
+}
+
+impl TestHistory {
+  /// Loads the history at `path`. A missing file, or one from an
+  /// incompatible version, starts a new history.
+  pub fn load(path: PathBuf, cwd: Url) -> Self {
+    let tests = std::fs::read_to_string(&path)
+      .ok()
+      .and_then(|text| serde_json::from_str::<HistoryFile>(&text).ok())
+      .filter(|file| file.version == HISTORY_VERSION)
+      .map(|file| file.tests)
+      .unwrap_or_default();
+    Self {
+      path,
+      cwd,
+      tests,
+      recorded: Vec::new(),
+    }
+  }
+
+  pub fn record(
+    &mut self,
+    description: &TestDescription,
+    outcome: TestOutcome,
+    duration: Duration,
+  ) {
+    let key = format!(
+      "{} > {}",
+      to_relative_path_or_remote_url(&self.cwd, &description.origin),
+      description.name
+    );
+    let runs = self.tests.entry(key.clone()).or_default();
+    runs.push(TestRun {
+      outcome,
+      duration_ms: duration.as_millis() as u64,
+    });
+    if runs.len() > MAX_RUNS_PER_TEST {
+      runs.drain(..runs.len() - MAX_RUNS_PER_TEST);
+    }
+    self.recorded.push((key, description.into()));
+  }
+
+  /// The tests recorded in this run that have a flaky history, most flaky
+  /// first.
+  pub fn flaky_tests(&self) -> Vec<FlakyTest> {
+    let mut flaky_tests = self
+      .recorded
+      .iter()
+      .filter_map(|(key, description)| {
+        let runs = self.tests.get(key)?;
+        let count =
+          |outcome| runs.iter().filter(|r| r.outcome == outcome).count();
+        let passed = count(TestOutcome::Passed);
+        let failed = count(TestOutcome::Failed);
+        let passed_after_retry = count(TestOutcome::PassedAfterRetry);
+        let is_flaky = passed_after_retry > 0 || (passed > 0 && failed > 0);
+        is_flaky.then(|| FlakyTest {
+          description: description.clone(),
+          flaky_runs: failed + passed_after_retry,
+          total_runs: runs.len(),
+        })
+      })
+      .collect::<Vec<_>>();
+    flaky_tests.sort_by(|a, b| b.rate().total_cmp(&a.rate()));
+    flaky_tests
+  }
+
+  pub fn save(&self) -> Result<(), AnyError> {
+    if let Some(parent) = self.path.parent() {
+      std::fs::create_dir_all(parent)?;
+    }
+    let file = HistoryFile {
+      version: HISTORY_VERSION,
+      tests: self.tests.clone(),
+    };
+    std::fs::write(&self.path, serde_json::to_string_pretty(&file)?)
+      .with_context(|| {
+        format!("Failed writing test history to '{}'", self.path.display())
+      })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::tools::test::TestLocation;
+
+  fn description(name: &str) -> TestDescription {
+    TestDescription {
+      id: 0,
+      name: name.to_string(),
+      ignore: false,
+      only: false,
+      sanitize_only: false,
+      origin: "file:///project/a_test.ts".to_string(),
+      location: TestLocation {
+        file_name: "file:///project/a_test.ts".to_string(),
+        line_number: 1,
+        column_number: 1,
+      },
+      sanitize_ops: false,
+      sanitize_resources: false,
+      timeout_ms: None,
+      retry: None,
+      repeats: None,
+      quarantined: false,
+    }
+  }
+
+  #[test]
+  fn reports_flaky_tests() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("history.json");
+    let cwd = Url::parse("file:///project/").unwrap();
+    let stable = description("stable");
+    let broken = description("broken");
+    let flaky = description("flaky");
+    let retried = description("retried");
+
+    for run in 0..4 {
+      let mut history = TestHistory::load(path.clone(), cwd.clone());
+      let elapsed = Duration::from_millis(5);
+      history.record(&stable, TestOutcome::Passed, elapsed);
+      history.record(&broken, TestOutcome::Failed, elapsed);
+      let outcome = if run == 2 {
+        TestOutcome::Failed
+      } else {
+        TestOutcome::Passed
+      };
+      history.record(&flaky, outcome, elapsed);
+      if run == 3 {
+        history.record(&retried, TestOutcome::PassedAfterRetry, elapsed);
+      }
+      history.save().unwrap();
+    }
+
+    let history = TestHistory::load(path, cwd);
+    assert_eq!(history.tests.len(), 4);
+    assert_eq!(history.tests["./a_test.ts > stable"].len(), 4);
+    // nothing was recorded in this run yet
+    assert_eq!(history.flaky_tests(), vec![]);
+
+    let mut history = history;
+    history.record(&stable, TestOutcome::Passed, Duration::ZERO);
+    history.record(&broken, TestOutcome::Failed, Duration::ZERO);
+    history.record(&flaky, TestOutcome::Passed, Duration::ZERO);
+    history.record(&retried, TestOutcome::Passed, Duration::ZERO);
+    let flaky_tests = history.flaky_tests();
+    assert_eq!(
+      flaky_tests
+        .iter()
+        .map(|t| (t.description.name.as_str(), t.flaky_runs, t.total_runs))
+        .collect::<Vec<_>>(),
+      vec![("retried", 1, 2), ("flaky", 1, 5)]
+    );
+    assert_eq!(flaky_tests[1].format_rate(), "1 of 5 runs flaky, 20%");
+  }
+
+  #[test]
+  fn keeps_recent_runs() {
+    let dir = tempfile::tempdir().unwrap();
+    let cwd = Url::parse("file:///project/").unwrap();
+    let mut history = TestHistory::load(dir.path().join("history.json"), cwd);
+    let test = description("test");
+    for _ in 0..MAX_RUNS_PER_TEST + 10 {
+      history.record(&test, TestOutcome::Passed, Duration::ZERO);
+    }
+    assert_eq!(history.tests["./a_test.ts > test"].len(), MAX_RUNS_PER_TEST);
+  }
+}
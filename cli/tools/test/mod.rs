@@ -25,6 +25,7 @@ use std::time::Instant;
 
 use deno_ast::MediaType;
 use deno_cache_dir::file_fetcher::File;
+use deno_config::deno_json::QuarantinedTest;
 use deno_config::glob::FilePatterns;
 use deno_config::glob::WalkEntry;
 use deno_core::ModuleSpecifier;
@@ -92,6 +93,8 @@ use crate::worker::CreateCustomWorkerError;
 
 mod channel;
 pub mod fmt;
+pub mod history;
+pub mod mutation;
 pub mod reporters;
 mod sanitizers;
 
@@ -102,6 +105,9 @@ pub use channel::create_single_test_event_channel;
 pub use channel::create_test_event_channel;
 use fmt::format_sanitizer_diff;
 pub use fmt::format_test_error;
+use history::FlakyTest;
+use history::TestHistory;
+use history::TestOutcome;
 use reporters::CompoundTestReporter;
 use reporters::DotTestReporter;
 use reporters::JunitTestReporter;
@@ -287,6 +293,20 @@ impl TestDescriptions {
   pub fn is_empty(&self) -> bool {
     self.tests.is_empty()
   }
+
+  /// Flags the tests listed in the `test.quarantine` config, matching both
+  /// the module and the test name.
+  fn mark_quarantined(&mut self, quarantine: &[QuarantinedTest]) {
+    if quarantine.is_empty() {
+      return;
+    }
+    for description in self.tests.values_mut() {
+      description.quarantined = quarantine.iter().any(|test| {
+        test.name == description.name
+          && test.module.as_str() == description.origin
+      });
+    }
+  }
 }
 
 impl<'a> IntoIterator for &'a TestDescriptions {
@@ -321,6 +341,11 @@ pub struct TestDescription {
   /// and inherits the `--repeats` flag default; `Some(0)` is an explicit
   /// opt-out that takes precedence over the flag.
   pub repeats: Option<u32>,
+  /// Listed in the `test.quarantine` config: a failure or cancellation is
+  /// reported but doesn't fail the run. Set by [`run_tests_for_worker`]
+  /// before the tests are registered.
+  #[serde(default)]
+  pub quarantined: bool,
 }
 
 /// May represent a failure of a test or test step.
@@ -633,7 +658,14 @@ pub struct TestSummary {
   pub snapshots_updated: usize,
   pub snapshots_removed: Vec<String>,
   pub failures: Vec<(TestFailureDescription, TestFailure)>,
+  /// Number of quarantined tests that failed or were cancelled. These don't
+  /// fail the run.
+  pub quarantined: usize,
+  /// Failures of quarantined tests, which don't fail the run.
+  pub quarantined_failures: Vec<(TestFailureDescription, TestFailure)>,
   pub uncaught_errors: Vec<(String, Box<JsError>)>,
+  /// Tests with a flaky outcome history (`--history`).
+  pub flaky_tests: Vec<FlakyTest>,
 }
 
 /// Snapshot statistics for a single test module run with
@@ -658,6 +690,13 @@ struct TestSpecifiersOptions {
   /// `(index, count)` with a 1-based index, from `--shard`. Selects a subset
   /// of test files so a run can be split across machines.
   shard: Option<(usize, usize)>,
+  report: ReportTestsOptions,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ReportTestsOptions {
+  /// Where to record each test's outcome, from `--history`.
+  pub history: Option<TestHistory>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -674,6 +713,9 @@ pub struct TestSpecifierOptions {
   pub sanitize_ops: bool,
   pub sanitize_resources: bool,
   pub update_snapshots: bool,
+  /// Tests whose failures are reported but don't fail the run, from the
+  /// `test.quarantine` config.
+  pub quarantine: Vec<QuarantinedTest>,
 }
 
 impl TestSummary {
@@ -692,7 +734,10 @@ impl TestSummary {
       snapshots_updated: 0,
       snapshots_removed: Vec::new(),
       failures: Vec::new(),
+      quarantined: 0,
+      quarantined_failures: Vec::new(),
       uncaught_errors: Vec::new(),
+      flaky_tests: Vec::new(),
     }
   }
 
@@ -1224,7 +1269,9 @@ pub async fn run_tests_for_worker(
     TestContainer::with_registration_phase_ended(),
   );
 
-  let descriptions = Arc::new(container.descriptions);
+  let mut descriptions = container.descriptions;
+  descriptions.mark_quarantined(&options.quarantine);
+  let descriptions = Arc::new(descriptions);
   let result = async {
     event_tracker.register(descriptions.clone())?;
     run_tests_for_worker_inner(
@@ -1564,6 +1611,8 @@ async fn run_tests_for_worker_inner(
         {
           AttemptInvocation::Completed(result) => result,
           AttemptInvocation::Uncaught => {
+            // The uncaught error is reported for the module and fails the
+            // run even when the test is quarantined.
             fail_fast_tracker.add_failure();
             event_tracker.cancelled(desc)?;
             had_uncaught_error = true;
@@ -1661,7 +1710,7 @@ async fn run_tests_for_worker_inner(
     }
 
     if let Some(result) = final_result {
-      if matches!(result, TestResult::Failed(_)) {
+      if matches!(result, TestResult::Failed(_)) && !desc.quarantined {
         fail_fast_tracker.add_failure();
       }
       event_tracker.result(desc, result, earlier.elapsed())?;
@@ -1785,7 +1834,11 @@ async fn test_specifiers(
     .buffer_unordered(concurrent_jobs.get())
     .collect::<Vec<Result<Result<(), AnyError>, tokio::task::JoinError>>>();
 
-  let handler = spawn(async move { report_tests(receiver, reporter).await.0 });
+  let report_options = options.report.clone();
+  let handler = spawn(async move {
+    let (result, _) = report_tests(receiver, reporter, report_options).await;
+    result
+  });
 
   let (join_results, result) = future::join(join_stream, handler).await;
   sigint_handler_handle.abort();
@@ -1802,7 +1855,10 @@ async fn test_specifiers(
 pub async fn report_tests(
   mut receiver: TestEventReceiver,
   mut reporter: Box<dyn TestReporter>,
+  options: ReportTestsOptions,
 ) -> (Result<(), AnyError>, TestEventReceiver) {
+  let mut history = options.history;
+  let mut retried_tests = HashSet::new();
   let mut tests = IndexMap::new();
   let mut test_steps = IndexMap::new();
   let mut tests_started = HashSet::new();
@@ -1816,9 +1872,10 @@ pub async fn report_tests(
     match event {
       TestEvent::Register(description) => {
         for (_, description) in description.into_iter() {
-          reporter.report_register(description);
           // TODO(mmastrac): We shouldn't need to clone here -- we can reuse the descriptions everywhere
-          tests.insert(description.id, description.clone());
+          let description = description.clone();
+          reporter.report_register(&description);
+          tests.insert(description.id, description);
         }
       }
       TestEvent::Plan(plan) => {
@@ -1844,18 +1901,35 @@ pub async fn report_tests(
       }
       TestEvent::Result(id, result, elapsed) => {
         if tests_with_result.insert(id) {
+          let description = tests.get(&id).unwrap();
           match result {
+            TestResult::Failed(_) | TestResult::Cancelled
+              if description.quarantined => {}
             TestResult::Failed(_) | TestResult::Cancelled => {
               failed = true;
             }
             _ => (),
           }
-          reporter.report_result(tests.get(&id).unwrap(), &result, elapsed);
+          if let Some(history) = &mut history {
+            let outcome = match result {
+              TestResult::Ok if retried_tests.contains(&id) => {
+                Some(TestOutcome::PassedAfterRetry)
+              }
+              TestResult::Ok => Some(TestOutcome::Passed),
+              TestResult::Failed(_) => Some(TestOutcome::Failed),
+              TestResult::Ignored | TestResult::Cancelled => None,
+            };
+            if let Some(outcome) = outcome {
+              history.record(description, outcome, elapsed);
+            }
+          }
+          reporter.report_result(description, &result, elapsed);
         }
       }
       TestEvent::Retry(id, attempt, failure) => {
         // Informational only: a retried attempt does not produce a terminal
         // result and is intentionally not gated by `tests_with_result`.
+        retried_tests.insert(id);
         reporter.report_retry(tests.get(&id).unwrap(), attempt, &failure);
       }
       TestEvent::Repeat(id, repetition) => {
@@ -1955,6 +2029,13 @@ pub async fn report_tests(
     }
   }
 
+  if let Some(history) = &history {
+    reporter.report_flaky_tests(&history.flaky_tests());
+    if let Err(err) = history.save() {
+      log::warn!("{} {:#}", colors::yellow("Warning"), err);
+    }
+  }
+
   let elapsed = start_time
     .map(|t| Instant::now().duration_since(t))
     .unwrap_or_default();
@@ -2427,6 +2508,17 @@ pub async fn run_tests(
   let preload_modules = cli_options.preload_modules()?;
   let require_modules = cli_options.require_modules()?;
 
+  let cwd =
+    Url::from_directory_path(cli_options.initial_cwd()).map_err(|_| {
+      anyhow!(
+        "Unable to construct URL from the path of cwd: {}",
+        cli_options.initial_cwd().to_string_lossy(),
+      )
+    })?;
+  let history =
+    history::resolve_history_path(&factory, &workspace_test_options.history)?
+      .map(|path| TestHistory::load(path, cwd.clone()));
+
   // Run tests
   test_specifiers(
     worker_factory,
@@ -2436,14 +2528,7 @@ pub async fn run_tests(
     preload_modules,
     require_modules,
     TestSpecifiersOptions {
-      cwd: Url::from_directory_path(cli_options.initial_cwd()).map_err(
-        |_| {
-          anyhow!(
-            "Unable to construct URL from the path of cwd: {}",
-            cli_options.initial_cwd().to_string_lossy(),
-          )
-        },
-      )?,
+      cwd: cwd.clone(),
       concurrent_jobs: workspace_test_options.concurrent_jobs,
       fail_fast: workspace_test_options.fail_fast,
       log_level,
@@ -2452,6 +2537,7 @@ pub async fn run_tests(
       junit_path: workspace_test_options.junit_path,
       hide_stacktraces: workspace_test_options.hide_stacktraces,
       shard: workspace_test_options.shard,
+      report: ReportTestsOptions { history },
       specifier: TestSpecifierOptions {
         filter: TestFilter::from_flag(&workspace_test_options.filter),
         shuffle: workspace_test_options.shuffle,
@@ -2461,6 +2547,7 @@ pub async fn run_tests(
         sanitize_ops: workspace_test_options.sanitize_ops,
         sanitize_resources: workspace_test_options.sanitize_resources,
         update_snapshots: workspace_test_options.update_snapshots,
+        quarantine: workspace_test_options.quarantine.clone(),
       },
     },
   )
@@ -2493,6 +2580,7 @@ pub async fn run_tests(
           .into_owned(),
       ),
       test_flags.coverage_threshold.map(|t| t as f64),
+      None,
       &reporters,
     ) {
       // An unmet coverage threshold is a deliberate failure and must fail the
@@ -2691,6 +2779,19 @@ pub async fn run_tests_with_watch(
           Arc::new(factory.create_cli_main_worker_factory().await?);
         let preload_modules = cli_options.preload_modules()?;
         let require_modules = cli_options.require_modules()?;
+        let cwd = Url::from_directory_path(cli_options.initial_cwd()).map_err(
+          |_| {
+            anyhow!(
+              "Unable to construct URL from the path of cwd: {}",
+              cli_options.initial_cwd().to_string_lossy(),
+            )
+          },
+        )?;
+        let history = history::resolve_history_path(
+          &factory,
+          &workspace_test_options.history,
+        )?
+        .map(|path| TestHistory::load(path, cwd.clone()));
 
         test_specifiers(
           worker_factory,
@@ -2700,14 +2801,7 @@ pub async fn run_tests_with_watch(
           preload_modules,
           require_modules,
           TestSpecifiersOptions {
-            cwd: Url::from_directory_path(cli_options.initial_cwd()).map_err(
-              |_| {
-                anyhow!(
-                  "Unable to construct URL from the path of cwd: {}",
-                  cli_options.initial_cwd().to_string_lossy(),
-                )
-              },
-            )?,
+            cwd: cwd.clone(),
             concurrent_jobs: workspace_test_options.concurrent_jobs,
             fail_fast: workspace_test_options.fail_fast,
             log_level,
@@ -2716,6 +2810,7 @@ pub async fn run_tests_with_watch(
             junit_path: workspace_test_options.junit_path,
             hide_stacktraces: workspace_test_options.hide_stacktraces,
             shard: workspace_test_options.shard,
+            report: ReportTestsOptions { history },
             specifier: TestSpecifierOptions {
               filter: TestFilter::from_flag(&workspace_test_options.filter),
               shuffle: workspace_test_options.shuffle,
@@ -2725,6 +2820,7 @@ pub async fn run_tests_with_watch(
               sanitize_ops: workspace_test_options.sanitize_ops,
               sanitize_resources: workspace_test_options.sanitize_resources,
               update_snapshots: workspace_test_options.update_snapshots,
+              quarantine: workspace_test_options.quarantine.clone(),
             },
           },
         )
@@ -0,0 +1,1062 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Mutation testing for `deno test --mutate`.
+//!
+//! Coverage says which lines the tests run, not whether the tests would
+//! notice if those lines were wrong. Mutation testing answers that by making
+//! small changes ("mutants") to the source modules and checking that some
+//! test fails for each of them:
+//!
+//! 1. Every test module runs once, unmodified, in a `deno test` subprocess
+//!    with coverage collection. This must pass, and gives the source lines
+//!    each test module runs and how long it takes.
+//! 2. The covered source modules are parsed with `deno_ast` and the
+//!    mutation operators are applied: flipping comparisons and logical
+//!    operators, swapping boolean literals, changing arithmetic and removing
+//!    calls.
+//! 3. Each mutant is written into a sandbox copy of the workspace, and only
+//!    the test modules that run the mutated line are run against it. A test
+//!    failure (or a timeout) kills the mutant; otherwise it survived. There is
+//!    one sandbox per job, so mutants run in parallel with `--parallel`.
+//!    The sandbox gets the workspace files that aren't excluded by the
+//!    workspace config or a `.gitignore`.
+//! 4. Results are cached in the Deno dir, keyed by the mutant and by the
+//!    test modules that cover it together with every source module those
+//!    test modules run, so an interrupted run resumes where it stopped and a
+//!    repeated run only tests what changed.
+//!
+//! Test selection is per test module, not per test case: coverage is
+//! collected for a whole test module, so every test in a test module that
+//! runs the mutated line is run against the mutant, including the tests that
+//! don't run it themselves.
+//!
+//! The workspace itself is never modified.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::ffi::OsString;
+use std::io::Write;
+use std::ops::Range;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use deno_ast::MediaType;
+use deno_ast::ModuleSpecifier;
+use deno_ast::SourceRangedForSpanned as _;
+use deno_ast::StartSourcePos;
+use deno_ast::swc::ast;
+use deno_ast::swc::ecma_visit::Visit;
+use deno_ast::swc::ecma_visit::VisitWith as _;
+use deno_config::glob::FileCollector;
+use deno_config::glob::FilePatterns;
+use deno_config::glob::PathOrPattern;
+use deno_config::glob::PathOrPatternSet;
+use deno_core::anyhow::Context;
+use deno_core::anyhow::anyhow;
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+use deno_core::futures::StreamExt;
+use deno_core::futures::stream;
+use deno_core::serde_json;
+use deno_lib::util::hash::FastInsecureHasher;
+use deno_path_util::url_to_file_path;
+use serde::Deserialize;
+use serde::Serialize;
+use sys_traits::FsSymlinkDir;
+
+use super::TestMode;
+use super::fetch_specifiers_with_test_mode;
+use crate::args::Flags;
+use crate::args::TestFlags;
+use crate::args::clap_root;
+use crate::colors;
+use crate::factory::CliFactory;
+use crate::sys::CliSys;
+use crate::tools::coverage::covered_lines;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Mutant {
+  /// Byte range in the original source that gets replaced.
+  range: Range<usize>,
+  replacement: String,
+  description: String,
+  /// Zero-based, matching the coverage line indexes.
+  line_index: usize,
+  /// One-based, for display.
+  column: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum MutantStatus {
+  /// A test failed with the mutant applied.
+  Killed,
+  /// The tests didn't finish in time with the mutant applied, which is
+  /// counted as detected.
+  TimedOut,
+  /// All the tests covering the mutated line still passed.
+  Survived,
+  /// No test runs the mutated line.
+  NoCoverage,
+}
+
+impl MutantStatus {
+  fn is_detected(self) -> bool {
+    matches!(self, Self::Killed | Self::TimedOut)
+  }
+
+  fn display(self) -> String {
+    match self {
+      Self::Killed => colors::green("killed").to_string(),
+      Self::TimedOut => colors::green("timed out").to_string(),
+      Self::Survived => colors::red("survived").to_string(),
+      Self::NoCoverage => colors::yellow("no coverage").to_string(),
+    }
+  }
+}
+
+fn mutate_binary_op(op: ast::BinaryOp) -> Option<(&'static str, &'static str)> {
+  use ast::BinaryOp::*;
+  Some(match op {
+    EqEq => ("==", "!="),
+    NotEq => ("!=", "=="),
+    EqEqEq => ("===", "!=="),
+    NotEqEq => ("!==", "==="),
+    Lt => ("<", ">="),
+    LtEq => ("<=", ">"),
+    Gt => (">", "<="),
+    GtEq => (">=", "<"),
+    Add => ("+", "-"),
+    Sub => ("-", "+"),
+    Mul => ("*", "/"),
+    Div => ("/", "*"),
+    Mod => ("%", "*"),
+    LogicalAnd => ("&&", "||"),
+    LogicalOr => ("||", "&&"),
+    _ => return None,
+  })
+}
+
+struct MutantCollector<'a> {
+  text: &'a str,
+  start_pos: StartSourcePos,
+  mutants: Vec<Mutant>,
+}
+
+impl MutantCollector<'_> {
+  fn add(
+    &mut self,
+    range: Range<usize>,
+    replacement: &str,
+    description: String,
+  ) {
+    let (line_index, column) = line_and_column(self.text, range.start);
+    self.mutants.push(Mutant {
+      range,
+      replacement: replacement.to_string(),
+      description,
+      line_index,
+      column,
+    });
+  }
+}
+
+impl Visit for MutantCollector<'_> {
+  fn visit_bin_expr(&mut self, node: &ast::BinExpr) {
+    if let Some((from, to)) = mutate_binary_op(node.op) {
+      // the operator has no span of its own, so find it between the operands
+      let between = node.left.range().end.as_byte_index(self.start_pos)
+        ..node.right.range().start.as_byte_index(self.start_pos);
+      if let Some(offset) = self.text[between.clone()].find(from) {
+        let start = between.start + offset;
+        self.add(
+          start..start + from.len(),
+          to,
+          format!("replaced `{from}` with `{to}`"),
+        );
+      }
+    }
+    node.visit_children_with(self);
+  }
+
+  fn visit_bool(&mut self, node: &ast::Bool) {
+    let range = node.range().as_byte_range(self.start_pos);
+    let (from, to) = if node.value {
+      ("true", "false")
+    } else {
+      ("false", "true")
+    };
+    self.add(range, to, format!("replaced `{from}` with `{to}`"));
+  }
+
+  fn visit_expr_stmt(&mut self, node: &ast::ExprStmt) {
+    if let ast::Expr::Call(call) = &*node.expr
+      && let ast::Callee::Expr(callee) = &call.callee
+    {
+      let callee_range = callee.range().as_byte_range(self.start_pos);
+      let callee_text = &self.text[callee_range];
+      let description = if callee_text.len() > 40 || callee_text.contains('\n')
+      {
+        "removed call".to_string()
+      } else {
+        format!("removed call to `{callee_text}`")
+      };
+      self.add(node.range().as_byte_range(self.start_pos), "", description);
+    }
+    node.visit_children_with(self);
+  }
+
+  // types are erased at runtime, so mutating them can't be detected
+  fn visit_ts_type(&mut self, _node: &ast::TsType) {}
+}
+
+fn line_and_column(text: &str, byte_index: usize) -> (usize, usize) {
+  let before = &text[..byte_index];
+  let line_index = before.matches('\n').count();
+  let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+  (line_index, before[line_start..].chars().count() + 1)
+}
+
+fn collect_mutants(
+  specifier: &ModuleSpecifier,
+  source: Arc<str>,
+  media_type: MediaType,
+) -> Result<Vec<Mutant>, AnyError> {
+  let parsed_source = deno_ast::parse_program(deno_ast::ParseParams {
+    specifier: specifier.clone(),
+    text: source,
+    media_type,
+    capture_tokens: false,
+    maybe_syntax: None,
+    scope_analysis: false,
+  })?;
+  let mut collector = MutantCollector {
+    text: parsed_source.text().as_ref(),
+    start_pos: parsed_source.start_pos(),
+    mutants: Vec::new(),
+  };
+  parsed_source.program_ref().visit_with(&mut collector);
+  let mut mutants = collector.mutants;
+  mutants.sort_by_key(|mutant| (mutant.range.start, mutant.range.end));
+  Ok(mutants)
+}
+
+fn apply_mutant(source: &str, mutant: &Mutant) -> String {
+  let mut text = String::with_capacity(source.len());
+  text.push_str(&source[..mutant.range.start]);
+  text.push_str(&mutant.replacement);
+  text.push_str(&source[mutant.range.end..]);
+  text
+}
+
+enum TestRun {
+  Passed(Duration),
+  Failed(String),
+  TimedOut,
+}
+
+/// Flags of `deno test` that the `deno test` subprocesses don't get: the ones
+/// about mutation testing itself, the ones selecting test modules, which are
+/// passed explicitly instead, and the ones writing reports or watching files,
+/// which would otherwise happen once per subprocess.
+const PARENT_ONLY_TEST_ARGS: &[&str] = &[
+  "changed",
+  "clean",
+  "coverage",
+  "coverage-raw-data-only",
+  "coverage-threshold",
+  "fail-fast",
+  "files",
+  "history",
+  "junit-path",
+  "mutate",
+  "no-clear-screen",
+  "parallel",
+  "permit-no-files",
+  "related",
+  "reporter",
+  "shard",
+  "watch",
+  "watch-exclude",
+];
+
+/// Whether the value of `arg` can be passed as the next argument, as in
+/// `--reporter dot`.
+fn takes_separate_value(arg: &clap::Arg) -> bool {
+  arg.get_action().takes_values()
+    && !arg.is_require_equals_set()
+    && arg
+      .get_num_args()
+      .is_some_and(|num_args| num_args.min_values() > 0)
+}
+
+/// Splits the arguments of this process, without the executable, into the
+/// arguments for the `deno test` subprocesses and the script arguments after
+/// `--`. Every flag is looked up in the CLI definition so that the flags in
+/// [`PARENT_ONLY_TEST_ARGS`] are left out together with their values.
+fn test_subprocess_args(
+  raw_args: impl IntoIterator<Item = OsString>,
+) -> (Vec<OsString>, Vec<OsString>) {
+  let mut root = clap_root();
+  root.build();
+  let mut command = &root;
+  let mut is_test_command = false;
+  let mut args = Vec::new();
+  let mut raw_args = raw_args.into_iter();
+  while let Some(raw_arg) = raw_args.next() {
+    let text = raw_arg.to_string_lossy().into_owned();
+    if text == "--" {
+      return (args, raw_args.collect());
+    }
+    let (arg, has_separate_value) = if let Some(long) = text.strip_prefix("--")
+    {
+      let (name, has_value) = match long.split_once('=') {
+        Some((name, _)) => (name, true),
+        None => (long, false),
+      };
+      let arg = command.get_arguments().find(|arg| {
+        arg.get_long() == Some(name)
+          || arg
+            .get_all_aliases()
+            .is_some_and(|aliases| aliases.contains(&name))
+      });
+      (arg, !has_value && arg.is_some_and(takes_separate_value))
+    } else if let Some(shorts) = text.strip_prefix('-')
+      && !shorts.is_empty()
+    {
+      // in a group like `-qA`, the first flag taking a value gets the rest
+      // of the group, or the next argument when it's last
+      let mut result = (None, false);
+      for (index, short) in shorts.char_indices() {
+        let arg = command.get_arguments().find(|arg| {
+          arg.get_short() == Some(short)
+            || arg
+              .get_all_short_aliases()
+              .is_some_and(|aliases| aliases.contains(&short))
+        });
+        result = (arg, false);
+        if let Some(arg) = arg
+          && arg.get_action().takes_values()
+        {
+          let is_last = index + short.len_utf8() == shorts.len();
+          result.1 = is_last && takes_separate_value(arg);
+          break;
+        }
+      }
+      result
+    } else if !is_test_command {
+      args.push(raw_arg);
+      if let Some(subcommand) = command.find_subcommand(&text)
+        && subcommand.get_name() == "test"
+      {
+        command = subcommand;
+        is_test_command = true;
+        // the first failure is enough to kill a mutant
+        args.push("--fail-fast".into());
+      }
+      continue;
+    } else {
+      // the test modules to run are passed explicitly
+      continue;
+    };
+    let is_excluded = is_test_command
+      && arg.is_some_and(|arg| {
+        PARENT_ONLY_TEST_ARGS.contains(&arg.get_id().as_str())
+      });
+    let value = if has_separate_value {
+      raw_args.next()
+    } else {
+      None
+    };
+    if !is_excluded {
+      args.push(raw_arg);
+      args.extend(value);
+    }
+  }
+  (args, Vec::new())
+}
+
+/// Runs test modules in `deno test` subprocesses that use the same flags as
+/// this process.
+struct TestModuleRunner {
+  deno_exe: PathBuf,
+  args: Vec<OsString>,
+  script_args: Vec<OsString>,
+  root: PathBuf,
+  initial_cwd: PathBuf,
+}
+
+impl TestModuleRunner {
+  fn new(root: PathBuf, initial_cwd: PathBuf) -> Result<Self, AnyError> {
+    let (args, script_args) = test_subprocess_args(std::env::args_os().skip(1));
+    Ok(Self {
+      deno_exe: std::env::current_exe()?,
+      args,
+      script_args,
+      root,
+      initial_cwd,
+    })
+  }
+
+  /// Where `path` in the workspace is in the given sandbox.
+  fn sandbox_path(&self, sandbox: &Path, path: &Path) -> PathBuf {
+    sandbox.join(path.strip_prefix(&self.root).unwrap_or(path))
+  }
+
+  async fn run(
+    &self,
+    sandbox: Option<&Path>,
+    modules: &[&Path],
+    coverage_dir: Option<&Path>,
+    timeout: Option<Duration>,
+  ) -> Result<TestRun, AnyError> {
+    let (cwd, modules) = match sandbox {
+      Some(sandbox) => (
+        self.sandbox_path(sandbox, &self.initial_cwd),
+        modules
+          .iter()
+          .map(|module| self.sandbox_path(sandbox, module))
+          .collect::<Vec<_>>(),
+      ),
+      None => (
+        self.initial_cwd.clone(),
+        modules.iter().map(|module| module.to_path_buf()).collect(),
+      ),
+    };
+    let mut command = tokio::process::Command::new(&self.deno_exe);
+    command.args(&self.args).args(modules);
+    if !self.script_args.is_empty() {
+      command.arg("--").args(&self.script_args);
+    }
+    command
+      .current_dir(cwd)
+      .env("NO_COLOR", "1")
+      .stdin(Stdio::null())
+      .kill_on_drop(true);
+    match coverage_dir {
+      Some(coverage_dir) => {
+        // only the baseline run needs the output, to explain a failure
+        command
+          .env("DENO_COVERAGE_DIR", coverage_dir)
+          .stdout(Stdio::piped())
+          .stderr(Stdio::piped());
+      }
+      None => {
+        command
+          .env_remove("DENO_COVERAGE_DIR")
+          .stdout(Stdio::null())
+          .stderr(Stdio::null());
+      }
+    }
+    let start = Instant::now();
+    let output = command.spawn()?.wait_with_output();
+    let output = match timeout {
+      Some(timeout) => match tokio::time::timeout(timeout, output).await {
+        Ok(output) => output?,
+        Err(_) => return Ok(TestRun::TimedOut),
+      },
+      None => output.await?,
+    };
+    if output.status.success() {
+      Ok(TestRun::Passed(start.elapsed()))
+    } else {
+      let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+      text.push_str(&String::from_utf8_lossy(&output.stderr));
+      Ok(TestRun::Failed(text))
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedResult {
+  key: String,
+  status: MutantStatus,
+}
+
+/// Mutant results from previous runs, appended to as mutants finish so that
+/// an interrupted run loses at most the mutants in flight.
+struct MutationResultCache {
+  path: PathBuf,
+  results: HashMap<String, MutantStatus>,
+}
+
+impl MutationResultCache {
+  fn load(path: PathBuf) -> Self {
+    let results = std::fs::read_to_string(&path)
+      .map(|text| {
+        text
+          .lines()
+          .filter_map(|line| serde_json::from_str::<CachedResult>(line).ok())
+          .map(|result| (result.key, result.status))
+          .collect()
+      })
+      .unwrap_or_default();
+    Self { path, results }
+  }
+
+  fn get(&self, key: &str) -> Option<MutantStatus> {
+    self.results.get(key).copied()
+  }
+
+  fn insert(&mut self, key: String, status: MutantStatus) {
+    let line = serde_json::to_string(&CachedResult {
+      key: key.clone(),
+      status,
+    })
+    .unwrap();
+    let result = std::fs::OpenOptions::new()
+      .create(true)
+      .append(true)
+      .open(&self.path)
+      .and_then(|mut file| writeln!(file, "{line}"));
+    if let Err(err) = result {
+      log::debug!("Failed caching mutant result: {err:#}");
+    }
+    self.results.insert(key, status);
+  }
+}
+
+/// Copies the workspace files into a sandbox. `node_modules` folders are
+/// linked rather than copied since mutants never touch them.
+fn copy_workspace(
+  root: &Path,
+  files: &[PathBuf],
+  node_modules_dirs: &[PathBuf],
+  to: &Path,
+) -> Result<(), AnyError> {
+  for file in files {
+    let to_path = to.join(file.strip_prefix(root)?);
+    if let Some(parent) = to_path.parent() {
+      std::fs::create_dir_all(parent)
+        .with_context(|| format!("Failed creating '{}'", parent.display()))?;
+    }
+    std::fs::copy(file, &to_path)
+      .with_context(|| format!("Failed copying '{}'", file.display()))?;
+  }
+  for dir in node_modules_dirs {
+    let to_path = to.join(dir.strip_prefix(root)?);
+    if let Some(parent) = to_path.parent() {
+      std::fs::create_dir_all(parent)
+        .with_context(|| format!("Failed creating '{}'", parent.display()))?;
+    }
+    CliSys::default()
+      .fs_symlink_dir(dir, &to_path)
+      .with_context(|| format!("Failed linking '{}'", dir.display()))?;
+  }
+  Ok(())
+}
+
+struct SourceModule {
+  path: PathBuf,
+  display_path: String,
+  source: Arc<str>,
+  mutants: Vec<Mutant>,
+}
+
+pub async fn run_mutation_tests(
+  flags: Arc<Flags>,
+  test_flags: TestFlags,
+) -> Result<(), AnyError> {
+  let factory = CliFactory::from_flags(flags.clone());
+  let cli_options = factory.cli_options()?;
+  let workspace_test_options =
+    cli_options.resolve_workspace_test_options(&test_flags);
+  let file_fetcher = factory.file_fetcher()?;
+  let members_with_test_options =
+    cli_options.resolve_test_options_for_members(&test_flags)?;
+  let specifiers_with_mode = fetch_specifiers_with_test_mode(
+    cli_options,
+    file_fetcher,
+    members_with_test_options.into_iter().map(|(_, v)| v.files),
+    &workspace_test_options.doc,
+  )
+  .await?;
+  let test_modules = specifiers_with_mode
+    .into_iter()
+    .filter(|(_, mode)| *mode != TestMode::Documentation)
+    .filter_map(|(specifier, _)| url_to_file_path(&specifier).ok())
+    .collect::<Vec<_>>();
+  if test_modules.is_empty() {
+    bail!("No test modules found");
+  }
+
+  let root = url_to_file_path(cli_options.workspace().root_dir_url())?;
+  let initial_cwd = cli_options.initial_cwd().to_path_buf();
+  if !initial_cwd.starts_with(&root) {
+    bail!(
+      "Mutation testing must be run from within the workspace at '{}'",
+      root.display()
+    );
+  }
+  let deno_dir_root = factory.deno_dir()?.root.clone();
+  let state_dir = deno_dir_root.join("mutation_v1").join(format!(
+    "{:016x}",
+    FastInsecureHasher::new_without_deno_version()
+      .write_str(&root.to_string_lossy())
+      .finish()
+  ));
+  let concurrency = workspace_test_options.concurrent_jobs.get();
+  let runner = TestModuleRunner::new(root.clone(), initial_cwd.clone())?;
+
+  // 1. Run each test module once to learn what it covers.
+  log::info!(
+    "{} {} test module(s) to collect coverage",
+    colors::green("Running"),
+    test_modules.len()
+  );
+  let coverage_root = state_dir.join("coverage");
+  let _ = std::fs::remove_dir_all(&coverage_root);
+  let baseline_runs = stream::iter(test_modules.iter().enumerate())
+    .map(|(index, module)| {
+      let coverage_dir = coverage_root.join(index.to_string());
+      let runner = &runner;
+      async move {
+        std::fs::create_dir_all(&coverage_dir)?;
+        let run = runner
+          .run(None, &[module.as_path()], Some(&coverage_dir), None)
+          .await?;
+        Ok::<_, AnyError>((index, coverage_dir, run))
+      }
+    })
+    .buffer_unordered(concurrency)
+    .collect::<Vec<_>>()
+    .await;
+  let mut durations = vec![Duration::ZERO; test_modules.len()];
+  // test module index -> the source modules it runs, so that the cached
+  // results of a test module are invalidated when any of them change
+  let mut covered_paths = vec![Vec::new(); test_modules.len()];
+  // source module -> (test module index, lines it runs); coverage is only
+  // known per test module, so that's also the unit of test selection
+  let mut coverage_by_module: BTreeMap<
+    ModuleSpecifier,
+    Vec<(usize, HashSet<usize>)>,
+  > = BTreeMap::new();
+  for result in baseline_runs {
+    let (index, coverage_dir, run) = result?;
+    match run {
+      TestRun::Passed(duration) => durations[index] = duration,
+      TestRun::Failed(output) => bail!(
+        "Mutation testing needs a passing test suite, but '{}' failed:\n{}",
+        test_modules[index].display(),
+        output.trim_end()
+      ),
+      TestRun::TimedOut => unreachable!(),
+    }
+    // a test module that doesn't run any source module has no coverage
+    let Ok(lines_by_module) = covered_lines(flags.clone(), &coverage_dir)
+    else {
+      continue;
+    };
+    for (specifier, lines) in lines_by_module {
+      if let Ok(path) = url_to_file_path(&specifier) {
+        covered_paths[index].push(path);
+      }
+      coverage_by_module
+        .entry(specifier)
+        .or_default()
+        .push((index, lines));
+    }
+    covered_paths[index].sort();
+  }
+
+  // 2. Generate the mutants of the covered source modules that get copied
+  // into the sandboxes.
+  let mut exclude = cli_options.workspace().resolve_config_excludes()?;
+  exclude.push(PathOrPattern::Path(deno_dir_root.clone()));
+  let workspace_files = FileCollector::new(|_| true)
+    .ignore_git_folder()
+    .ignore_node_modules()
+    .use_gitignore()
+    .collect_file_patterns(
+      &CliSys::default(),
+      &FilePatterns {
+        base: root.clone(),
+        include: None,
+        exclude,
+      },
+    );
+  let node_modules_dirs = cli_options
+    .workspace()
+    .config_folders()
+    .keys()
+    .filter_map(|dir_url| url_to_file_path(dir_url).ok())
+    .map(|dir| dir.join("node_modules"))
+    .filter(|dir| dir.is_dir())
+    .collect::<Vec<_>>();
+  let copied_files = workspace_files.iter().collect::<HashSet<_>>();
+  let mutate_patterns = match &test_flags.mutate {
+    Some(paths) if !paths.is_empty() => {
+      Some(PathOrPatternSet::from_include_relative_path_or_patterns(
+        &initial_cwd,
+        paths,
+      )?)
+    }
+    _ => None,
+  };
+  let mut source_modules = Vec::new();
+  for specifier in coverage_by_module.keys() {
+    let Ok(path) = url_to_file_path(specifier) else {
+      continue;
+    };
+    // only files copied into the sandboxes can be mutated there
+    if !copied_files.contains(&path) {
+      continue;
+    }
+    if let Some(patterns) = &mutate_patterns
+      && !patterns.matches_path(&path)
+    {
+      continue;
+    }
+    let source: Arc<str> = std::fs::read_to_string(&path)
+      .with_context(|| format!("Failed reading '{}'", path.display()))?
+      .into();
+    let media_type = MediaType::from_path(&path);
+    let mutants = collect_mutants(specifier, source.clone(), media_type)
+      .with_context(|| format!("Failed parsing '{}'", path.display()))?;
+    let display_path = path
+      .strip_prefix(&initial_cwd)
+      .unwrap_or(&path)
+      .to_string_lossy()
+      .replace('\\', "/");
+    source_modules.push(SourceModule {
+      path,
+      display_path,
+      source,
+      mutants,
+    });
+  }
+  if source_modules
+    .iter()
+    .all(|module| module.mutants.is_empty())
+  {
+    match &mutate_patterns {
+      Some(_) => bail!("No mutants were generated for the given paths"),
+      None => bail!("No mutants were generated"),
+    }
+  }
+
+  // 3. Work out what still needs to run.
+  std::fs::create_dir_all(&state_dir)?;
+  let mut cache = MutationResultCache::load(state_dir.join("results.jsonl"));
+  let mut test_module_hashes = HashMap::new();
+  let mut results: Vec<Vec<Option<MutantStatus>>> = Vec::new();
+  let mut pending = Vec::new();
+  let mut cached_count = 0;
+  for (module_index, module) in source_modules.iter().enumerate() {
+    let specifier = deno_path_util::url_from_file_path(&module.path)?;
+    let coverage = coverage_by_module.get(&specifier);
+    let mut module_results = Vec::with_capacity(module.mutants.len());
+    for (mutant_index, mutant) in module.mutants.iter().enumerate() {
+      let covering_test_modules = coverage
+        .map(|coverage| {
+          coverage
+            .iter()
+            .filter(|(_, lines)| lines.contains(&mutant.line_index))
+            .map(|(index, _)| *index)
+            .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+      if covering_test_modules.is_empty() {
+        module_results.push(Some(MutantStatus::NoCoverage));
+        continue;
+      }
+      let mut hasher = FastInsecureHasher::new_deno_versioned();
+      hasher
+        .write_str(&module.display_path)
+        .write_str(&module.source)
+        .write_u64(mutant.range.start as u64)
+        .write_u64(mutant.range.end as u64)
+        .write_str(&mutant.replacement);
+      for index in &covering_test_modules {
+        let test_hash =
+          *test_module_hashes.entry(*index).or_insert_with(|| {
+            let mut hasher = FastInsecureHasher::new_without_deno_version();
+            hasher
+              .write_str(&test_modules[*index].to_string_lossy())
+              .write(&std::fs::read(&test_modules[*index]).unwrap_or_default());
+            for path in &covered_paths[*index] {
+              hasher
+                .write_str(&path.to_string_lossy())
+                .write(&std::fs::read(path).unwrap_or_default());
+            }
+            hasher.finish()
+          });
+        hasher.write_u64(test_hash);
+      }
+      let key = format!("{:016x}", hasher.finish());
+      match cache.get(&key) {
+        Some(status) => {
+          cached_count += 1;
+          module_results.push(Some(status));
+        }
+        None => {
+          module_results.push(None);
+          pending.push((
+            module_index,
+            mutant_index,
+            covering_test_modules,
+            key,
+          ));
+        }
+      }
+    }
+    results.push(module_results);
+  }
+
+  // 4. Run the pending mutants, one sandbox per job.
+  if !pending.is_empty() {
+    let sandbox_count = concurrency.min(pending.len());
+    log::info!(
+      "{} {} mutant(s){}",
+      colors::green("Testing"),
+      pending.len(),
+      if cached_count > 0 {
+        format!(" ({} cached)", cached_count)
+      } else {
+        String::new()
+      }
+    );
+    let sandbox_root = state_dir.join("sandboxes");
+    let _ = std::fs::remove_dir_all(&sandbox_root);
+    let mut sandboxes = Vec::with_capacity(sandbox_count);
+    for index in 0..sandbox_count {
+      let sandbox = sandbox_root.join(index.to_string());
+      copy_workspace(&root, &workspace_files, &node_modules_dirs, &sandbox)?;
+      // the working directory of the subprocesses might have no files
+      std::fs::create_dir_all(runner.sandbox_path(&sandbox, &initial_cwd))?;
+      sandboxes.push(sandbox);
+    }
+    let free_sandboxes = RefCell::new(sandboxes);
+
+    let mut runs = stream::iter(pending)
+      .map(|(module_index, mutant_index, covering_test_modules, key)| {
+        let module = &source_modules[module_index];
+        let mutant = &module.mutants[mutant_index];
+        let runner = &runner;
+        let free_sandboxes = &free_sandboxes;
+        let test_modules = &test_modules;
+        let durations = &durations;
+        async move {
+          // at most `sandbox_count` runs are in flight
+          let sandbox = free_sandboxes.borrow_mut().pop().unwrap();
+          let sandbox_file = runner.sandbox_path(&sandbox, &module.path);
+          let result = async {
+            std::fs::write(
+              &sandbox_file,
+              apply_mutant(&module.source, mutant),
+            )?;
+            let modules = covering_test_modules
+              .iter()
+              .map(|index| test_modules[*index].as_path())
+              .collect::<Vec<_>>();
+            let expected: Duration = covering_test_modules
+              .iter()
+              .map(|index| durations[*index])
+              .sum();
+            let timeout = expected * 3 + Duration::from_secs(5);
+            runner
+              .run(Some(&sandbox), &modules, None, Some(timeout))
+              .await
+          }
+          .await;
+          let restored =
+            std::fs::write(&sandbox_file, module.source.as_bytes());
+          free_sandboxes.borrow_mut().push(sandbox);
+          restored?;
+          let status = match result? {
+            TestRun::Passed(_) => MutantStatus::Survived,
+            TestRun::Failed(_) => MutantStatus::Killed,
+            TestRun::TimedOut => MutantStatus::TimedOut,
+          };
+          Ok::<_, AnyError>((module_index, mutant_index, key, status))
+        }
+      })
+      .buffer_unordered(sandbox_count);
+    while let Some(result) = runs.next().await {
+      let (module_index, mutant_index, key, status) = result?;
+      let module = &source_modules[module_index];
+      let mutant = &module.mutants[mutant_index];
+      log::info!(
+        "{}:{}:{} {} ... {}",
+        module.display_path,
+        mutant.line_index + 1,
+        mutant.column,
+        mutant.description,
+        status.display()
+      );
+      cache.insert(key, status);
+      results[module_index][mutant_index] = Some(status);
+    }
+    drop(runs);
+    let _ = std::fs::remove_dir_all(&sandbox_root);
+  }
+
+  // 5. Report.
+  let mut counts: HashMap<MutantStatus, usize> = HashMap::new();
+  let mut survivors = Vec::new();
+  let mut uncovered = Vec::new();
+  for (module, module_results) in source_modules.iter().zip(&results) {
+    for (mutant, status) in module.mutants.iter().zip(module_results) {
+      let status = status.ok_or_else(|| anyhow!("Mutant was not tested"))?;
+      *counts.entry(status).or_default() += 1;
+      let location = format!(
+        "{}:{}:{} {}",
+        module.display_path,
+        mutant.line_index + 1,
+        mutant.column,
+        mutant.description
+      );
+      match status {
+        MutantStatus::Survived => survivors.push(location),
+        MutantStatus::NoCoverage => uncovered.push(location),
+        MutantStatus::Killed | MutantStatus::TimedOut => {}
+      }
+    }
+  }
+  let count = |status| counts.get(&status).copied().unwrap_or(0);
+  let total = counts.values().sum::<usize>();
+  let detected = counts
+    .iter()
+    .filter(|(status, _)| status.is_detected())
+    .map(|(_, count)| count)
+    .sum::<usize>();
+
+  if !survivors.is_empty() {
+    log::info!("\n{}", colors::red_bold("Surviving mutants:"));
+    for location in &survivors {
+      log::info!("  {location}");
+    }
+  }
+  if !uncovered.is_empty() {
+    log::info!("\n{}", colors::yellow("Mutants not run by any test:"));
+    for location in &uncovered {
+      log::info!("  {location}");
+    }
+  }
+  log::info!(
+    "\n{} mutants: {} killed, {} timed out, {} survived, {} without coverage",
+    total,
+    count(MutantStatus::Killed),
+    count(MutantStatus::TimedOut),
+    count(MutantStatus::Survived),
+    count(MutantStatus::NoCoverage),
+  );
+  log::info!(
+    "{} {:.1}%",
+    colors::bold("Mutation score:"),
+    detected as f64 / total as f64 * 100.0
+  );
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn mutants(source: &str) -> Vec<(String, String)> {
+    let specifier = ModuleSpecifier::parse("file:///mod.ts").unwrap();
+    collect_mutants(&specifier, source.into(), MediaType::TypeScript)
+      .unwrap()
+      .into_iter()
+      .map(|mutant| (mutant.description.clone(), apply_mutant(source, &mutant)))
+      .collect()
+  }
+
+  #[test]
+  fn mutates_operators_and_literals() {
+    assert_eq!(
+      mutants("const a = b < c && true;"),
+      vec![
+        (
+          "replaced `<` with `>=`".to_string(),
+          "const a = b >= c && true;".to_string()
+        ),
+        (
+          "replaced `&&` with `||`".to_string(),
+          "const a = b < c || true;".to_string()
+        ),
+        (
+          "replaced `true` with `false`".to_string(),
+          "const a = b < c && false;".to_string()
+        ),
+      ]
+    );
+    assert_eq!(
+      mutants("x = (a + b) * c;"),
+      vec![
+        (
+          "replaced `+` with `-`".to_string(),
+          "x = (a - b) * c;".to_string()
+        ),
+        (
+          "replaced `*` with `/`".to_string(),
+          "x = (a + b) / c;".to_string()
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn removes_call_statements() {
+    assert_eq!(
+      mutants("console.log(1);\nconst x = f();"),
+      vec![(
+        "removed call to `console.log`".to_string(),
+        "\nconst x = f();".to_string()
+      )]
+    );
+  }
+
+  #[test]
+  fn skips_types() {
+    assert_eq!(mutants("let a: true = true as true;").len(), 1);
+  }
+
+  #[test]
+  fn strips_parent_only_args_with_their_values() {
+    let (args, script_args) = test_subprocess_args(
+      [
+        "--quiet",
+        "test",
+        "-A",
+        "--junit-path",
+        "report.xml",
+        "--reporter",
+        "dot",
+        "--coverage=cov",
+        "--filter",
+        "foo",
+        "src/",
+        "--mutate=src",
+        "--",
+        "arg",
+      ]
+      .map(OsString::from),
+    );
+    assert_eq!(
+      args,
+      ["--quiet", "test", "--fail-fast", "-A", "--filter", "foo"]
+        .map(OsString::from)
+    );
+    assert_eq!(script_args, vec![OsString::from("arg")]);
+  }
+
+  #[test]
+  fn reports_one_based_columns() {
+    let specifier = ModuleSpecifier::parse("file:///mod.ts").unwrap();
+    let mutants = collect_mutants(
+      &specifier,
+      "a;\n  b == c;".into(),
+      MediaType::TypeScript,
+    )
+    .unwrap();
+    assert_eq!(mutants[0].line_index, 1);
+    assert_eq!(mutants[0].column, 5);
+  }
+}
@@ -67,6 +67,28 @@ pub(super) fn discard_step_results(
   pending.remove(&root_id);
 }
 
+/// Records a failed or cancelled test in the summary. A quarantined test is
+/// counted apart so it is reported without failing the run.
+pub(super) fn record_failure(
+  summary: &mut TestSummary,
+  description: &TestDescription,
+  failure: Option<&TestFailure>,
+) {
+  if description.quarantined {
+    summary.quarantined += 1;
+    if let Some(failure) = failure {
+      summary
+        .quarantined_failures
+        .push((description.into(), failure.clone()));
+    }
+  } else {
+    summary.failed += 1;
+    if let Some(failure) = failure {
+      summary.failures.push((description.into(), failure.clone()));
+    }
+  }
+}
+
 pub(super) fn format_test_step_ancestry(
   desc: &TestStepDescription,
   tests: &IndexMap<usize, TestDescription>,
@@ -300,6 +322,34 @@ pub(super) fn report_summary(
     }
   }
 
+  if !summary.quarantined_failures.is_empty() {
+    writeln!(writer, "\n{}\n", colors::yellow_bold("QUARANTINED")).ok();
+    for (description, failure) in &summary.quarantined_failures {
+      writeln!(writer, "{}", format_test_for_summary(cwd, description)).ok();
+      writeln!(
+        writer,
+        "{}: {}",
+        colors::yellow_bold("error"),
+        failure.format(options)
+      )
+      .ok();
+      writeln!(writer).ok();
+    }
+  }
+
+  if !summary.flaky_tests.is_empty() {
+    writeln!(writer, "\n{}\n", colors::yellow_bold("FLAKY TESTS")).ok();
+    for flaky_test in &summary.flaky_tests {
+      writeln!(
+        writer,
+        "{} {}",
+        format_test_for_summary(cwd, &flaky_test.description),
+        colors::gray(format!("({})", flaky_test.format_rate()))
+      )
+      .ok();
+    }
+  }
+
   if summary.snapshots_updated > 0 {
     writeln!(
       writer,
@@ -369,6 +419,10 @@ pub(super) fn report_summary(
     write!(summary_result, " | {} flaky", summary.flaky).ok();
   }
 
+  if summary.quarantined > 0 {
+    write!(summary_result, " | {} quarantined", summary.quarantined).ok();
+  }
+
   let ignored_steps = get_steps_text(summary.ignored_steps);
   if summary.ignored > 0 || !ignored_steps.is_empty() {
     write!(
@@ -108,6 +108,12 @@ impl TestReporter for CompoundTestReporter {
     }
   }
 
+  fn report_flaky_tests(&mut self, flaky_tests: &[FlakyTest]) {
+    for reporter in &mut self.test_reporters {
+      reporter.report_flaky_tests(flaky_tests);
+    }
+  }
+
   fn report_summary(
     &mut self,
     elapsed: &Duration,
@@ -62,10 +62,19 @@ impl DotTestReporter {
     self.print_status(status);
   }
 
-  fn print_test_result(&mut self, result: &TestResult) {
+  fn print_test_result(
+    &mut self,
+    description: &TestDescription,
+    result: &TestResult,
+  ) {
     let status = match result {
       TestResult::Ok => fmt_ok(),
       TestResult::Ignored => fmt_ignored(),
+      TestResult::Failed(_) | TestResult::Cancelled
+        if description.quarantined =>
+      {
+        fmt_quarantined()
+      }
       TestResult::Failed(_failure) => fmt_failed(),
       TestResult::Cancelled => fmt_cancelled(),
     };
@@ -86,6 +95,10 @@ fn fmt_failed() -> String {
   colors::red_bold("!").to_string()
 }
 
+fn fmt_quarantined() -> String {
+  colors::yellow("!").to_string()
+}
+
 fn fmt_cancelled() -> String {
   colors::gray("!").to_string()
 }
@@ -135,18 +148,14 @@ impl TestReporter for DotTestReporter {
         self.summary.ignored += 1;
       }
       TestResult::Failed(failure) => {
-        self.summary.failed += 1;
-        self
-          .summary
-          .failures
-          .push((description.into(), failure.clone()));
+        common::record_failure(&mut self.summary, description, Some(failure));
       }
       TestResult::Cancelled => {
-        self.summary.failed += 1;
+        common::record_failure(&mut self.summary, description, None);
       }
     }
 
-    self.print_test_result(result);
+    self.print_test_result(description, result);
   }
 
   fn report_retry(
@@ -213,6 +222,10 @@ impl TestReporter for DotTestReporter {
       .extend(summary.removed.iter().cloned());
   }
 
+  fn report_flaky_tests(&mut self, flaky_tests: &[FlakyTest]) {
+    self.summary.flaky_tests = flaky_tests.to_vec();
+  }
+
   fn report_summary(
     &mut self,
     elapsed: &Duration,
@@ -142,11 +142,42 @@ impl TestReporter for JunitTestReporter {
     elapsed: Duration,
   ) {
     if let Some(case) = self.cases.get_mut(&description.id) {
-      case.status = Self::convert_status(result, &self.failure_format_options);
+      case.status = match result {
+        // a quarantined failure doesn't fail the run, so don't fail the report
+        TestResult::Failed(failure) if description.quarantined => {
+          JunitTestCaseStatus::Skipped {
+            message: Some(format!(
+              "quarantined: {}",
+              strip_ansi_codes(&failure.overview())
+            )),
+            ty: None,
+            description: None,
+          }
+        }
+        TestResult::Cancelled if description.quarantined => {
+          JunitTestCaseStatus::Skipped {
+            message: Some("quarantined: Cancelled".to_string()),
+            ty: None,
+            description: None,
+          }
+        }
+        _ => Self::convert_status(result, &self.failure_format_options),
+      };
       case.set_time(elapsed);
     }
   }
 
+  fn report_flaky_tests(&mut self, flaky_tests: &[FlakyTest]) {
+    for flaky_test in flaky_tests {
+      if let Some(case) = self.cases.get_mut(&flaky_test.description.id) {
+        case.extra.insert(
+          String::from("flakiness"),
+          format!("{:.2}", flaky_test.rate()),
+        );
+      }
+    }
+  }
+
   fn report_uncaught_error(&mut self, _origin: &str, _error: Box<JsError>) {}
 
   fn report_step_register(&mut self, description: &TestStepDescription) {
@@ -519,7 +550,7 @@ fn serialize_status(
   }
 }
 
-fn write_attr(
+pub fn write_attr(
   mut writer: impl Write,
   name: &str,
   value: &str,
@@ -529,7 +560,10 @@ fn write_attr(
   write!(writer, "\"")
 }
 
-fn write_escaped(mut writer: impl Write, value: &str) -> std::io::Result<()> {
+pub fn write_escaped(
+  mut writer: impl Write,
+  value: &str,
+) -> std::io::Result<()> {
   for ch in value.chars() {
     match ch {
       '<' => write!(writer, "&lt;")?,
@@ -543,7 +577,7 @@ fn write_escaped(mut writer: impl Write, value: &str) -> std::io::Result<()> {
   Ok(())
 }
 
-fn format_time(time: Duration) -> String {
+pub fn format_time(time: Duration) -> String {
   format!("{:.3}", time.as_secs_f64())
 }
 
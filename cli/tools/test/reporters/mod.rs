@@ -12,6 +12,9 @@ mod tap;
 pub use compound::CompoundTestReporter;
 pub use dot::DotTestReporter;
 pub use junit::JunitTestReporter;
+pub use junit::format_time;
+pub use junit::write_attr;
+pub use junit::write_escaped;
 pub use pretty::PrettyTestReporter;
 pub use tap::TapTestReporter;
 
@@ -63,6 +66,9 @@ pub trait TestReporter {
   /// removed snapshots. Reporters that print a final summary should
   /// accumulate these counts and include them there.
   fn report_snapshot_summary(&mut self, _summary: &TestSnapshotSummary) {}
+  /// Called before the summary with the tests from this run that have a
+  /// flaky outcome history (`--history`). Defaults to a no-op.
+  fn report_flaky_tests(&mut self, _flaky_tests: &[FlakyTest]) {}
   fn report_summary(
     &mut self,
     elapsed: &Duration,
@@ -298,14 +298,10 @@ impl TestReporter for PrettyTestReporter {
         self.summary.ignored += 1;
       }
       TestResult::Failed(failure) => {
-        self.summary.failed += 1;
-        self
-          .summary
-          .failures
-          .push((description.into(), failure.clone()));
+        common::record_failure(&mut self.summary, description, Some(failure));
       }
       TestResult::Cancelled => {
-        self.summary.failed += 1;
+        common::record_failure(&mut self.summary, description, None);
       }
     }
 
@@ -350,6 +346,11 @@ impl TestReporter for PrettyTestReporter {
     {
       write!(&mut self.writer, " ({})", inline_summary).ok();
     }
+    if description.quarantined
+      && matches!(result, TestResult::Failed(_) | TestResult::Cancelled)
+    {
+      write!(&mut self.writer, " {}", colors::yellow("(quarantined)")).ok();
+    }
     writeln!(
       &mut self.writer,
       " {}",
@@ -483,6 +484,10 @@ impl TestReporter for PrettyTestReporter {
       .extend(summary.removed.iter().cloned());
   }
 
+  fn report_flaky_tests(&mut self, flaky_tests: &[FlakyTest]) {
+    self.summary.flaky_tests = flaky_tests.to_vec();
+  }
+
   fn report_summary(
     &mut self,
     elapsed: &Duration,
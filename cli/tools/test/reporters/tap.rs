@@ -176,6 +176,12 @@ impl TestReporter for TapTestReporter {
     let (status, directive) = match result {
       TestResult::Ok => ("ok", ""),
       TestResult::Ignored => ("ok", " # SKIP"),
+      // A TODO directive tells TAP consumers the failure is expected
+      TestResult::Failed(_) | TestResult::Cancelled
+        if description.quarantined =>
+      {
+        ("not ok", " # TODO quarantined")
+      }
       TestResult::Failed(_failure) => ("not ok", ""),
       TestResult::Cancelled => ("not ok", ""),
     };
@@ -226,6 +232,16 @@ impl TestReporter for TapTestReporter {
     self.print_step_result(desc, result);
   }
 
+  fn report_flaky_tests(&mut self, flaky_tests: &[FlakyTest]) {
+    for flaky_test in flaky_tests {
+      println!(
+        "# flaky: {} ({})",
+        Self::escape_description(&flaky_test.description.name),
+        flaky_test.format_rate()
+      );
+    }
+  }
+
   fn report_summary(
     &mut self,
     _elapsed: &Duration,
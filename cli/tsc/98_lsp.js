@@ -419,6 +419,157 @@ function formatErrorWithArgs(error, args) {
   return errorString;
 }
 
+/**
+ * @param {ts.SourceFile} sourceFile
+ * @param {number} position
+ * @returns {ts.Node}
+ */
+function getNodeAtPosition(sourceFile, position) {
+  /** @type {ts.Node} */
+  let node = sourceFile;
+  while (true) {
+    const child = node.getChildren(sourceFile).find((c) =>
+      c.getStart(sourceFile) <= position && position <= c.getEnd()
+    );
+    if (!child) {
+      return node;
+    }
+    node = child;
+  }
+}
+
+/**
+ * @param {ts.Node | undefined} node
+ * @returns {node is ts.ClassLikeDeclaration | ts.InterfaceDeclaration}
+ */
+function isTypeHierarchyDeclaration(node) {
+  return !!node && (ts.isClassLike(node) || ts.isInterfaceDeclaration(node));
+}
+
+/**
+ * The class and interface declarations of the symbol at a node.
+ * @param {ts.TypeChecker} checker
+ * @param {ts.Node} node
+ * @returns {(ts.ClassLikeDeclaration | ts.InterfaceDeclaration)[]}
+ */
+function getTypeHierarchyDeclarations(checker, node) {
+  if (isTypeHierarchyDeclaration(node.parent) && node.parent.name === node) {
+    return [node.parent];
+  }
+  let symbol = checker.getSymbolAtLocation(node);
+  if (symbol && symbol.flags & ts.SymbolFlags.Alias) {
+    symbol = checker.getAliasedSymbol(symbol);
+  }
+  return symbol?.declarations?.filter(isTypeHierarchyDeclaration) ?? [];
+}
+
+/**
+ * Converts a declaration to the shape of a `ts.CallHierarchyItem`, which the
+ * language server reuses for type hierarchy items.
+ * @param {ts.ClassLikeDeclaration | ts.InterfaceDeclaration} declaration
+ */
+function toTypeHierarchyItem(declaration) {
+  const sourceFile = declaration.getSourceFile();
+  const start = declaration.getStart(sourceFile);
+  const name = declaration.name;
+  return {
+    name: name?.text ?? "default",
+    kind: ts.isInterfaceDeclaration(declaration)
+      ? ts.ScriptElementKind.interfaceElement
+      : ts.ScriptElementKind.classElement,
+    kindModifiers: ts.getJSDocDeprecatedTag(declaration) ? "deprecated" : "",
+    file: sourceFile.fileName,
+    span: { start, length: declaration.getEnd() - start },
+    selectionSpan: name
+      ? { start: name.getStart(sourceFile), length: name.getWidth(sourceFile) }
+      : { start, length: 0 },
+  };
+}
+
+/**
+ * The language service has no type hierarchy, so it is built from the
+ * heritage clauses of class and interface declarations. Supertypes are the
+ * types a declaration extends or implements. Subtypes are the declarations
+ * with a reference to it in their heritage clauses.
+ * @param {ts.LanguageService} ls
+ * @param {string} method
+ * @param {string} specifier
+ * @param {number} position
+ */
+function getTypeHierarchy(ls, method, specifier, position) {
+  const program = ls.getProgram();
+  const sourceFile = program?.getSourceFile(specifier);
+  if (!program || !sourceFile) {
+    return [];
+  }
+  const checker = program.getTypeChecker();
+  const declarations = getTypeHierarchyDeclarations(
+    checker,
+    getNodeAtPosition(sourceFile, position),
+  );
+  /** @type {(ts.ClassLikeDeclaration | ts.InterfaceDeclaration)[]} */
+  const results = [];
+  switch (method) {
+    case "$prepareTypeHierarchy": {
+      results.push(...declarations);
+      break;
+    }
+    case "$provideTypeHierarchySupertypes": {
+      for (const declaration of declarations) {
+        for (const clause of declaration.heritageClauses ?? []) {
+          for (const type of clause.types) {
+            const expression = ts.isPropertyAccessExpression(type.expression)
+              ? type.expression.name
+              : type.expression;
+            results.push(...getTypeHierarchyDeclarations(checker, expression));
+          }
+        }
+      }
+      break;
+    }
+    case "$provideTypeHierarchySubtypes": {
+      for (const declaration of declarations) {
+        if (!declaration.name) {
+          continue;
+        }
+        const declarationFile = declaration.getSourceFile();
+        const referencedSymbols = ls.findReferences(
+          declarationFile.fileName,
+          declaration.name.getStart(declarationFile),
+        ) ?? [];
+        for (const { references } of referencedSymbols) {
+          for (const reference of references) {
+            const referenceFile = program.getSourceFile(reference.fileName);
+            if (!referenceFile) {
+              continue;
+            }
+            let node = getNodeAtPosition(
+              referenceFile,
+              reference.textSpan.start,
+            );
+            if (
+              ts.isPropertyAccessExpression(node.parent) &&
+              node.parent.name === node
+            ) {
+              node = node.parent;
+            }
+            const clause = node.parent?.parent;
+            if (
+              ts.isExpressionWithTypeArguments(node.parent) &&
+              clause && ts.isHeritageClause(clause) &&
+              isTypeHierarchyDeclaration(clause.parent)
+            ) {
+              results.push(clause.parent);
+            }
+          }
+        }
+      }
+      break;
+    }
+  }
+  return [...new Set(results)].map(toTypeHierarchyItem);
+}
+
 /**
  * @param {number} id
  * @param {string} method
@@ -603,6 +754,29 @@ function serverRequestInner(
         ) ?? [],
       );
     }
+    case "$prepareTypeHierarchy":
+    case "$provideTypeHierarchySupertypes":
+    case "$provideTypeHierarchySubtypes": {
+      try {
+        return respond(id, getTypeHierarchy(ls, method, args[0], args[1]));
+      } catch (e) {
+        if (!isCancellationError(e)) {
+          return respond(
+            id,
+            [],
+            formatErrorWithArgs(e, [
+              id,
+              method,
+              args,
+              compilerOptionsKey,
+              notebookUri,
+              maybeChange,
+            ]),
+          );
+        }
+        return respond(id, []);
+      }
+    }
     default:
       if (typeof ls[method] === "function") {
         // The `getCompletionEntryDetails()` method returns null if the
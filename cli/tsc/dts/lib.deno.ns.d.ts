@@ -813,6 +813,46 @@ declare namespace Deno {
      * ```
      */
     assertSnapshot<T>(actual: T, message?: string): Promise<void>;
+
+    /** A virtual clock for the test. The first access freezes time for
+     * timers, `Date` and `performance.now()`, which then only moves when the
+     * clock is advanced. Time goes back to normal when the test finishes.
+     *
+     * Internal timers, such as the one behind `AbortSignal.timeout()`, use
+     * the same clock.
+     *
+     * ```ts
+     * Deno.test("debounces", async (t) => {
+     *   let calls = 0;
+     *   setTimeout(() => calls++, 1000);
+     *   await t.clock.tick(999);
+     *   console.log(calls); // 0
+     *   await t.clock.tick(1);
+     *   console.log(calls); // 1
+     * });
+     * ```
+     */
+    readonly clock: TestClock;
+  }
+
+  /** The virtual clock available as {@linkcode Deno.TestContext.clock}.
+   *
+   * @category Testing */
+  export interface TestClock {
+    /** The current virtual time, in milliseconds since the epoch, as
+     * returned by `Date.now()`. */
+    readonly now: number;
+    /** Move time forward by `ms` milliseconds, running each timer that
+     * comes due on the way at its scheduled time. Promise continuations
+     * get to run between timers. */
+    tick(ms: number): Promise<void>;
+    /** Run timers until none remain, moving time forward to each one.
+     * Rejects if there are still timers after 10,000 expiries, as happens
+     * with an interval that is never cleared. */
+    runAll(): Promise<void>;
+    /** Go back to real time before the test finishes. The remaining timers
+     * are scheduled in real time. */
+    restore(): void;
   }
 
   /** Options which can be set when calling
@@ -448,11 +448,68 @@ declare namespace Deno {
    * kv.close();
    * ```
    *
+   * Databases stored locally (in a file or in memory) can raise or lower the
+   * limits `Deno.Kv` enforces with the `limits` option, or from the config
+   * file: `kv.limits` applies to every local database, and
+   * `kv.databases["./path.db"].limits` to a single database file. Remote
+   * databases always use the default limits, and passing `limits` when
+   * opening one throws a `TypeError`.
+   *
+   * ```ts
+   * const kv = await Deno.openKv("./my_database.sqlite", {
+   *   limits: { maxValueSizeBytes: 1024 * 1024 },
+   * });
+   * ```
+   *
    * @tags allow-read, allow-write
    * @category Cloud
    * @experimental
    */
-  export function openKv(path?: string): Promise<Kv>;
+  export function openKv(path?: string, options?: KvOpenOptions): Promise<Kv>;
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Options for {@linkcode Deno.openKv}.
+   *
+   * @category Cloud
+   * @experimental
+   */
+  export interface KvOpenOptions {
+    /** Overrides of the default limits, for databases stored locally. */
+    limits?: KvLimits;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Limits enforced on the operations of a {@linkcode Deno.Kv} database. Each
+   * limit that is not set keeps its default value, given in brackets.
+   *
+   * @category Cloud
+   * @experimental
+   */
+  export interface KvLimits {
+    /** Maximum size of a key that is written, in bytes. [2048] */
+    maxWriteKeySizeBytes?: number;
+    /** Maximum size of a value, in bytes. [65536] */
+    maxValueSizeBytes?: number;
+    /** Maximum number of ranges read by a single read. [10] */
+    maxReadRanges?: number;
+    /** Maximum number of entries read by a single read. [1000] */
+    maxReadEntries?: number;
+    /** Maximum number of checks in an atomic operation. [100] */
+    maxChecks?: number;
+    /** Maximum number of mutations and enqueues in an atomic operation.
+     * [1000] */
+    maxMutations?: number;
+    /** Maximum number of keys watched by {@linkcode Deno.Kv.watch}. [10] */
+    maxWatchedKeys?: number;
+    /** Maximum total size of the keys and values written by an atomic
+     * operation, in bytes. [819200] */
+    maxTotalMutationSizeBytes?: number;
+    /** Maximum total size of the keys written by an atomic operation, in
+     * bytes. [81920] */
+    maxTotalKeySizeBytes?: number;
+  }
 
   /** **UNSTABLE**: New API, yet to be vetted.
    *
@@ -571,6 +628,50 @@ declare namespace Deno {
     handler: () => Promise<void> | void,
   ): Promise<void>;
 
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * A recorded execution attempt of a cron job, as returned by
+   * {@linkcode Deno.cronHistory}.
+   *
+   * @category Cloud
+   * @experimental
+   */
+  export interface CronExecution {
+    /** The scheduled time this attempt ran for. Retries of a failed
+     * execution share it. */
+    scheduledAt: Date;
+    /** When the handler finished running. */
+    finishedAt: Date;
+    /** Whether the handler completed without throwing. */
+    success: boolean;
+    /** Whether this execution was missed while the process wasn't running
+     * and was caught up on startup. */
+    catchUp: boolean;
+  }
+
+  /** **UNSTABLE**: New API, yet to be vetted.
+   *
+   * Returns the most recent execution attempts of the cron job `name`,
+   * newest first, up to `limit` of them (100 by default).
+   *
+   * Executions are only recorded when the `DENO_CRON_DB_PATH` environment
+   * variable points to a database to persist them in. Otherwise the history
+   * is always empty.
+   *
+   * ```ts
+   * for (const execution of await Deno.cronHistory("sample cron")) {
+   *   console.log(execution.scheduledAt, execution.success);
+   * }
+   * ```
+   *
+   * @category Cloud
+   * @experimental
+   */
+  export function cronHistory(
+    name: string,
+    options?: { limit?: number },
+  ): Promise<CronExecution[]>;
+
   /** **UNSTABLE**: New API, yet to be vetted.
    *
    * A key to be persisted in a {@linkcode Deno.Kv}. A key is a sequence
@@ -1254,6 +1355,32 @@ declare namespace Deno {
       options?: { raw?: boolean },
     ): ReadableStream<{ [K in keyof T]: KvEntryMaybe<T[K]> }>;
 
+    /**
+     * Write a consistent snapshot of the database to a new SQLite file at
+     * `path`, replacing the file if it exists. The database stays usable
+     * while the backup is taken, and the snapshot includes queued messages.
+     *
+     * Only databases stored in local files can be backed up. Requires write
+     * permission for `path`.
+     *
+     * ```ts
+     * const db = await Deno.openKv("./kv.sqlite3");
+     * await db.backup("./kv-backup.sqlite3");
+     * ```
+     */
+    backup(path: string | URL): Promise<void>;
+
+    /**
+     * Replace the contents of the database with a snapshot written by
+     * `.backup()`, without closing the database. Versionstamps keep
+     * increasing across the restore, and queued messages that were being
+     * delivered when the snapshot was taken are delivered again.
+     *
+     * Only databases stored in local files can be restored. Requires read
+     * permission for `path`.
+     */
+    restore(path: string | URL): Promise<void>;
+
     /**
      * Close the database connection. This will prevent any further operations
      * from being performed on the database, and interrupt any in-flight
@@ -1,6 +1,9 @@
 // Copyright 2018-2026 the Deno authors. MIT license.
 
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
 use std::path::Path;
+use std::path::PathBuf;
 use std::process::Stdio;
 
 use deno_core::anyhow::anyhow;
@@ -72,3 +75,138 @@ pub async fn check_if_git_repo_dirty(cwd: &Path) -> Option<String> {
     Some(text.to_string())
   }
 }
+
+/// Lines (1-based, inclusive) that changed in a file since a git ref.
+pub type ChangedLineRanges = Vec<RangeInclusive<usize>>;
+
+/// Computes the lines that changed in the working tree since the merge-base
+/// of `base` and `HEAD`, keyed by absolute file path.
+///
+/// Untracked files (respecting .gitignore) are considered changed in full.
+/// Deleted files and pure deletions within a file have no changed lines and
+/// are not included.
+pub fn changed_line_ranges(
+  cwd: &Path,
+  base: &str,
+) -> Result<HashMap<PathBuf, ChangedLineRanges>, AnyError> {
+  let repo_root =
+    run_git(cwd, &["rev-parse", "--show-toplevel"]).map_err(|err| {
+      anyhow!("`--changed-since` requires a git repository: {err}")
+    })?;
+  let repo_root = PathBuf::from(repo_root.trim());
+
+  // Run from the repository root so all paths are root-relative. The explicit
+  // prefixes guard against a user's `diff.noprefix` or `diff.mnemonicPrefix`
+  // configuration.
+  let diff = run_git(
+    &repo_root,
+    &[
+      "-c",
+      "core.quotePath=false",
+      "diff",
+      "--unified=0",
+      "--no-color",
+      "--no-ext-diff",
+      "--src-prefix=a/",
+      "--dst-prefix=b/",
+      "--merge-base",
+      base,
+    ],
+  )?;
+  let mut changed = parse_unified_diff(&repo_root, &diff);
+
+  let untracked =
+    run_git(&repo_root, &["ls-files", "--others", "--exclude-standard"])?;
+  for line in untracked.lines() {
+    let line = line.trim();
+    if !line.is_empty() {
+      changed.insert(repo_root.join(line), vec![1..=usize::MAX]);
+    }
+  }
+  Ok(changed)
+}
+
+/// Parses the output of `git diff --unified=0` into the added or modified
+/// line ranges of each file in the new version.
+fn parse_unified_diff(
+  repo_root: &Path,
+  diff: &str,
+) -> HashMap<PathBuf, ChangedLineRanges> {
+  let mut changed: HashMap<PathBuf, ChangedLineRanges> = HashMap::new();
+  let mut current_file = None;
+  for line in diff.lines() {
+    if let Some(path) = line.strip_prefix("+++ ") {
+      let path = path.trim_matches('"');
+      current_file = path.strip_prefix("b/").map(|path| repo_root.join(path));
+    } else if let Some(hunk) = line.strip_prefix("@@ ") {
+      let Some(file) = &current_file else {
+        continue;
+      };
+      // e.g. "@@ -10,2 +12,3 @@ fn foo() {"
+      let Some(new_range) =
+        hunk.split(' ').find_map(|part| part.strip_prefix('+'))
+      else {
+        continue;
+      };
+      let (start, count) = match new_range.split_once(',') {
+        Some((start, count)) => (start.parse::<usize>(), count.parse()),
+        None => (new_range.parse::<usize>(), Ok(1)),
+      };
+      let (Ok(start), Ok(count)) = (start, count) else {
+        continue;
+      };
+      // A count of zero means lines were only removed at this position.
+      if count == 0 {
+        continue;
+      }
+      changed
+        .entry(file.clone())
+        .or_default()
+        .push(start..=start + count - 1);
+    }
+  }
+  changed
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_unified_diff_hunks() {
+    let diff = r#"diff --git a/src/a.ts b/src/a.ts
+index 1111111..2222222 100644
+--- a/src/a.ts
++++ b/src/a.ts
+@@ -3 +3 @@ export function a() {
+-  return 1;
++  return 2;
+@@ -10,0 +11,3 @@ export function b() {
++  if (x) {
++    y();
++  }
+@@ -20,2 +23,0 @@
+-  z();
+-  w();
+diff --git a/old.ts b/old.ts
+deleted file mode 100644
+--- a/old.ts
++++ /dev/null
+@@ -1,2 +0,0 @@
+-a
+-b
+diff --git a/new.ts b/new.ts
+new file mode 100644
+--- /dev/null
++++ b/new.ts
+@@ -0,0 +1,2 @@
++a
++b
+"#;
+    let root = Path::new("/repo");
+    let changed = parse_unified_diff(root, diff);
+    assert_eq!(changed.len(), 2);
+    assert_eq!(changed[&root.join("src/a.ts")], vec![3..=3, 11..=13]);
+    assert_eq!(changed[&root.join("new.ts")], vec![1..=2]);
+  }
+}
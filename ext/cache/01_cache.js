@@ -13,6 +13,7 @@ const {
 } = core.ops;
 const {
   ArrayPrototypePush,
+  DateNow,
   ObjectPrototypeIsPrototypeOf,
   StringPrototypeSplit,
   StringPrototypeTrim,
@@ -37,6 +38,14 @@ const {
   readableStreamForRid,
   resourceForReadableStream,
 } = core.loadExtScript("ext:deno_web/06_streams.js");
+const {
+  otelState,
+  builtinMeter,
+  builtinTracer,
+} = core.loadExtScript("ext:deno_telemetry/telemetry.ts");
+const { updateSpanFromError } = core.loadExtScript(
+  "ext:deno_telemetry/util.ts",
+);
 class CacheStorage {
   constructor() {
     webidl.illegalConstructor();
@@ -50,6 +59,7 @@ class CacheStorage {
     const cacheId = await op_cache_storage_open(cacheName);
     const cache = webidl.createBranded(Cache);
     cache[_id] = cacheId;
+    cache[_name] = cacheName;
     return cache;
   }
 
@@ -113,10 +123,32 @@ class CacheStorage {
 
 const _matchAll = Symbol("[[matchAll]]");
 const _id = Symbol("id");
+const _name = Symbol("name");
+
+let cacheMetrics;
+
+function getCacheMetrics() {
+  if (!cacheMetrics) {
+    const meter = builtinMeter();
+    cacheMetrics = {
+      duration: meter.createHistogram("deno.cache.match.duration", {
+        description: "Duration of Cache API lookups.",
+        unit: "s",
+      }),
+      lookups: meter.createCounter("deno.cache.lookups", {
+        description: "Number of Cache API lookups.",
+        unit: "{lookup}",
+      }),
+    };
+  }
+  return cacheMetrics;
+}
 
 class Cache {
   /** @type {number} */
   [_id];
+  /** @type {string} */
+  [_name];
 
   constructor() {
     webidl.illegalConstructor();
@@ -217,11 +249,44 @@ class Cache {
       prefix,
       "Argument 1",
     );
-    const p = await this[_matchAll](request, options);
-    if (p.length > 0) {
-      return p[0];
-    } else {
-      return undefined;
+    if (!otelState.TRACING_ENABLED && !otelState.METRICS_ENABLED) {
+      const p = await this[_matchAll](request, options);
+      if (p.length > 0) {
+        return p[0];
+      } else {
+        return undefined;
+      }
+    }
+
+    let span;
+    if (otelState.TRACING_ENABLED) {
+      span = builtinTracer().startSpan("deno.cache.match", { kind: 2 });
+      span.setAttribute("deno.cache.name", this[_name]);
+    }
+    const start = DateNow();
+    let result = "error";
+    try {
+      const p = await this[_matchAll](request, options);
+      result = p.length > 0 ? "hit" : "miss";
+      if (span) {
+        span.setAttribute("deno.cache.result", result);
+        span.setStatus({ code: 1 });
+      }
+      return p.length > 0 ? p[0] : undefined;
+    } catch (error) {
+      if (span) updateSpanFromError(span, error);
+      throw error;
+    } finally {
+      span?.end();
+      if (otelState.METRICS_ENABLED) {
+        const metrics = getCacheMetrics();
+        const attributes = {
+          "deno.cache.name": this[_name],
+          "deno.cache.result": result,
+        };
+        metrics.duration.record((DateNow() - start) / 1000, attributes);
+        metrics.lookups.add(1, attributes);
+      }
     }
   }
 
@@ -2,15 +2,19 @@
 
 (function () {
 const { core, internals, primordials } = __bootstrap;
-const { op_cron_create, op_cron_next } = core.ops;
+const { op_cron_create, op_cron_history, op_cron_next } = core.ops;
 const {
   ArrayPrototypeJoin,
+  ArrayPrototypeMap,
+  Date,
+  DateNow,
   NumberPrototypeToString,
   SafeArrayIterator,
   TypeError,
 } = primordials;
 const {
   otelState,
+  builtinMeter,
   builtinTracer,
   ContextManager,
   enterSpan,
@@ -20,6 +24,26 @@ const { updateSpanFromError } = core.loadExtScript(
   "ext:deno_telemetry/util.ts",
 );
 
+let cronMetrics;
+
+function getCronMetrics() {
+  if (!cronMetrics) {
+    const meter = builtinMeter();
+    cronMetrics = {
+      duration: meter.createHistogram("deno.cron.execution.duration", {
+        description: "Duration of Deno.cron handler executions.",
+        unit: "s",
+      }),
+      retries: meter.createCounter("deno.cron.execution.retries", {
+        description:
+          "Number of Deno.cron handler executions that were retries of a failed one.",
+        unit: "{execution}",
+      }),
+    };
+  }
+  return cronMetrics;
+}
+
 function formatToCronSchedule(
   value?: number | { exact: number | number[] } | {
     start?: number;
@@ -194,6 +218,11 @@ function cron(
         );
         span.setAttribute("deno.cron.name", name);
         span.setAttribute("deno.cron.schedule", schedule);
+        span.setAttribute("deno.cron.retries", r.retries);
+      }
+      const start = otelState.METRICS_ENABLED ? DateNow() : 0;
+      if (otelState.METRICS_ENABLED && r.retries > 0) {
+        getCronMetrics().retries.add(1, { "deno.cron.name": name });
       }
       try {
         if (span) {
@@ -219,13 +248,40 @@ function cron(
         internals.log("error", `Exception in cron handler ${name}`, error);
         success = false;
       }
+      if (otelState.METRICS_ENABLED) {
+        getCronMetrics().duration.record((DateNow() - start) / 1000, {
+          "deno.cron.name": name,
+          "deno.cron.result": success ? "success" : "failure",
+        });
+      }
     }
   })();
 }
 
+const DEFAULT_HISTORY_LIMIT = 100;
+
+async function cronHistory(
+  name: string,
+  options?: { limit?: number },
+): Promise<Deno.CronExecution[]> {
+  const limit = options?.limit ?? DEFAULT_HISTORY_LIMIT;
+  if (!(limit >= 0)) {
+    throw new TypeError(
+      `Cannot read cron history, limit must be a non-negative number: received ${limit}`,
+    );
+  }
+  const executions = await op_cron_history(name, limit);
+  return ArrayPrototypeMap(executions, (execution) => ({
+    scheduledAt: new Date(execution.scheduledAtMs),
+    finishedAt: new Date(execution.finishedAtMs),
+    success: execution.success,
+    catchUp: execution.catchUp,
+  }));
+}
+
 // For testing
 internals.formatToCronSchedule = formatToCronSchedule;
 internals.parseScheduleToString = parseScheduleToString;
 
-return { cron, formatToCronSchedule, parseScheduleToString };
+return { cron, cronHistory, formatToCronSchedule, parseScheduleToString };
 })();
@@ -2,12 +2,17 @@
 
 use std::rc::Rc;
 
+use async_trait::async_trait;
+
 use crate::CronError;
 use crate::CronHandle;
 use crate::CronHandler;
 use crate::CronSpec;
 use crate::local::LocalCronHandler;
 use crate::socket::SocketCronHandler;
+use crate::sqlite::CatchUpPolicy;
+use crate::sqlite::CronExecution;
+use crate::sqlite::SqliteCronStore;
 
 pub enum CronHandlerImpl {
   Local(LocalCronHandler),
@@ -18,11 +23,36 @@ impl CronHandlerImpl {
   pub fn create_from_env() -> Self {
     match std::env::var("DENO_UNSTABLE_CRON_SOCK") {
       Ok(socket_addr) => Self::Socket(SocketCronHandler::new(socket_addr)),
-      Err(_) => Self::Local(LocalCronHandler::new()),
+      Err(_) => Self::Local(create_local_from_env()),
+    }
+  }
+}
+
+/// Persists executions to `DENO_CRON_DB_PATH` when it's set, catching up on
+/// missed ones according to `DENO_CRON_CATCH_UP`.
+fn create_local_from_env() -> LocalCronHandler {
+  let Ok(db_path) = std::env::var("DENO_CRON_DB_PATH") else {
+    return LocalCronHandler::new();
+  };
+  let catch_up = match std::env::var("DENO_CRON_CATCH_UP") {
+    Ok(value) => value.parse::<CatchUpPolicy>().unwrap_or_else(|err| {
+      log::warn!("{err}, defaulting to 'run-once'");
+      CatchUpPolicy::default()
+    }),
+    Err(_) => CatchUpPolicy::default(),
+  };
+  match SqliteCronStore::open(std::path::Path::new(&db_path)) {
+    Ok(store) => LocalCronHandler::new_with_store(store, catch_up),
+    Err(err) => {
+      log::warn!(
+        "Failed to open cron database at '{db_path}', executions will not be persisted: {err}"
+      );
+      LocalCronHandler::new()
     }
   }
 }
 
+#[async_trait(?Send)]
 impl CronHandler for CronHandlerImpl {
   fn create(&self, spec: CronSpec) -> Result<Rc<dyn CronHandle>, CronError> {
     match self {
@@ -31,6 +61,17 @@ impl CronHandler for CronHandlerImpl {
     }
   }
 
+  async fn execution_history(
+    &self,
+    name: &str,
+    limit: usize,
+  ) -> Result<Vec<CronExecution>, CronError> {
+    match self {
+      Self::Local(h) => h.execution_history(name, limit).await,
+      Self::Socket(h) => h.execution_history(name, limit).await,
+    }
+  }
+
   /// Reload happens when:
   /// - Local → Socket (upgrade)
   /// - Socket(addr1) → Socket(addr2) where addr1 != addr2
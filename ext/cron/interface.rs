@@ -5,6 +5,7 @@ use std::rc::Rc;
 use async_trait::async_trait;
 
 use crate::CronError;
+use crate::sqlite::CronExecution;
 
 pub type Traceparent = Option<String>;
 
@@ -12,11 +13,25 @@ pub type Traceparent = Option<String>;
 pub struct CronNextResult {
   pub active: bool,
   pub traceparent: Traceparent,
+  /// How many times the execution being dispatched has already failed and
+  /// been retried according to the backoff schedule.
+  pub retries: u32,
 }
 
+#[async_trait(?Send)]
 pub trait CronHandler {
   fn create(&self, spec: CronSpec) -> Result<Rc<dyn CronHandle>, CronError>;
 
+  /// The most recent recorded execution attempts of the cron `name`, newest
+  /// first. Handlers that don't record executions return none.
+  async fn execution_history(
+    &self,
+    _name: &str,
+    _limit: usize,
+  ) -> Result<Vec<CronExecution>, CronError> {
+    Ok(Vec::new())
+  }
+
   /// Check if the handler should be replaced based on current environment.
   /// Returns a fresh handler when a reload is needed, `None` otherwise.
   /// Called when a `MainWorker` hydrates an unconfigured runtime, since the
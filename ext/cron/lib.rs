@@ -5,6 +5,7 @@ mod handler_impl;
 mod interface;
 pub mod local;
 mod socket;
+pub mod sqlite;
 
 use std::borrow::Cow;
 use std::cell::RefCell;
@@ -30,6 +31,7 @@ deno_core::extension!(deno_cron,
   ops = [
     op_cron_create,
     op_cron_next,
+    op_cron_history,
   ],
   lazy_loaded_js = [ "01_cron.ts" ],
   options = {
@@ -88,6 +90,9 @@ pub enum CronError {
   #[class(generic)]
   #[error("Error registering cron: {0}")]
   RejectedError(String),
+  #[class(generic)]
+  #[error("Cron persistence error: {0}")]
+  Sqlite(#[from] rusqlite::Error),
   #[class(inherit)]
   #[error(transparent)]
   Other(JsErrorBox),
@@ -140,6 +145,7 @@ async fn op_cron_next(
           return Ok(CronNextResult {
             active: false,
             traceparent: None,
+            retries: 0,
           });
         } else {
           return Err(CronError::Resource(err));
@@ -152,6 +158,24 @@ async fn op_cron_next(
   cron_handle.next(prev_success).await
 }
 
+#[op2]
+#[serde]
+async fn op_cron_history(
+  state: Rc<RefCell<OpState>>,
+  #[string] name: String,
+  #[number] limit: usize,
+) -> Result<Vec<sqlite::CronExecution>, CronError> {
+  let cron_handler = {
+    let state = state.borrow();
+    state
+      .borrow::<Arc<FeatureChecker>>()
+      .check_or_exit(UNSTABLE_FEATURE_NAME, "Deno.cronHistory");
+    state.borrow::<Rc<dyn CronHandler>>().clone()
+  };
+
+  cron_handler.execution_history(&name, limit).await
+}
+
 fn validate_cron_name(name: &str) -> Result<(), CronError> {
   if name.len() > 64 {
     return Err(CronError::NameExceeded(name.len()));
@@ -4,6 +4,7 @@ use std::cell::OnceCell;
 use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::VecDeque;
 use std::env;
 use std::rc::Rc;
 use std::rc::Weak;
@@ -14,6 +15,8 @@ use deno_core::futures;
 use deno_core::futures::FutureExt;
 use deno_core::unsync::JoinHandle;
 use deno_core::unsync::spawn;
+use deno_core::unsync::spawn_blocking;
+use deno_error::JsErrorBox;
 use tokio::sync::OwnedSemaphorePermit;
 use tokio::sync::Semaphore;
 use tokio::sync::mpsc;
@@ -25,6 +28,9 @@ use crate::CronHandler;
 use crate::CronNextResult;
 use crate::CronSpec;
 use crate::cron::Schedule;
+use crate::sqlite::CatchUpPolicy;
+use crate::sqlite::CronExecution;
+use crate::sqlite::SqliteCronStore;
 
 const MAX_CRONS: usize = 100;
 const DISPATCH_CONCURRENCY_LIMIT: usize = 50;
@@ -42,12 +48,30 @@ pub struct LocalCronHandler {
 struct RuntimeState {
   crons: HashMap<String, Cron>,
   scheduled_deadlines: BTreeMap<u64, Vec<String>>,
+  persistence: Option<Persistence>,
+}
+
+struct Persistence {
+  store: SqliteCronStore,
+  catch_up: CatchUpPolicy,
 }
 
 struct Cron {
   spec: CronSpec,
   next_tx: mpsc::WeakSender<()>,
   current_execution_retries: u32,
+  /// Schedule times missed while the process was down that are still to be
+  /// run, oldest first.
+  catch_up: VecDeque<u64>,
+  /// The schedule time of the current execution, and whether it is a
+  /// catch-up of a missed one.
+  current_execution: Option<(u64, bool)>,
+  /// Whether the current execution was dispatched and hasn't reported its
+  /// result yet.
+  dispatched: bool,
+  /// Whether the cron was registered with the store, which happens when it
+  /// first asks for an execution.
+  registered: bool,
 }
 
 impl Cron {
@@ -58,6 +82,23 @@ impl Cron {
       .as_deref()
       .unwrap_or(&DEFAULT_BACKOFF_SCHEDULE)
   }
+
+  fn will_retry(&self, prev_success: bool) -> bool {
+    !prev_success
+      && self.current_execution_retries < self.backoff_schedule().len() as u32
+  }
+}
+
+/// Store work to do before scheduling the next execution of a cron.
+enum StoreWork {
+  Register {
+    cron_schedule: String,
+    catch_up: CatchUpPolicy,
+  },
+  Record {
+    execution: CronExecution,
+    is_final: bool,
+  },
 }
 
 impl Default for LocalCronHandler {
@@ -68,6 +109,20 @@ impl Default for LocalCronHandler {
 
 impl LocalCronHandler {
   pub fn new() -> Self {
+    Self::new_inner(None)
+  }
+
+  /// Creates a handler that records executions in `store`, and on
+  /// registration catches up on the ones that were missed while the process
+  /// wasn't running according to `catch_up`.
+  pub fn new_with_store(
+    store: SqliteCronStore,
+    catch_up: CatchUpPolicy,
+  ) -> Self {
+    Self::new_inner(Some(Persistence { store, catch_up }))
+  }
+
+  fn new_inner(persistence: Option<Persistence>) -> Self {
     Self {
       cron_schedule_tx: OnceCell::new(),
       concurrency_limiter: Arc::new(Semaphore::new(DISPATCH_CONCURRENCY_LIMIT)),
@@ -75,10 +130,97 @@ impl LocalCronHandler {
       runtime_state: Rc::new(RefCell::new(RuntimeState {
         crons: HashMap::new(),
         scheduled_deadlines: BTreeMap::new(),
+        persistence,
       })),
     }
   }
 
+  /// Does the store work due when the cron `name` asks for its next
+  /// execution: registering it on the first request, which loads the
+  /// executions it missed, and recording the attempt that just finished on
+  /// the later ones.
+  ///
+  /// Persistence is best effort: store errors are logged and never stop a
+  /// cron from running.
+  async fn persist(
+    runtime_state: &RefCell<RuntimeState>,
+    name: &str,
+    prev_success: bool,
+  ) {
+    let (store, work) = {
+      let mut runtime_state = runtime_state.borrow_mut();
+      let runtime_state = &mut *runtime_state;
+      let (Some(persistence), Some(cron)) = (
+        &runtime_state.persistence,
+        runtime_state.crons.get_mut(name),
+      ) else {
+        return;
+      };
+      let work = if !cron.registered {
+        cron.registered = true;
+        StoreWork::Register {
+          cron_schedule: cron.spec.cron_schedule.clone(),
+          catch_up: persistence.catch_up,
+        }
+      } else if cron.dispatched
+        && let Some((scheduled_at_ms, catch_up)) = cron.current_execution
+      {
+        StoreWork::Record {
+          execution: CronExecution {
+            name: name.to_string(),
+            scheduled_at_ms,
+            finished_at_ms: chrono::Utc::now().timestamp_millis() as u64,
+            success: prev_success,
+            catch_up,
+          },
+          is_final: !cron.will_retry(prev_success),
+        }
+      } else {
+        return;
+      };
+      (persistence.store.clone(), work)
+    };
+
+    match work {
+      StoreWork::Register {
+        cron_schedule,
+        catch_up,
+      } => {
+        let cron_name = name.to_string();
+        let result = run_blocking(move || {
+          store.register(
+            &cron_name,
+            &cron_schedule,
+            catch_up,
+            chrono::Utc::now(),
+          )
+        })
+        .await;
+        match result {
+          Ok(missed) => {
+            if let Some(cron) = runtime_state.borrow_mut().crons.get_mut(name) {
+              cron.catch_up = missed.into();
+            }
+          }
+          Err(err) => log::warn!(
+            "Failed to load the missed executions of cron '{name}': {err}"
+          ),
+        }
+      }
+      StoreWork::Record {
+        execution,
+        is_final,
+      } => {
+        let result =
+          run_blocking(move || store.record_execution(&execution, is_final))
+            .await;
+        if let Err(err) = result {
+          log::warn!("Failed to record an execution of cron '{name}': {err}");
+        }
+      }
+    }
+  }
+
   async fn cron_loop(
     runtime_state: Rc<RefCell<RuntimeState>>,
     mut cron_schedule_rx: mpsc::Receiver<(String, bool)>,
@@ -114,19 +256,25 @@ impl LocalCronHandler {
 
       // Schedule next execution of the cron if needed.
       if let Some((name, prev_success)) = cron_to_schedule {
+        Self::persist(&runtime_state, &name, prev_success).await;
         let mut runtime_state = runtime_state.borrow_mut();
+        let runtime_state = &mut *runtime_state;
         if let Some(cron) = runtime_state.crons.get_mut(&name) {
-          let backoff_schedule = cron.backoff_schedule();
-          let next_deadline = if !prev_success
-            && cron.current_execution_retries < backoff_schedule.len() as u32
-          {
+          let will_retry = cron.will_retry(prev_success);
+          let now = chrono::Utc::now().timestamp_millis() as u64;
+          cron.dispatched = false;
+          let next_deadline = if will_retry {
             let backoff_ms =
-              backoff_schedule[cron.current_execution_retries as usize];
-            let now = chrono::Utc::now().timestamp_millis() as u64;
+              cron.backoff_schedule()[cron.current_execution_retries as usize];
             cron.current_execution_retries += 1;
             now + backoff_ms as u64
+          } else if let Some(missed_ts) = cron.catch_up.pop_front() {
+            cron.current_execution = Some((missed_ts, true));
+            cron.current_execution_retries = 0;
+            now
           } else {
             let next_ts = compute_next_deadline(&cron.spec.cron_schedule)?;
+            cron.current_execution = Some((next_ts, false));
             cron.current_execution_retries = 0;
             next_ts
           };
@@ -176,10 +324,10 @@ impl RuntimeState {
             .collect::<Vec<_>>()
         })
         .filter_map(|(_, name)| {
-          self
-            .crons
-            .get(&name)
-            .map(|c| (name.clone(), c.next_tx.clone()))
+          self.crons.get_mut(&name).map(|c| {
+            c.dispatched = true;
+            (name.clone(), c.next_tx.clone())
+          })
         })
         .collect::<Vec<_>>()
     };
@@ -188,6 +336,7 @@ impl RuntimeState {
   }
 }
 
+#[async_trait(?Send)]
 impl CronHandler for LocalCronHandler {
   fn create(&self, spec: CronSpec) -> Result<Rc<dyn CronHandle>, CronError> {
     // Ensure that the cron loop is started.
@@ -228,6 +377,10 @@ impl CronHandler for LocalCronHandler {
       spec: spec.clone(),
       next_tx: next_tx.downgrade(),
       current_execution_retries: 0,
+      catch_up: VecDeque::new(),
+      current_execution: None,
+      dispatched: false,
+      registered: false,
     };
     runtime_state.crons.insert(spec.name.clone(), cron);
 
@@ -243,6 +396,20 @@ impl CronHandler for LocalCronHandler {
       }),
     }))
   }
+
+  async fn execution_history(
+    &self,
+    name: &str,
+    limit: usize,
+  ) -> Result<Vec<CronExecution>, CronError> {
+    let Some(store) = (self.runtime_state.borrow().persistence.as_ref())
+      .map(|persistence| persistence.store.clone())
+    else {
+      return Ok(Vec::new());
+    };
+    let name = name.to_string();
+    run_blocking(move || store.history(&name, limit)).await
+  }
 }
 
 impl Drop for LocalCronHandler {
@@ -284,6 +451,7 @@ impl CronHandle for CronExecutionHandle {
       return Ok(CronNextResult {
         active: false,
         traceparent: None,
+        retries: 0,
       });
     };
 
@@ -291,12 +459,14 @@ impl CronHandle for CronExecutionHandle {
       return Ok(CronNextResult {
         active: false,
         traceparent: None,
+        retries: 0,
       });
     };
     if next_rx.recv().await.is_none() {
       return Ok(CronNextResult {
         active: false,
         traceparent: None,
+        retries: 0,
       });
     };
 
@@ -304,9 +474,21 @@ impl CronHandle for CronExecutionHandle {
     let mut inner = self.inner.borrow_mut();
     inner.next_rx = Some(next_rx);
     inner.permit = Some(permit);
+    let retries = self
+      .runtime_state
+      .upgrade()
+      .and_then(|runtime_state| {
+        runtime_state
+          .borrow()
+          .crons
+          .get(&self.name)
+          .map(|cron| cron.current_execution_retries)
+      })
+      .unwrap_or(0);
     Ok(CronNextResult {
       active: true,
       traceparent: None,
+      retries,
     })
   }
 
@@ -321,6 +503,15 @@ impl CronHandle for CronExecutionHandle {
   }
 }
 
+/// Runs a blocking store operation on the blocking thread pool.
+async fn run_blocking<T: Send + 'static>(
+  f: impl FnOnce() -> Result<T, CronError> + Send + 'static,
+) -> Result<T, CronError> {
+  spawn_blocking(f)
+    .await
+    .map_err(|err| CronError::Other(JsErrorBox::generic(err.to_string())))?
+}
+
 fn compute_next_deadline(cron_expression: &str) -> Result<u64, CronError> {
   let now = chrono::Utc::now();
 
@@ -364,4 +555,48 @@ mod tests {
     assert!(compute_next_deadline("* * * * * *").is_err());
     assert!(compute_next_deadline("* * *").is_err());
   }
+
+  #[tokio::test]
+  async fn test_catch_up_missed_executions() {
+    let store = SqliteCronStore::open_in_memory().unwrap();
+    let since = chrono::Utc::now() - chrono::Duration::days(3);
+    store
+      .register("daily", "0 0 * * *", CatchUpPolicy::RunAll, since)
+      .unwrap();
+    let schedule = "0 0 * * *".parse::<Schedule>().unwrap();
+    let mut missed = Vec::new();
+    let mut cursor = since;
+    while let Some(next) = schedule.next_after(cursor)
+      && next <= chrono::Utc::now()
+    {
+      missed.push(next.timestamp_millis() as u64);
+      cursor = next;
+    }
+    assert_eq!(missed.len(), 3);
+
+    let handler =
+      LocalCronHandler::new_with_store(store.clone(), CatchUpPolicy::RunAll);
+    let handle = handler
+      .create(CronSpec {
+        name: "daily".to_string(),
+        cron_schedule: "0 0 * * *".to_string(),
+        backoff_schedule: None,
+      })
+      .unwrap();
+
+    // The missed executions are dispatched right away, oldest first, and each
+    // one is recorded before the next one is dispatched.
+    for recorded in 0..missed.len() {
+      assert!(handle.next(true).await.unwrap().active);
+      let history = handler.execution_history("daily", 10).await.unwrap();
+      assert_eq!(history.len(), recorded);
+      for (execution, scheduled_at_ms) in history.iter().rev().zip(&missed) {
+        assert_eq!(execution.scheduled_at_ms, *scheduled_at_ms);
+        assert!(execution.catch_up);
+        assert!(execution.success);
+      }
+    }
+
+    handle.close();
+  }
 }
@@ -221,6 +221,7 @@ impl CronHandle for SocketCronHandle {
       return Ok(CronNextResult {
         active: false,
         traceparent: None,
+        retries: 0,
       });
     }
 
@@ -242,12 +243,15 @@ impl CronHandle for SocketCronHandle {
       Some(traceparent) => Ok(CronNextResult {
         active: true,
         traceparent,
+        // The socket protocol doesn't report retries.
+        retries: 0,
       }),
       None => {
         self.closed.set(true);
         Ok(CronNextResult {
           active: false,
           traceparent: None,
+          retries: 0,
         })
       }
     };
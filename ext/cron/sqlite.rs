@@ -0,0 +1,387 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! SQLite persistence for [`crate::local::LocalCronHandler`].
+//!
+//! The store records, per cron, the last schedule time that ran to
+//! completion and the last one that succeeded, so that executions that were
+//! due while the process was down can be caught up on startup according to a
+//! [`CatchUpPolicy`]. Every execution attempt is also kept in a bounded
+//! history table for debugging.
+//!
+//! All methods block on SQLite, so async callers should run them with
+//! `spawn_blocking`.
+
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use chrono::DateTime;
+use chrono::Utc;
+use rusqlite::Connection;
+use rusqlite::OptionalExtension;
+use rusqlite::params;
+
+use crate::CronError;
+use crate::cron::Schedule;
+
+/// Upper bound on the executions caught up for a single cron with
+/// [`CatchUpPolicy::RunAll`], so a long outage doesn't flood the handler.
+const MAX_CATCH_UP_RUNS: usize = 100;
+/// Number of execution attempts kept per cron in the history table.
+const MAX_HISTORY_PER_CRON: u64 = 100;
+
+/// What to do on startup with executions that were due while the process
+/// wasn't running.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CatchUpPolicy {
+  /// Drop missed executions and wait for the next scheduled time.
+  Skip,
+  /// Run once for the most recent missed time, then resume the schedule.
+  #[default]
+  RunOnce,
+  /// Run once for every missed time, oldest first.
+  RunAll,
+}
+
+impl FromStr for CatchUpPolicy {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "skip" => Ok(Self::Skip),
+      "run-once" => Ok(Self::RunOnce),
+      "run-all" => Ok(Self::RunAll),
+      _ => Err(format!(
+        "Invalid cron catch-up policy '{s}': expected 'skip', 'run-once' or 'run-all'"
+      )),
+    }
+  }
+}
+
+/// A single execution attempt of a cron, as recorded in the history.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CronExecution {
+  pub name: String,
+  /// The schedule time this attempt ran for, in milliseconds since the
+  /// epoch. Retries of the same execution share it.
+  pub scheduled_at_ms: u64,
+  pub finished_at_ms: u64,
+  pub success: bool,
+  /// Whether this execution was missed while the process was down.
+  pub catch_up: bool,
+}
+
+/// A handle to the cron database. Clones share the same connection.
+#[derive(Clone)]
+pub struct SqliteCronStore {
+  conn: Arc<Mutex<Connection>>,
+}
+
+impl SqliteCronStore {
+  pub fn open(path: &Path) -> Result<Self, CronError> {
+    let conn = Connection::open(path)?;
+    conn.execute_batch(
+      "
+      PRAGMA journal_mode=WAL;
+      PRAGMA synchronous=NORMAL;
+      ",
+    )?;
+    Self::from_connection(conn)
+  }
+
+  pub fn open_in_memory() -> Result<Self, CronError> {
+    Self::from_connection(Connection::open_in_memory()?)
+  }
+
+  fn from_connection(conn: Connection) -> Result<Self, CronError> {
+    conn.execute_batch(
+      "
+      CREATE TABLE IF NOT EXISTS cron_state (
+        name TEXT PRIMARY KEY,
+        schedule TEXT NOT NULL,
+        last_completed_ms INTEGER NOT NULL,
+        last_success_ms INTEGER
+      );
+      CREATE TABLE IF NOT EXISTS cron_executions (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        name TEXT NOT NULL,
+        scheduled_ms INTEGER NOT NULL,
+        finished_ms INTEGER NOT NULL,
+        success INTEGER NOT NULL,
+        catch_up INTEGER NOT NULL
+      );
+      CREATE INDEX IF NOT EXISTS cron_executions_name
+        ON cron_executions (name, id);
+      ",
+    )?;
+    Ok(Self {
+      conn: Arc::new(Mutex::new(conn)),
+    })
+  }
+
+  /// Registers a cron and returns the schedule times it missed since its
+  /// last completed execution, filtered by `policy`.
+  ///
+  /// A cron seen for the first time, or whose schedule expression changed,
+  /// starts over from `now` and has nothing to catch up.
+  pub fn register(
+    &self,
+    name: &str,
+    cron_schedule: &str,
+    policy: CatchUpPolicy,
+    now: DateTime<Utc>,
+  ) -> Result<Vec<u64>, CronError> {
+    let conn = self.conn.lock().unwrap();
+    let last_completed_ms = conn
+      .query_row(
+        "SELECT last_completed_ms FROM cron_state WHERE name = ? AND schedule = ?",
+        params![name, cron_schedule],
+        |row| row.get::<_, u64>(0),
+      )
+      .optional()?;
+    let Some(last_completed_ms) = last_completed_ms else {
+      conn.execute(
+        "INSERT OR REPLACE INTO cron_state (name, schedule, last_completed_ms, last_success_ms)
+          VALUES (?, ?, ?, NULL)",
+        params![name, cron_schedule, now.timestamp_millis() as u64],
+      )?;
+      return Ok(Vec::new());
+    };
+
+    let schedule = cron_schedule
+      .parse::<Schedule>()
+      .map_err(|_| CronError::InvalidCron)?;
+    let Some(since) = DateTime::from_timestamp_millis(last_completed_ms as i64)
+    else {
+      return Ok(Vec::new());
+    };
+    Ok(missed_deadlines(&schedule, since, now, policy))
+  }
+
+  /// Records an execution attempt. A final attempt (one that succeeded, or
+  /// failed with no retries left) marks its schedule time as completed.
+  pub fn record_execution(
+    &self,
+    execution: &CronExecution,
+    is_final: bool,
+  ) -> Result<(), CronError> {
+    let conn = self.conn.lock().unwrap();
+    conn.execute(
+      "INSERT INTO cron_executions (name, scheduled_ms, finished_ms, success, catch_up)
+        VALUES (?, ?, ?, ?, ?)",
+      params![
+        execution.name,
+        execution.scheduled_at_ms,
+        execution.finished_at_ms,
+        execution.success,
+        execution.catch_up
+      ],
+    )?;
+    conn.execute(
+      "DELETE FROM cron_executions WHERE name = ? AND id <= (
+        SELECT id FROM cron_executions WHERE name = ?
+          ORDER BY id DESC LIMIT 1 OFFSET ?
+      )",
+      params![execution.name, execution.name, MAX_HISTORY_PER_CRON],
+    )?;
+    if is_final {
+      conn.execute(
+        "UPDATE cron_state SET last_completed_ms = max(last_completed_ms, ?1)
+          WHERE name = ?2",
+        params![execution.scheduled_at_ms, execution.name],
+      )?;
+    }
+    if execution.success {
+      conn.execute(
+        "UPDATE cron_state SET last_success_ms = ? WHERE name = ?",
+        params![execution.scheduled_at_ms, execution.name],
+      )?;
+    }
+    Ok(())
+  }
+
+  /// The schedule time of the last successful execution of a cron.
+  pub fn last_success(&self, name: &str) -> Result<Option<u64>, CronError> {
+    Ok(
+      self
+        .conn
+        .lock()
+        .unwrap()
+        .query_row(
+          "SELECT last_success_ms FROM cron_state WHERE name = ?",
+          params![name],
+          |row| row.get::<_, Option<u64>>(0),
+        )
+        .optional()?
+        .flatten(),
+    )
+  }
+
+  /// The most recent execution attempts of a cron, newest first.
+  pub fn history(
+    &self,
+    name: &str,
+    limit: usize,
+  ) -> Result<Vec<CronExecution>, CronError> {
+    let conn = self.conn.lock().unwrap();
+    let mut stmt = conn.prepare_cached(
+      "SELECT scheduled_ms, finished_ms, success, catch_up FROM cron_executions
+        WHERE name = ? ORDER BY id DESC LIMIT ?",
+    )?;
+    let rows = stmt.query_map(
+      params![name, limit.min(i64::MAX as usize) as i64],
+      |row| {
+        Ok(CronExecution {
+          name: name.to_string(),
+          scheduled_at_ms: row.get(0)?,
+          finished_at_ms: row.get(1)?,
+          success: row.get(2)?,
+          catch_up: row.get(3)?,
+        })
+      },
+    )?;
+    Ok(rows.collect::<Result<Vec<_>, _>>()?)
+  }
+}
+
+/// The schedule times strictly after `since` and no later than `now` that
+/// `policy` says to run, oldest first.
+fn missed_deadlines(
+  schedule: &Schedule,
+  since: DateTime<Utc>,
+  now: DateTime<Utc>,
+  policy: CatchUpPolicy,
+) -> Vec<u64> {
+  let keep = match policy {
+    CatchUpPolicy::Skip => return Vec::new(),
+    CatchUpPolicy::RunOnce => 1,
+    CatchUpPolicy::RunAll => MAX_CATCH_UP_RUNS,
+  };
+  let mut missed = Vec::new();
+  let mut cursor = since;
+  while let Some(next) = schedule.next_after(cursor) {
+    if next > now {
+      break;
+    }
+    missed.push(next.timestamp_millis() as u64);
+    cursor = next;
+  }
+  missed.split_off(missed.len().saturating_sub(keep))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn ms(date: &str) -> u64 {
+    date.parse::<DateTime<Utc>>().unwrap().timestamp_millis() as u64
+  }
+
+  #[test]
+  fn test_missed_deadlines() {
+    let schedule = "0 * * * *".parse::<Schedule>().unwrap();
+    let since = "2026-01-01T00:00:00Z".parse().unwrap();
+    let now = "2026-01-01T03:30:00Z".parse().unwrap();
+    assert_eq!(
+      missed_deadlines(&schedule, since, now, CatchUpPolicy::Skip),
+      Vec::<u64>::new()
+    );
+    assert_eq!(
+      missed_deadlines(&schedule, since, now, CatchUpPolicy::RunOnce),
+      vec![ms("2026-01-01T03:00:00Z")]
+    );
+    assert_eq!(
+      missed_deadlines(&schedule, since, now, CatchUpPolicy::RunAll),
+      vec![
+        ms("2026-01-01T01:00:00Z"),
+        ms("2026-01-01T02:00:00Z"),
+        ms("2026-01-01T03:00:00Z"),
+      ]
+    );
+
+    let schedule = "* * * * *".parse::<Schedule>().unwrap();
+    let now = "2026-01-02T00:00:00Z".parse().unwrap();
+    let missed = missed_deadlines(&schedule, since, now, CatchUpPolicy::RunAll);
+    assert_eq!(missed.len(), MAX_CATCH_UP_RUNS);
+    assert_eq!(*missed.last().unwrap(), ms("2026-01-02T00:00:00Z"));
+  }
+
+  #[test]
+  fn test_register_and_record() {
+    let store = SqliteCronStore::open_in_memory().unwrap();
+    let start = "2026-01-01T00:00:00Z".parse().unwrap();
+    let later = "2026-01-01T02:30:00Z".parse().unwrap();
+
+    // first registration has nothing to catch up
+    let missed = store
+      .register("hourly", "0 * * * *", CatchUpPolicy::RunAll, start)
+      .unwrap();
+    assert!(missed.is_empty());
+
+    let execution = CronExecution {
+      name: "hourly".to_string(),
+      scheduled_at_ms: ms("2026-01-01T01:00:00Z"),
+      finished_at_ms: ms("2026-01-01T01:00:01Z"),
+      success: false,
+      catch_up: false,
+    };
+    store.record_execution(&execution, false).unwrap();
+    assert_eq!(store.last_success("hourly").unwrap(), None);
+    // the failed attempt isn't final, so 01:00 still counts as missed
+    let missed = store
+      .register("hourly", "0 * * * *", CatchUpPolicy::RunAll, later)
+      .unwrap();
+    assert_eq!(
+      missed,
+      vec![ms("2026-01-01T01:00:00Z"), ms("2026-01-01T02:00:00Z")]
+    );
+
+    let retry = CronExecution {
+      success: true,
+      finished_at_ms: ms("2026-01-01T01:00:02Z"),
+      ..execution.clone()
+    };
+    store.record_execution(&retry, true).unwrap();
+    assert_eq!(
+      store.last_success("hourly").unwrap(),
+      Some(ms("2026-01-01T01:00:00Z"))
+    );
+    let missed = store
+      .register("hourly", "0 * * * *", CatchUpPolicy::RunAll, later)
+      .unwrap();
+    assert_eq!(missed, vec![ms("2026-01-01T02:00:00Z")]);
+
+    let history = store.history("hourly", 10).unwrap();
+    assert_eq!(history, vec![retry, execution]);
+
+    // a changed schedule starts over
+    let missed = store
+      .register("hourly", "30 * * * *", CatchUpPolicy::RunAll, later)
+      .unwrap();
+    assert!(missed.is_empty());
+  }
+
+  #[test]
+  fn test_history_is_bounded() {
+    let store = SqliteCronStore::open_in_memory().unwrap();
+    let now = Utc::now();
+    store
+      .register("c", "* * * * *", CatchUpPolicy::Skip, now)
+      .unwrap();
+    for i in 0..MAX_HISTORY_PER_CRON + 10 {
+      let execution = CronExecution {
+        name: "c".to_string(),
+        scheduled_at_ms: i,
+        finished_at_ms: i,
+        success: true,
+        catch_up: false,
+      };
+      store.record_execution(&execution, true).unwrap();
+    }
+    let history = store.history("c", usize::MAX).unwrap();
+    assert_eq!(history.len() as u64, MAX_HISTORY_PER_CRON);
+    assert_eq!(history[0].scheduled_at_ms, MAX_HISTORY_PER_CRON + 9);
+  }
+}
@@ -7,10 +7,12 @@ const {
 } = core;
 const {
   op_kv_atomic_write,
+  op_kv_backup,
   op_kv_database_open,
   op_kv_dequeue_next_message,
   op_kv_encode_cursor,
   op_kv_finish_dequeued_message,
+  op_kv_restore,
   op_kv_snapshot_read,
   op_kv_watch,
   op_kv_watch_next,
@@ -25,6 +27,7 @@ const {
   AsyncGeneratorPrototype,
   BigInt,
   BigIntPrototypeToString,
+  DateNow,
   Error,
   NumberIsInteger,
   NumberIsNaN,
@@ -32,6 +35,7 @@ const {
   ObjectFreeze,
   ObjectGetPrototypeOf,
   ObjectHasOwn,
+  ObjectKeys,
   ObjectPrototypeIsPrototypeOf,
   RangeError,
   SafeMap,
@@ -47,6 +51,15 @@ const {
 } = primordials;
 
 const { ReadableStream } = core.loadExtScript("ext:deno_web/06_streams.js");
+const { pathFromURL } = core.loadExtScript("ext:deno_web/00_infra.js");
+const {
+  otelState,
+  builtinMeter,
+  builtinTracer,
+} = core.loadExtScript("ext:deno_telemetry/telemetry.ts");
+const { updateSpanFromError } = core.loadExtScript(
+  "ext:deno_telemetry/util.ts",
+);
 
 const cloneableDeserializers = core.getCloneableDeserializers();
 
@@ -56,8 +69,20 @@ const encodeCursor: (
 ) => string = (selector, boundaryKey) =>
   op_kv_encode_cursor(selector, boundaryKey);
 
-async function openKv(path: string) {
-  const rid = await op_kv_database_open(path);
+async function openKv(path: string, options: Deno.KvOpenOptions = {}) {
+  const limits = options.limits;
+  if (limits !== undefined) {
+    const names = ObjectKeys(limits);
+    for (let i = 0; i < names.length; ++i) {
+      const value = limits[names[i]];
+      if (value !== undefined && (!NumberIsInteger(value) || value <= 0)) {
+        throw new TypeError(
+          `Limit "${names[i]}" must be a positive integer: received ${value}`,
+        );
+      }
+    }
+  }
+  const rid = await op_kv_database_open(path, limits);
   return new Kv(rid, kvSymbol);
 }
 
@@ -442,6 +467,14 @@ class Kv {
     });
   }
 
+  async backup(path: string | URL): Promise<void> {
+    await op_kv_backup(this.#rid, pathFromURL(path));
+  }
+
+  async restore(path: string | URL): Promise<void> {
+    await op_kv_restore(this.#rid, pathFromURL(path));
+  }
+
   close() {
     core.close(this.#rid);
     this.#isClosed = true;
@@ -962,11 +995,76 @@ class KvListIterator extends AsyncIterator
   }
 }
 
+let kvMetrics;
+
+function getKvMetrics() {
+  if (!kvMetrics) {
+    const meter = builtinMeter();
+    kvMetrics = {
+      duration: meter.createHistogram("deno.kv.atomic.duration", {
+        description: "Duration of Deno KV atomic operations.",
+        unit: "s",
+      }),
+      keys: meter.createCounter("deno.kv.atomic.keys", {
+        description:
+          "Number of keys checked and mutated by Deno KV atomic operations.",
+        unit: "{key}",
+      }),
+    };
+  }
+  return kvMetrics;
+}
+
 async function doAtomicWriteInPlace(
   rid: number,
   checks: [Deno.KvKey, string | null][],
   mutations: [Deno.KvKey, string, RawValue | null, number | undefined][],
   enqueues: [Uint8Array, number, Deno.KvKey[], number[] | null][],
+): Promise<string | null> {
+  if (!otelState.TRACING_ENABLED && !otelState.METRICS_ENABLED) {
+    return await atomicWrite(rid, checks, mutations, enqueues);
+  }
+
+  let span;
+  if (otelState.TRACING_ENABLED) {
+    span = builtinTracer().startSpan("deno.kv.atomic", { kind: 2 });
+    span.setAttribute("db.system.name", "deno.kv");
+    span.setAttribute("db.operation.name", "atomic");
+    span.setAttribute("deno.kv.checks", checks.length);
+    span.setAttribute("deno.kv.mutations", mutations.length);
+    span.setAttribute("deno.kv.enqueues", enqueues.length);
+  }
+  const start = DateNow();
+  // "committed", "check_failed" or "error"
+  let result = "error";
+  try {
+    const versionstamp = await atomicWrite(rid, checks, mutations, enqueues);
+    result = versionstamp === null ? "check_failed" : "committed";
+    if (span) {
+      span.setAttribute("deno.kv.result", result);
+      span.setStatus({ code: 1 });
+    }
+    return versionstamp;
+  } catch (error) {
+    if (span) updateSpanFromError(span, error);
+    throw error;
+  } finally {
+    span?.end();
+    if (otelState.METRICS_ENABLED) {
+      const metrics = getKvMetrics();
+      const attributes = { "deno.kv.result": result };
+      metrics.duration.record((DateNow() - start) / 1000, attributes);
+      metrics.keys.add(checks.length, { "deno.kv.operation": "check" });
+      metrics.keys.add(mutations.length, { "deno.kv.operation": "mutate" });
+    }
+  }
+}
+
+async function atomicWrite(
+  rid: number,
+  checks: [Deno.KvKey, string | null][],
+  mutations: [Deno.KvKey, string, RawValue | null, number | undefined][],
+  enqueues: [Uint8Array, number, Deno.KvKey[], number[] | null][],
 ): Promise<string | null> {
   for (let i = 0; i < mutations.length; ++i) {
     const mutation = mutations[i];
@@ -0,0 +1,161 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Point-in-time snapshots of databases stored in local SQLite files, for
+//! `Deno.Kv.prototype.backup()`, `Deno.Kv.prototype.restore()` and
+//! `deno kv backup` / `deno kv restore`.
+//!
+//! Like `backup()` in `node:sqlite`, these use SQLite's online backup API,
+//! so the database stays usable while they run. The whole database is
+//! copied in a single step, which reads it in one transaction: the copy is
+//! consistent no matter what other connections write meanwhile.
+//!
+//! Queued messages are part of the copy. When a backup is restored, the
+//! messages that were being delivered when it was taken are made ready
+//! again and delivered right away. The messages the live database was
+//! delivering are replaced with the rest of its data, so they aren't
+//! delivered again unless the backup has them too.
+//!
+//! Watches through handles in the restoring process report the restored
+//! values; other processes only see the changes on their next write.
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
+
+use rusqlite::Connection;
+use rusqlite::OpenFlags;
+use rusqlite::backup::Backup;
+use rusqlite::backup::StepResult;
+
+#[derive(Debug, thiserror::Error, deno_error::JsError)]
+pub enum KvBackupError {
+  #[class(type)]
+  #[error("Only databases stored in local files can be backed up or restored")]
+  NotLocal,
+  #[class(type)]
+  #[error("'{}' is not a Deno KV database", .0.display())]
+  NotKvDatabase(PathBuf),
+  #[class(generic)]
+  #[error(
+    "Timed out after {}s waiting for another connection to release the database",
+    LOCKED_TIMEOUT.as_secs()
+  )]
+  LockTimeout,
+  #[class(generic)]
+  #[error(transparent)]
+  Sqlite(#[from] rusqlite::Error),
+}
+
+/// How long to wait before retrying a step that another connection's lock
+/// blocked.
+const LOCKED_RETRY_DELAY: Duration = Duration::from_millis(10);
+
+/// How long to keep retrying steps that locks block before giving up.
+const LOCKED_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Copies the database at `source` to `target`, replacing `target` if it
+/// exists.
+pub fn backup(source: &Path, target: &Path) -> Result<(), KvBackupError> {
+  let source_conn = open_existing(source)?;
+  ensure_kv_database(&source_conn, source)?;
+  let mut target_conn = Connection::open(target)?;
+  copy(&source_conn, &mut target_conn)?;
+  Ok(())
+}
+
+/// Replaces the database at `target`, which may be in use, with the backup
+/// at `backup`.
+///
+/// The restored data version is moved past the live database's, so that
+/// writes after the restore get versionstamps the live database never
+/// handed out.
+pub fn restore(backup: &Path, target: &Path) -> Result<(), KvBackupError> {
+  let backup_conn = open_existing(backup)?;
+  ensure_kv_database(&backup_conn, backup)?;
+  let mut staged = Connection::open_in_memory()?;
+  copy(&backup_conn, &mut staged)?;
+  if has_table(&staged, "queue_running")? {
+    // nothing is delivering these in the restored database, so don't wait
+    // for their delivery deadline to expire
+    staged.execute_batch(
+      "INSERT INTO queue (ts, id, data, backoff_schedule, keys_if_undelivered)
+        SELECT 0, id, data, backoff_schedule, keys_if_undelivered
+        FROM queue_running;
+      DELETE FROM queue_running;",
+    )?;
+  }
+
+  let mut target_conn = Connection::open(target)?;
+  if is_kv_database(&target_conn)? {
+    let live_version: i64 = target_conn.query_row(
+      "SELECT version FROM data_version WHERE k = 0",
+      [],
+      |row| row.get(0),
+    )?;
+    staged.execute(
+      "UPDATE data_version SET version = max(version, ?1) WHERE k = 0",
+      [live_version],
+    )?;
+  }
+  copy(&staged, &mut target_conn)?;
+  Ok(())
+}
+
+fn open_existing(path: &Path) -> Result<Connection, rusqlite::Error> {
+  // read-write, as WAL databases can't always be opened read-only
+  Connection::open_with_flags(
+    path,
+    OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+  )
+}
+
+fn has_table(conn: &Connection, name: &str) -> Result<bool, rusqlite::Error> {
+  let tables: i64 = conn.query_row(
+    "SELECT count(*) FROM sqlite_master WHERE type = 'table' AND name = ?1",
+    [name],
+    |row| row.get(0),
+  )?;
+  Ok(tables == 1)
+}
+
+fn is_kv_database(conn: &Connection) -> Result<bool, rusqlite::Error> {
+  let tables: i64 = conn.query_row(
+    "SELECT count(*) FROM sqlite_master WHERE type = 'table' AND name IN ('kv', 'data_version')",
+    [],
+    |row| row.get(0),
+  )?;
+  Ok(tables == 2)
+}
+
+fn ensure_kv_database(
+  conn: &Connection,
+  path: &Path,
+) -> Result<(), KvBackupError> {
+  if !is_kv_database(conn)? {
+    return Err(KvBackupError::NotKvDatabase(path.to_path_buf()));
+  }
+  Ok(())
+}
+
+fn copy(
+  source: &Connection,
+  target: &mut Connection,
+) -> Result<(), KvBackupError> {
+  let backup = Backup::new(source, target)?;
+  let deadline = Instant::now() + LOCKED_TIMEOUT;
+  loop {
+    // Copying every page in one step keeps the source in a single read
+    // transaction, so concurrent writes can't restart or tear the copy.
+    match backup.step(-1)? {
+      StepResult::Done => return Ok(()),
+      StepResult::Busy | StepResult::Locked => {
+        if Instant::now() >= deadline {
+          return Err(KvBackupError::LockTimeout);
+        }
+        std::thread::sleep(LOCKED_RETRY_DELAY)
+      }
+      _ => {}
+    }
+  }
+}
@@ -1,5 +1,9 @@
 // Copyright 2018-2026 the Deno authors. MIT license.
 
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+
 #[derive(Clone, Copy, Debug)]
 pub struct KvConfig {
   pub max_write_key_size_bytes: usize,
@@ -135,3 +139,140 @@ impl KvConfigBuilder {
     }
   }
 }
+
+/// Overrides of the [`KvConfig`] limits, which users can set for databases
+/// stored locally from the `kv.limits` config file field and the `limits`
+/// option of `Deno.openKv()`. Remote databases always enforce the limits of
+/// the embedder's config, as the server enforces its own.
+#[derive(
+  Clone,
+  Copy,
+  Debug,
+  Default,
+  PartialEq,
+  Eq,
+  serde::Serialize,
+  serde::Deserialize,
+)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct KvLimits {
+  pub max_write_key_size_bytes: Option<usize>,
+  pub max_value_size_bytes: Option<usize>,
+  pub max_read_ranges: Option<usize>,
+  pub max_read_entries: Option<usize>,
+  pub max_checks: Option<usize>,
+  pub max_mutations: Option<usize>,
+  pub max_watched_keys: Option<usize>,
+  pub max_total_mutation_size_bytes: Option<usize>,
+  pub max_total_key_size_bytes: Option<usize>,
+}
+
+impl KvLimits {
+  pub fn is_empty(&self) -> bool {
+    *self == Self::default()
+  }
+
+  /// The name of the first limit set to 0, which no limit can be.
+  pub fn find_zero(&self) -> Option<&'static str> {
+    [
+      ("maxWriteKeySizeBytes", self.max_write_key_size_bytes),
+      ("maxValueSizeBytes", self.max_value_size_bytes),
+      ("maxReadRanges", self.max_read_ranges),
+      ("maxReadEntries", self.max_read_entries),
+      ("maxChecks", self.max_checks),
+      ("maxMutations", self.max_mutations),
+      ("maxWatchedKeys", self.max_watched_keys),
+      (
+        "maxTotalMutationSizeBytes",
+        self.max_total_mutation_size_bytes,
+      ),
+      ("maxTotalKeySizeBytes", self.max_total_key_size_bytes),
+    ]
+    .into_iter()
+    .find(|(_, value)| *value == Some(0))
+    .map(|(name, _)| name)
+  }
+
+  /// Returns these limits, falling back to `other` for the ones not set.
+  pub fn or(&self, other: &KvLimits) -> KvLimits {
+    KvLimits {
+      max_write_key_size_bytes: self
+        .max_write_key_size_bytes
+        .or(other.max_write_key_size_bytes),
+      max_value_size_bytes: self
+        .max_value_size_bytes
+        .or(other.max_value_size_bytes),
+      max_read_ranges: self.max_read_ranges.or(other.max_read_ranges),
+      max_read_entries: self.max_read_entries.or(other.max_read_entries),
+      max_checks: self.max_checks.or(other.max_checks),
+      max_mutations: self.max_mutations.or(other.max_mutations),
+      max_watched_keys: self.max_watched_keys.or(other.max_watched_keys),
+      max_total_mutation_size_bytes: self
+        .max_total_mutation_size_bytes
+        .or(other.max_total_mutation_size_bytes),
+      max_total_key_size_bytes: self
+        .max_total_key_size_bytes
+        .or(other.max_total_key_size_bytes),
+    }
+  }
+}
+
+/// The [`KvLimits`] the config file sets for databases stored locally.
+#[derive(
+  Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LocalKvLimits {
+  /// Limits for every local database.
+  pub default: KvLimits,
+  /// Limits for single database files, keyed by their canonicalized path.
+  /// They take precedence over `default`.
+  pub databases: BTreeMap<PathBuf, KvLimits>,
+}
+
+impl LocalKvLimits {
+  /// The limits for the database stored at `path`, or for an in-memory
+  /// database when there is no path.
+  pub fn resolve(&self, path: Option<&Path>) -> KvLimits {
+    match path.and_then(|path| self.databases.get(path)) {
+      Some(limits) => limits.or(&self.default),
+      None => self.default,
+    }
+  }
+}
+
+impl KvConfig {
+  /// Returns this config with the limits set in `limits` replaced.
+  pub fn with_limits(&self, limits: &KvLimits) -> KvConfig {
+    let max_write_key_size_bytes = limits
+      .max_write_key_size_bytes
+      .unwrap_or(self.max_write_key_size_bytes);
+    KvConfig {
+      max_write_key_size_bytes,
+      max_read_key_size_bytes: if limits.max_write_key_size_bytes.is_some() {
+        // range selectors can contain 0x00 or 0xff suffixes
+        max_write_key_size_bytes + 1
+      } else {
+        self.max_read_key_size_bytes
+      },
+      max_value_size_bytes: limits
+        .max_value_size_bytes
+        .unwrap_or(self.max_value_size_bytes),
+      max_read_ranges: limits.max_read_ranges.unwrap_or(self.max_read_ranges),
+      max_read_entries: limits
+        .max_read_entries
+        .unwrap_or(self.max_read_entries),
+      max_checks: limits.max_checks.unwrap_or(self.max_checks),
+      max_mutations: limits.max_mutations.unwrap_or(self.max_mutations),
+      max_watched_keys: limits
+        .max_watched_keys
+        .unwrap_or(self.max_watched_keys),
+      max_total_mutation_size_bytes: limits
+        .max_total_mutation_size_bytes
+        .unwrap_or(self.max_total_mutation_size_bytes),
+      max_total_key_size_bytes: limits
+        .max_total_key_size_bytes
+        .unwrap_or(self.max_total_key_size_bytes),
+    }
+  }
+}
@@ -1,5 +1,6 @@
 // Copyright 2018-2026 the Deno authors. MIT license.
 
+use std::any::Any;
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -16,6 +17,7 @@ use crate::DatabaseHandler;
 use crate::QueueMessageHandle;
 use crate::ReadRange;
 use crate::SnapshotReadOptions;
+use crate::sqlite::SqliteDb;
 use crate::sqlite::SqliteDbHandler;
 
 pub struct MultiBackendDbHandler {
@@ -149,11 +151,23 @@ pub trait DynamicDb {
   fn dyn_watch(&self, keys: Vec<Vec<u8>>) -> WatchStream;
 
   fn dyn_close(&self);
+
+  fn dyn_as_any(&self) -> &dyn Any;
 }
 
 #[derive(Clone)]
 pub struct RcDynamicDb(Rc<dyn DynamicDb>);
 
+impl RcDynamicDb {
+  /// The database as a local SQLite database, if it is one.
+  pub fn as_sqlite(&self) -> Option<&SqliteDb> {
+    let db = self.0.dyn_as_any();
+    // handlers that pick between backends wrap their backend's database
+    db.downcast_ref::<SqliteDb>()
+      .or_else(|| db.downcast_ref::<RcDynamicDb>()?.as_sqlite())
+  }
+}
+
 #[async_trait(?Send)]
 impl Database for RcDynamicDb {
   type QMH = Box<dyn QueueMessageHandle>;
@@ -191,7 +205,7 @@ impl Database for RcDynamicDb {
 #[async_trait(?Send)]
 impl<T, QMH> DynamicDb for T
 where
-  T: Database<QMH = QMH>,
+  T: Database<QMH = QMH> + 'static,
   QMH: QueueMessageHandle + 'static,
 {
   async fn dyn_snapshot_read(
@@ -227,4 +241,8 @@ where
   fn dyn_close(&self) {
     self.close()
   }
+
+  fn dyn_as_any(&self) -> &dyn Any {
+    self
+  }
 }
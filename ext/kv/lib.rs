@@ -1,5 +1,6 @@
 // Copyright 2018-2026 the Deno authors. MIT license.
 
+pub mod backup;
 pub mod config;
 pub mod dynamic;
 mod interface;
@@ -9,6 +10,7 @@ pub mod sqlite;
 use std::borrow::Cow;
 use std::cell::RefCell;
 use std::num::NonZeroU32;
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -32,9 +34,13 @@ use deno_core::futures::StreamExt;
 use deno_core::op2;
 use deno_core::serde_v8::AnyValue;
 use deno_core::serde_v8::BigInt;
+use deno_core::unsync::spawn_blocking;
 use deno_error::JsErrorBox;
 use deno_error::JsErrorClass;
 use deno_features::FeatureChecker;
+use deno_permissions::OpenAccessKind;
+use deno_permissions::PermissionCheckError;
+use deno_permissions::PermissionsContainer;
 use denokv_proto::AtomicWrite;
 use denokv_proto::Check;
 use denokv_proto::Consistency;
@@ -75,20 +81,25 @@ deno_core::extension!(deno_kv,
     op_kv_finish_dequeued_message,
     op_kv_watch,
     op_kv_watch_next,
+    op_kv_backup,
+    op_kv_restore,
   ],
   lazy_loaded_js = [ "01_db.ts" ],
   options = {
     handler: Box<dyn DynamicDbHandler>,
     config: KvConfig,
+    local_limits: LocalKvLimits,
   },
   state = |state, options| {
     state.put(Rc::new(options.config));
+    state.put(options.local_limits);
     state.put::<Rc<dyn DynamicDbHandler>>(Rc::from(options.handler));
   }
 );
 
 struct DatabaseResource {
   db: RcDynamicDb,
+  config: Rc<KvConfig>,
   cancel_handle: Rc<CancelHandle>,
 }
 
@@ -149,6 +160,9 @@ pub enum KvErrorKind {
   #[error("limit must be greater than 0")]
   InvalidLimit,
   #[class(type)]
+  #[error("Limits can only be overridden for databases stored locally")]
+  RemoteLimits,
+  #[class(type)]
   #[error("Invalid boundary key")]
   InvalidBoundaryKey,
   #[class(type)]
@@ -208,6 +222,12 @@ pub enum KvErrorKind {
   #[class(type)]
   #[error("Invalid range")]
   InvalidRange,
+  #[class(inherit)]
+  #[error(transparent)]
+  Backup(#[from] backup::KvBackupError),
+  #[class(inherit)]
+  #[error(transparent)]
+  Permission(#[from] PermissionCheckError),
 }
 
 #[op2(stack_trace)]
@@ -215,7 +235,9 @@ pub enum KvErrorKind {
 async fn op_kv_database_open(
   state: Rc<RefCell<OpState>>,
   #[string] path: Option<String>,
+  #[serde] limits: Option<KvLimits>,
 ) -> Result<ResourceId, KvError> {
+  let limits = limits.unwrap_or_default();
   let handler = {
     let state = state.borrow();
     state
@@ -227,13 +249,97 @@ async fn op_kv_database_open(
     .dyn_open(state.clone(), path)
     .await
     .map_err(KvErrorKind::DatabaseHandler)?;
+  let config = {
+    let state = state.borrow();
+    let config = state.borrow::<Rc<KvConfig>>().clone();
+    if let Some(sqlite) = db.as_sqlite() {
+      let local_limits = state.borrow::<LocalKvLimits>().resolve(sqlite.path());
+      Rc::new(config.with_limits(&limits.or(&local_limits)))
+    } else if limits.is_empty() {
+      config
+    } else {
+      return Err(KvErrorKind::RemoteLimits.into_box());
+    }
+  };
   let rid = state.borrow_mut().resource_table.add(DatabaseResource {
     db,
+    config,
     cancel_handle: CancelHandle::new_rc(),
   });
   Ok(rid)
 }
 
+/// Resolves the file of the local database `rid` and the other path of a
+/// backup or restore, checked with `access_kind`.
+fn backup_paths(
+  state: &RefCell<OpState>,
+  rid: ResourceId,
+  path: String,
+  access_kind: OpenAccessKind,
+  api_name: &str,
+) -> Result<(PathBuf, PathBuf), KvError> {
+  let state = state.borrow();
+  let resource = state
+    .resource_table
+    .get::<DatabaseResource>(rid)
+    .map_err(KvErrorKind::Resource)?;
+  let db_path = resource
+    .db
+    .as_sqlite()
+    .and_then(|db| db.path())
+    .ok_or(backup::KvBackupError::NotLocal)
+    .map_err(KvErrorKind::Backup)?
+    .to_path_buf();
+  let path = state
+    .borrow::<PermissionsContainer>()
+    .check_open(Cow::Owned(PathBuf::from(path)), access_kind, Some(api_name))
+    .map_err(KvErrorKind::Permission)?
+    .into_owned_path();
+  Ok((db_path, path))
+}
+
+#[op2(stack_trace)]
+async fn op_kv_backup(
+  state: Rc<RefCell<OpState>>,
+  #[smi] rid: ResourceId,
+  #[string] path: String,
+) -> Result<(), KvError> {
+  let (db_path, target) = backup_paths(
+    &state,
+    rid,
+    path,
+    OpenAccessKind::Write,
+    "Deno.Kv.prototype.backup",
+  )?;
+  spawn_blocking(move || backup::backup(&db_path, &target))
+    .await
+    .unwrap()
+    .map_err(KvErrorKind::Backup)?;
+  Ok(())
+}
+
+#[op2(stack_trace)]
+async fn op_kv_restore(
+  state: Rc<RefCell<OpState>>,
+  #[smi] rid: ResourceId,
+  #[string] path: String,
+) -> Result<(), KvError> {
+  let (db_path, source) = backup_paths(
+    &state,
+    rid,
+    path,
+    OpenAccessKind::Read,
+    "Deno.Kv.prototype.restore",
+  )?;
+  let restored_path = db_path.clone();
+  spawn_blocking(move || backup::restore(&source, &db_path))
+    .await
+    .unwrap()
+    .map_err(KvErrorKind::Backup)?;
+  sqlite::notify_restored(&restored_path);
+  Ok(())
+}
+
 type KvKey = Vec<AnyValue>;
 
 fn key_part_from_v8(value: AnyValue) -> KeyPart {
@@ -354,18 +460,13 @@ async fn op_kv_snapshot_read(
   #[serde] ranges: Vec<SnapshotReadRange>,
   #[serde] consistency: V8Consistency,
 ) -> Result<Vec<Vec<ToV8KvEntry>>, KvError> {
-  let db = {
+  let (db, config) = {
     let state = state.borrow();
     let resource = state
       .resource_table
       .get::<DatabaseResource>(rid)
       .map_err(KvErrorKind::Resource)?;
-    resource.db.clone()
-  };
-
-  let config = {
-    let state = state.borrow();
-    state.borrow::<Rc<KvConfig>>().clone()
+    (resource.db.clone(), resource.config.clone())
   };
 
   if ranges.len() > config.max_read_ranges {
@@ -473,7 +574,7 @@ fn op_kv_watch(
     .resource_table
     .get::<DatabaseResource>(rid)
     .map_err(KvErrorKind::Resource)?;
-  let config = state.borrow::<Rc<KvConfig>>().clone();
+  let config = resource.config.clone();
 
   if keys.len() > config.max_watched_keys {
     return Err(KvErrorKind::TooManyKeys(config.max_watched_keys).into_box());
@@ -895,18 +996,13 @@ async fn op_kv_atomic_write(
   #[serde] enqueues: Vec<V8Enqueue>,
 ) -> Result<Option<String>, KvError> {
   let current_timestamp = chrono::Utc::now();
-  let db = {
+  let (db, config) = {
     let state = state.borrow();
     let resource = state
       .resource_table
       .get::<DatabaseResource>(rid)
       .map_err(KvErrorKind::Resource)?;
-    resource.db.clone()
-  };
-
-  let config = {
-    let state = state.borrow();
-    state.borrow::<Rc<KvConfig>>().clone()
+    (resource.db.clone(), resource.config.clone())
   };
 
   if checks.len() > config.max_checks {
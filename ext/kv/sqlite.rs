@@ -3,6 +3,7 @@
 use std::borrow::Cow;
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::path::Path;
 use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::Arc;
@@ -11,21 +12,46 @@ use std::sync::OnceLock;
 
 use async_trait::async_trait;
 use deno_core::OpState;
+use deno_core::futures::StreamExt;
+use deno_core::futures::stream;
 use deno_core::unsync::spawn_blocking;
 use deno_error::JsErrorBox;
 use deno_permissions::OpenAccessKind;
 use deno_permissions::PermissionsContainer;
+use denokv_proto::AtomicWrite;
+use denokv_proto::CommitResult;
+use denokv_proto::Database;
+use denokv_proto::ReadRange;
+use denokv_proto::ReadRangeOutput;
+use denokv_proto::SnapshotReadOptions;
+use denokv_proto::WatchStream;
 pub use denokv_sqlite::SqliteBackendError;
 use denokv_sqlite::SqliteConfig;
 use denokv_sqlite::SqliteNotifier;
 use rand::SeedableRng;
 use rusqlite::OpenFlags;
+use tokio::sync::watch;
 
 use crate::DatabaseHandler;
 
 static SQLITE_NOTIFIERS_MAP: OnceLock<Mutex<HashMap<PathBuf, SqliteNotifier>>> =
   OnceLock::new();
 
+/// Signalled after a database file is restored from a backup, to restart
+/// the watches on it.
+static SQLITE_RESTORES_MAP: OnceLock<
+  Mutex<HashMap<PathBuf, Arc<watch::Sender<()>>>>,
+> = OnceLock::new();
+
+/// Lets the watches of every handle to the database at `path` in this
+/// process know that it was restored from a backup.
+pub fn notify_restored(path: &Path) {
+  let restores = SQLITE_RESTORES_MAP.get_or_init(Default::default);
+  if let Some(sender) = restores.lock().unwrap().get(path) {
+    sender.send_replace(());
+  }
+}
+
 pub struct SqliteDbHandler {
   pub default_storage_dir: Option<PathBuf>,
   versionstamp_rng_seed: Option<u64>,
@@ -57,7 +83,7 @@ enum Mode {
 
 #[async_trait(?Send)]
 impl DatabaseHandler for SqliteDbHandler {
-  type DB = denokv_sqlite::Sqlite;
+  type DB = SqliteDb;
 
   async fn open(
     &self,
@@ -172,6 +198,16 @@ impl DatabaseHandler for SqliteDbHandler {
     .unwrap()
     .map_err(JsErrorBox::from_err)?;
 
+    let path = notifier_key.clone();
+    let restores = path.as_ref().map(|path| {
+      SQLITE_RESTORES_MAP
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap()
+        .entry(path.clone())
+        .or_insert_with(|| Arc::new(watch::Sender::new(())))
+        .clone()
+    });
     let notifier = if let Some(notifier_key) = notifier_key {
       SQLITE_NOTIFIERS_MAP
         .get_or_init(Default::default)
@@ -191,7 +227,7 @@ impl DatabaseHandler for SqliteDbHandler {
       num_workers: 1,
     };
 
-    denokv_sqlite::Sqlite::new(
+    let db = denokv_sqlite::Sqlite::new(
       move || {
         let conn =
           conn_gen().map_err(|e| JsErrorBox::generic(e.to_string()))?;
@@ -209,6 +245,82 @@ impl DatabaseHandler for SqliteDbHandler {
       notifier,
       config,
     )
-    .map_err(|e| JsErrorBox::generic(e.to_string()))
+    .map_err(|e| JsErrorBox::generic(e.to_string()))?;
+    Ok(SqliteDb {
+      db: Rc::new(db),
+      path,
+      restores,
+    })
+  }
+}
+
+/// A database opened by [`SqliteDbHandler`], which remembers the file it is
+/// stored in so it can be backed up and restored.
+pub struct SqliteDb {
+  db: Rc<denokv_sqlite::Sqlite>,
+  path: Option<PathBuf>,
+  restores: Option<Arc<watch::Sender<()>>>,
+}
+
+impl SqliteDb {
+  /// The database file, or `None` for an in-memory database.
+  pub fn path(&self) -> Option<&Path> {
+    self.path.as_deref()
+  }
+}
+
+#[async_trait(?Send)]
+impl Database for SqliteDb {
+  type QMH = <denokv_sqlite::Sqlite as Database>::QMH;
+
+  async fn snapshot_read(
+    &self,
+    requests: Vec<ReadRange>,
+    options: SnapshotReadOptions,
+  ) -> Result<Vec<ReadRangeOutput>, JsErrorBox> {
+    self.db.snapshot_read(requests, options).await
+  }
+
+  async fn atomic_write(
+    &self,
+    write: AtomicWrite,
+  ) -> Result<Option<CommitResult>, JsErrorBox> {
+    self.db.atomic_write(write).await
+  }
+
+  async fn dequeue_next_message(
+    &self,
+  ) -> Result<Option<Self::QMH>, JsErrorBox> {
+    self.db.dequeue_next_message().await
+  }
+
+  fn watch(&self, keys: Vec<Vec<u8>>) -> WatchStream {
+    let Some(restores) = &self.restores else {
+      return self.db.watch(keys);
+    };
+    // A restore replaces the file under the SQLite notifier, which only
+    // hears about writes made through it. Starting over from the current
+    // values reports what the restore changed.
+    let watch = self.db.watch(keys.clone());
+    let state = (self.db.clone(), keys, restores.subscribe(), watch);
+    stream::unfold(state, |(db, keys, mut restored, mut watch)| async move {
+      loop {
+        let next = tokio::select! {
+          next = watch.next() => Some(next),
+          Ok(()) = restored.changed() => None,
+        };
+        match next {
+          Some(next) => {
+            return next.map(|next| (next, (db, keys, restored, watch)));
+          }
+          None => watch = db.watch(keys.clone()),
+        }
+      }
+    })
+    .boxed_local()
+  }
+
+  fn close(&self) {
+    self.db.close()
   }
 }
@@ -0,0 +1,305 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Writes telemetry to local files as OTLP/JSON, selected with
+//! `OTEL_EXPORTER_OTLP_PROTOCOL=file`, for machines that can't reach a
+//! collector. Each line of a file is one OTLP export request, the format the
+//! OpenTelemetry Collector's `otlpjsonfile` receiver reads, so the files can
+//! be shipped later and loaded into Jaeger or any other backend.
+//!
+//! Traces, metrics and logs go to `traces.jsonl`, `metrics.jsonl` and
+//! `logs.jsonl` in `OTEL_DENO_FILE_EXPORTER_DIR`. Once a file would grow
+//! past `OTEL_DENO_FILE_EXPORTER_MAX_BYTES`, it is renamed to
+//! `traces.1.jsonl` (moving older files to `traces.2.jsonl` and so on) and
+//! a new one is started. Only the `OTEL_DENO_FILE_EXPORTER_MAX_FILES` most
+//! recent rotated files are kept.
+
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use deno_core::futures::future::BoxFuture;
+use opentelemetry_proto::tonic::collector::logs::v1::ExportLogsServiceRequest;
+use opentelemetry_proto::tonic::collector::metrics::v1::ExportMetricsServiceRequest;
+use opentelemetry_proto::tonic::collector::trace::v1::ExportTraceServiceRequest;
+use opentelemetry_proto::transform::common::tonic::ResourceAttributesWithSchema;
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::export::logs::LogBatch;
+use opentelemetry_sdk::export::trace::SpanData;
+use opentelemetry_sdk::metrics::Temporality;
+use opentelemetry_sdk::metrics::data::ResourceMetrics;
+use serde::Serialize;
+use sys_traits::EnvVar;
+
+const DEFAULT_DIR: &str = "otel";
+const DEFAULT_MAX_BYTES: u64 = 10 * 1024 * 1024;
+const DEFAULT_MAX_FILES: usize = 5;
+
+#[derive(Debug, Clone)]
+pub struct FileExporterConfig {
+  pub dir: PathBuf,
+  pub max_bytes: u64,
+  pub max_files: usize,
+}
+
+impl FileExporterConfig {
+  pub fn from_env(sys: &impl EnvVar) -> deno_core::anyhow::Result<Self> {
+    fn parse_var<T: std::str::FromStr>(
+      sys: &impl EnvVar,
+      name: &str,
+      default: T,
+    ) -> deno_core::anyhow::Result<T> {
+      match sys.env_var(name) {
+        Ok(value) if !value.is_empty() => value.parse().map_err(|_| {
+          deno_core::anyhow::anyhow!(
+            "Invalid value for {}: expected a non-negative integer, got {}",
+            name,
+            value
+          )
+        }),
+        _ => Ok(default),
+      }
+    }
+
+    Ok(Self {
+      dir: sys
+        .env_var_path("OTEL_DENO_FILE_EXPORTER_DIR")
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_DIR)),
+      max_bytes: parse_var(
+        sys,
+        "OTEL_DENO_FILE_EXPORTER_MAX_BYTES",
+        DEFAULT_MAX_BYTES,
+      )?,
+      max_files: parse_var(
+        sys,
+        "OTEL_DENO_FILE_EXPORTER_MAX_FILES",
+        DEFAULT_MAX_FILES,
+      )?,
+    })
+  }
+}
+
+/// An append-only JSON lines file that is rotated once it reaches the
+/// configured size.
+#[derive(Debug)]
+struct RotatingFile {
+  config: FileExporterConfig,
+  name: &'static str,
+  file: Option<File>,
+  size: u64,
+}
+
+impl RotatingFile {
+  fn new(config: FileExporterConfig, name: &'static str) -> Self {
+    Self {
+      config,
+      name,
+      file: None,
+      size: 0,
+    }
+  }
+
+  fn path(&self, generation: usize) -> PathBuf {
+    if generation == 0 {
+      self.config.dir.join(format!("{}.jsonl", self.name))
+    } else {
+      self
+        .config
+        .dir
+        .join(format!("{}.{}.jsonl", self.name, generation))
+    }
+  }
+
+  fn open(&mut self) -> std::io::Result<&mut File> {
+    if self.file.is_none() {
+      std::fs::create_dir_all(&self.config.dir)?;
+      let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(self.path(0))?;
+      self.size = file.metadata()?.len();
+      self.file = Some(file);
+    }
+    Ok(self.file.as_mut().unwrap())
+  }
+
+  fn rotate(&mut self) -> std::io::Result<()> {
+    self.file = None;
+    if self.config.max_files == 0 {
+      return std::fs::remove_file(self.path(0));
+    }
+    for generation in (1..self.config.max_files).rev() {
+      match std::fs::rename(self.path(generation), self.path(generation + 1)) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => {
+          return Err(err);
+        }
+        _ => {}
+      }
+    }
+    std::fs::rename(self.path(0), self.path(1))
+  }
+
+  fn write_json(&mut self, value: &impl Serialize) -> std::io::Result<()> {
+    let mut line = serde_json::to_vec(value)?;
+    line.push(b'\n');
+    self.open()?;
+    // A line is never split across files, so a line larger than the limit
+    // gets a file of its own.
+    if self.size > 0 && self.size + line.len() as u64 > self.config.max_bytes {
+      self.rotate()?;
+    }
+    let file = self.open()?;
+    file.write_all(&line)?;
+    file.flush()?;
+    self.size += line.len() as u64;
+    Ok(())
+  }
+}
+
+fn shared_file(
+  config: &FileExporterConfig,
+  name: &'static str,
+) -> Arc<Mutex<RotatingFile>> {
+  Arc::new(Mutex::new(RotatingFile::new(config.clone(), name)))
+}
+
+// ---- Span Exporter ----
+
+#[derive(Debug)]
+pub struct FileSpanExporter {
+  file: Arc<Mutex<RotatingFile>>,
+  resource: Resource,
+}
+
+impl FileSpanExporter {
+  pub fn new(config: &FileExporterConfig) -> Self {
+    Self {
+      file: shared_file(config, "traces"),
+      resource: Resource::default(),
+    }
+  }
+}
+
+impl opentelemetry_sdk::export::trace::SpanExporter for FileSpanExporter {
+  fn export(
+    &mut self,
+    batch: Vec<SpanData>,
+  ) -> BoxFuture<'static, opentelemetry_sdk::export::trace::ExportResult> {
+    let resource: ResourceAttributesWithSchema = (&self.resource).into();
+    let file = self.file.clone();
+    Box::pin(async move {
+      let resource_spans =
+        opentelemetry_proto::transform::trace::tonic::group_spans_by_resource_and_scope(
+          batch, &resource,
+        );
+      let request = ExportTraceServiceRequest { resource_spans };
+      file
+        .lock()
+        .unwrap()
+        .write_json(&request)
+        .map_err(|err| opentelemetry::trace::TraceError::Other(err.into()))
+    })
+  }
+
+  fn shutdown(&mut self) {}
+
+  fn set_resource(&mut self, resource: &Resource) {
+    self.resource = resource.clone();
+  }
+}
+
+// ---- Log Exporter ----
+
+#[derive(Debug)]
+pub struct FileLogExporter {
+  file: Arc<Mutex<RotatingFile>>,
+  resource: Resource,
+}
+
+impl FileLogExporter {
+  pub fn new(config: &FileExporterConfig) -> Self {
+    Self {
+      file: shared_file(config, "logs"),
+      resource: Resource::default(),
+    }
+  }
+}
+
+#[async_trait]
+impl opentelemetry_sdk::export::logs::LogExporter for FileLogExporter {
+  async fn export(
+    &mut self,
+    batch: LogBatch<'_>,
+  ) -> opentelemetry_sdk::logs::LogResult<()> {
+    let resource: ResourceAttributesWithSchema = (&self.resource).into();
+    let resource_logs =
+      opentelemetry_proto::transform::logs::tonic::group_logs_by_resource_and_scope(
+        batch, &resource,
+      );
+    let request = ExportLogsServiceRequest { resource_logs };
+    self
+      .file
+      .lock()
+      .unwrap()
+      .write_json(&request)
+      .map_err(|err| opentelemetry_sdk::logs::LogError::Other(err.into()))
+  }
+
+  fn shutdown(&mut self) {}
+
+  fn set_resource(&mut self, resource: &Resource) {
+    self.resource = resource.clone();
+  }
+}
+
+// ---- Metric Exporter ----
+
+#[derive(Debug)]
+pub struct FileMetricExporter {
+  file: Arc<Mutex<RotatingFile>>,
+  temporality: Temporality,
+}
+
+impl FileMetricExporter {
+  pub fn new(config: &FileExporterConfig, temporality: Temporality) -> Self {
+    Self {
+      file: shared_file(config, "metrics"),
+      temporality,
+    }
+  }
+}
+
+#[async_trait]
+impl opentelemetry_sdk::metrics::exporter::PushMetricExporter
+  for FileMetricExporter
+{
+  async fn export(
+    &self,
+    metrics: &mut ResourceMetrics,
+  ) -> opentelemetry_sdk::metrics::MetricResult<()> {
+    let request = ExportMetricsServiceRequest::from(&*metrics);
+    self
+      .file
+      .lock()
+      .unwrap()
+      .write_json(&request)
+      .map_err(|err| {
+        opentelemetry_sdk::metrics::MetricError::Other(err.to_string())
+      })
+  }
+
+  async fn force_flush(&self) -> opentelemetry_sdk::metrics::MetricResult<()> {
+    Ok(())
+  }
+
+  fn shutdown(&self) -> opentelemetry_sdk::metrics::MetricResult<()> {
+    Ok(())
+  }
+
+  fn temporality(&self) -> Temporality {
+    self.temporality
+  }
+}
@@ -104,7 +104,14 @@ use tokio::sync::oneshot;
 use tokio::task::JoinSet;
 
 mod console_exporter;
+mod file_exporter;
 mod grpc_exporter;
+mod sampler;
+
+pub use sampler::Sampler;
+pub use sampler::SamplerConfig;
+use sampler::SamplingDecision;
+pub use sampler::SamplingRule;
 
 deno_core::extension!(
   deno_telemetry,
@@ -952,7 +959,7 @@ pub struct OtelGlobals {
   pub builtin_instrumentation_scope: InstrumentationScope,
   pub span_event_count_limit: usize,
   pub span_attribute_count_limit: usize,
-  pub sampler: Sampler,
+  pub sampler: std::sync::RwLock<Sampler>,
   pub config: OtelConfig,
 }
 
@@ -1025,131 +1032,6 @@ fn attribute_count_limit() -> usize {
     .unwrap_or(DEFAULT_ATTRIBUTE_COUNT_LIMIT)
 }
 
-/// Head-based trace sampler configured via the `OTEL_TRACES_SAMPLER` and
-/// `OTEL_TRACES_SAMPLER_ARG` environment variables.
-///
-/// See <https://opentelemetry.io/docs/specs/otel/configuration/sdk-environment-variables/#general-sdk-configuration>
-#[derive(Debug, Clone, Copy)]
-pub struct Sampler {
-  /// When set, the sampling decision of a valid parent span takes precedence
-  /// over `root` (the `parentbased_*` variants).
-  parent_based: bool,
-  /// The sampler consulted when there is no parent (or `parent_based` is
-  /// false).
-  root: RootSampler,
-}
-
-#[derive(Debug, Clone, Copy)]
-enum RootSampler {
-  AlwaysOn,
-  AlwaysOff,
-  TraceIdRatio(f64),
-}
-
-impl Default for Sampler {
-  fn default() -> Self {
-    // When `OTEL_TRACES_SAMPLER` is unset, preserve Deno's historical behavior
-    // of recording and sampling every span.
-    Sampler {
-      parent_based: false,
-      root: RootSampler::AlwaysOn,
-    }
-  }
-}
-
-impl Sampler {
-  fn from_env(
-    sys: &impl TelemetrySys,
-  ) -> Result<Self, deno_core::anyhow::Error> {
-    let Ok(value) = sys.env_var("OTEL_TRACES_SAMPLER") else {
-      return Ok(Self::default());
-    };
-    let value = value.trim();
-    if value.is_empty() {
-      return Ok(Self::default());
-    }
-    // `OTEL_TRACES_SAMPLER_ARG` is the sampling probability for the
-    // `traceidratio` samplers, in the range [0, 1]. It defaults to 1.0 and is
-    // ignored by the other samplers.
-    let ratio = || -> f64 {
-      sys
-        .env_var("OTEL_TRACES_SAMPLER_ARG")
-        .ok()
-        .and_then(|s| s.trim().parse::<f64>().ok())
-        .unwrap_or(1.0)
-        .clamp(0.0, 1.0)
-    };
-    let sampler = match value {
-      "always_on" => Sampler {
-        parent_based: false,
-        root: RootSampler::AlwaysOn,
-      },
-      "always_off" => Sampler {
-        parent_based: false,
-        root: RootSampler::AlwaysOff,
-      },
-      "traceidratio" => Sampler {
-        parent_based: false,
-        root: RootSampler::TraceIdRatio(ratio()),
-      },
-      "parentbased_always_on" => Sampler {
-        parent_based: true,
-        root: RootSampler::AlwaysOn,
-      },
-      "parentbased_always_off" => Sampler {
-        parent_based: true,
-        root: RootSampler::AlwaysOff,
-      },
-      "parentbased_traceidratio" => Sampler {
-        parent_based: true,
-        root: RootSampler::TraceIdRatio(ratio()),
-      },
-      other => {
-        return Err(deno_core::anyhow::anyhow!(
-          "Env var OTEL_TRACES_SAMPLER specifies an unsupported sampler: {}",
-          other
-        ));
-      }
-    };
-    Ok(sampler)
-  }
-
-  /// Returns whether a span with the given `trace_id` should be sampled
-  /// (recorded and exported), given its `parent` span context if any.
-  fn should_sample(
-    &self,
-    parent: Option<&SpanContext>,
-    trace_id: TraceId,
-  ) -> bool {
-    let parent_decision = parent.and_then(|parent| {
-      (self.parent_based && parent.is_valid()).then(|| parent.is_sampled())
-    });
-    if let Some(sampled) = parent_decision {
-      return sampled;
-    }
-    match self.root {
-      RootSampler::AlwaysOn => true,
-      RootSampler::AlwaysOff => false,
-      RootSampler::TraceIdRatio(ratio) => {
-        if ratio >= 1.0 {
-          return true;
-        }
-        if ratio <= 0.0 {
-          return false;
-        }
-        // Matches the opentelemetry-rust `TraceIdRatioBased` sampler: derive a
-        // deterministic value in [0, 2^63) from the lower 64 bits of the trace
-        // id and keep the span when it falls below the probability bound.
-        let bytes = trace_id.to_bytes();
-        let low = u64::from_be_bytes(bytes[8..16].try_into().unwrap());
-        let value = low >> 1;
-        let bound = (ratio * (1u64 << 63) as f64) as u64;
-        value < bound
-      }
-    }
-  }
-}
-
 pub static OTEL_GLOBALS: OnceCell<OtelGlobals> = OnceCell::new();
 
 #[sys_traits::auto_impl]
@@ -1170,26 +1052,32 @@ pub fn init(
   // Parse the `OTEL_EXPORTER_OTLP_PROTOCOL` variable. The opentelemetry_*
   // crates don't do this automatically.
   let protocol_var = sys.env_var("OTEL_EXPORTER_OTLP_PROTOCOL");
-  let (use_console_exporter, protocol) = match protocol_var.as_deref() {
-    Ok("console") => (true, Protocol::HttpBinary),
-    Ok("http/protobuf") | Ok("") | Err(std::env::VarError::NotPresent) => {
-      (false, Protocol::HttpBinary)
-    }
-    Ok("http/json") => (false, Protocol::HttpJson),
-    Ok("grpc") => (false, Protocol::Grpc),
-    Ok(protocol) => {
-      return Err(deno_core::anyhow::anyhow!(
-        "Env var OTEL_EXPORTER_OTLP_PROTOCOL specifies an unsupported protocol: {}",
-        protocol
-      ));
-    }
-    Err(err) => {
-      return Err(deno_core::anyhow::anyhow!(
-        "Failed to read env var OTEL_EXPORTER_OTLP_PROTOCOL: {}",
-        err
-      ));
-    }
-  };
+  let (use_console_exporter, file_exporter_config, protocol) =
+    match protocol_var.as_deref() {
+      Ok("console") => (true, None, Protocol::HttpBinary),
+      Ok("file") => (
+        false,
+        Some(file_exporter::FileExporterConfig::from_env(sys)?),
+        Protocol::HttpJson,
+      ),
+      Ok("http/protobuf") | Ok("") | Err(std::env::VarError::NotPresent) => {
+        (false, None, Protocol::HttpBinary)
+      }
+      Ok("http/json") => (false, None, Protocol::HttpJson),
+      Ok("grpc") => (false, None, Protocol::Grpc),
+      Ok(protocol) => {
+        return Err(deno_core::anyhow::anyhow!(
+          "Env var OTEL_EXPORTER_OTLP_PROTOCOL specifies an unsupported protocol: {}",
+          protocol
+        ));
+      }
+      Err(err) => {
+        return Err(deno_core::anyhow::anyhow!(
+          "Failed to read env var OTEL_EXPORTER_OTLP_PROTOCOL: {}",
+          err
+        ));
+      }
+    };
 
   // Define the resource attributes that will be attached to all log records.
   // These attributes are sourced as follows (in order of precedence):
@@ -1268,6 +1156,26 @@ pub fn init(
       BatchLogProcessor::builder(log_exporter, OtelSharedRuntime).build();
     log_processor.set_resource(&resource);
 
+    (span_processor, meter_provider, log_processor)
+  } else if let Some(file_config) = file_exporter_config {
+    let span_exporter = file_exporter::FileSpanExporter::new(&file_config);
+    let mut span_processor =
+      BatchSpanProcessor::builder(span_exporter, OtelSharedRuntime).build();
+    span_processor.set_resource(&resource);
+
+    let metric_exporter =
+      file_exporter::FileMetricExporter::new(&file_config, temporality);
+    let metric_reader = DenoPeriodicReader::new(sys, metric_exporter);
+    let meter_provider = SdkMeterProvider::builder()
+      .with_reader(metric_reader)
+      .with_resource(resource.clone())
+      .build();
+
+    let log_exporter = file_exporter::FileLogExporter::new(&file_config);
+    let log_processor =
+      BatchLogProcessor::builder(log_exporter, OtelSharedRuntime).build();
+    log_processor.set_resource(&resource);
+
     (span_processor, meter_provider, log_processor)
   } else if protocol == Protocol::Grpc {
     let client = hyper_client::HyperClient::new_h2(sys)?;
@@ -1347,7 +1255,7 @@ pub fn init(
       builtin_instrumentation_scope,
       span_event_count_limit,
       span_attribute_count_limit,
-      sampler,
+      sampler: std::sync::RwLock::new(sampler),
       config,
     })
     .map_err(|_| deno_core::anyhow::anyhow!("failed to set otel globals"))?;
@@ -1358,6 +1266,18 @@ pub fn init(
   Ok(())
 }
 
+/// Applies the sampling settings of the config file, which is only loaded
+/// after telemetry is initialized. Does nothing if telemetry is disabled.
+pub fn configure_sampler(
+  sys: &impl TelemetrySys,
+  config: SamplerConfig,
+) -> deno_core::anyhow::Result<()> {
+  let Some(OtelGlobals { sampler, .. }) = OTEL_GLOBALS.get() else {
+    return Ok(());
+  };
+  sampler.write().unwrap().configure(sys, config)
+}
+
 fn before_exit() {
   log::trace!("deno_telemetry::before_exit");
 
@@ -1956,11 +1876,25 @@ impl OtelTracer {
         parent_span_context = None;
       }
     }
-    let sampled = sampler.should_sample(parent_span_context.as_ref(), trace_id);
+    let span_kind = match span_kind {
+      0 => SpanKind::Internal,
+      1 => SpanKind::Server,
+      2 => SpanKind::Client,
+      3 => SpanKind::Producer,
+      4 => SpanKind::Consumer,
+      _ => return Err(JsErrorBox::generic("invalid span kind")),
+    };
+    let span_id = id_generator.new_span_id();
+    let decision = sampler.read().unwrap().should_sample(
+      parent_span_context.as_ref(),
+      trace_id,
+      span_id,
+      &span_kind,
+    );
     let span_context = SpanContext::new(
       trace_id,
-      id_generator.new_span_id(),
-      if sampled {
+      span_id,
+      if decision == SamplingDecision::Sample {
         TraceFlags::SAMPLED
       } else {
         TraceFlags::default()
@@ -1968,7 +1902,7 @@ impl OtelTracer {
       false,
       trace_state,
     );
-    if !sampled {
+    if decision == SamplingDecision::Drop {
       // The span is not sampled: keep its context for propagation, but do not
       // record or export it.
       return Ok(OtelSpan(Rc::new(RefCell::new(Box::new(
@@ -1981,14 +1915,6 @@ impl OtelTracer {
         .try_cast()
         .map_err(|e: DataError| JsErrorBox::generic(e.to_string()))?,
     );
-    let span_kind = match span_kind {
-      0 => SpanKind::Internal,
-      1 => SpanKind::Server,
-      2 => SpanKind::Client,
-      3 => SpanKind::Producer,
-      4 => SpanKind::Consumer,
-      _ => return Err(JsErrorBox::generic("invalid span kind")),
-    };
     let start_time = start_time
       .map(|start_time| {
         SystemTime::UNIX_EPOCH
@@ -2051,11 +1977,25 @@ impl OtelTracer {
       true,
       TraceState::NONE,
     );
-    let sampled = sampler.should_sample(Some(&parent_context), parent_trace_id);
+    let span_kind = match span_kind {
+      0 => SpanKind::Internal,
+      1 => SpanKind::Server,
+      2 => SpanKind::Client,
+      3 => SpanKind::Producer,
+      4 => SpanKind::Consumer,
+      _ => return Err(JsErrorBox::generic("invalid span kind")),
+    };
+    let span_id = id_generator.new_span_id();
+    let decision = sampler.read().unwrap().should_sample(
+      Some(&parent_context),
+      parent_trace_id,
+      span_id,
+      &span_kind,
+    );
     let span_context = SpanContext::new(
       parent_trace_id,
-      id_generator.new_span_id(),
-      if sampled {
+      span_id,
+      if decision == SamplingDecision::Sample {
         TraceFlags::SAMPLED
       } else {
         TraceFlags::default()
@@ -2063,7 +2003,7 @@ impl OtelTracer {
       false,
       TraceState::NONE,
     );
-    if !sampled {
+    if decision == SamplingDecision::Drop {
       return Ok(OtelSpan(Rc::new(RefCell::new(Box::new(
         OtelSpanState::Done(span_context),
       )))));
@@ -2074,14 +2014,6 @@ impl OtelTracer {
         .try_cast()
         .map_err(|e: DataError| JsErrorBox::generic(e.to_string()))?,
     );
-    let span_kind = match span_kind {
-      0 => SpanKind::Internal,
-      1 => SpanKind::Server,
-      2 => SpanKind::Client,
-      3 => SpanKind::Producer,
-      4 => SpanKind::Consumer,
-      _ => return Err(JsErrorBox::generic("invalid span kind")),
-    };
     let start_time = start_time
       .map(|start_time| {
         SystemTime::UNIX_EPOCH
@@ -2245,11 +2177,22 @@ impl OtelSpan {
         Box::new(OtelSpanState::Done(span_context)),
       ) {
         span.end_time = end_time;
-        let Some(OtelGlobals { span_processor, .. }) = OTEL_GLOBALS.get()
+        let Some(OtelGlobals {
+          span_processor,
+          sampler,
+          ..
+        }) = OTEL_GLOBALS.get()
         else {
           return;
         };
-        span_processor.on_end(span);
+        // A recording span that isn't sampled waits for a tail decision.
+        if span.span_context.is_sampled() {
+          span_processor.on_end(span);
+        } else {
+          for span in sampler.read().unwrap().end_deferred(span) {
+            span_processor.on_end(span);
+          }
+        }
       }
     }
   }
@@ -2462,6 +2405,21 @@ impl OtelMeter {
     Ok(OtelMeter(meter))
   }
 
+  #[static_method]
+  #[cppgc]
+  fn builtin() -> Result<OtelMeter, JsErrorBox> {
+    let OtelGlobals {
+      meter_provider,
+      builtin_instrumentation_scope,
+      ..
+    } = OTEL_GLOBALS
+      .get()
+      .ok_or_else(|| JsErrorBox::generic("otel not initialized"))?;
+    Ok(OtelMeter(
+      meter_provider.meter_with_scope(builtin_instrumentation_scope.clone()),
+    ))
+  }
+
   #[cppgc]
   fn create_counter<'s>(
     &self,
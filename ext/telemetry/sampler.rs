@@ -0,0 +1,754 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Trace sampling.
+//!
+//! Most spans are sampled when they start ("head-based"), using the sampler
+//! named by `OTEL_TRACES_SAMPLER`, or the config file's `telemetry.sampler`
+//! when that is unset. Configured [`SamplingRule`]s add a decision made when
+//! a `Deno.serve` request's span ends ("tail-based"), so that errors and slow
+//! requests can be kept even when the head sampler would drop them. Until
+//! then, the spans of such a trace are recorded but held back in memory.
+//! Only the spans this process records are held back: the trace flags sent
+//! to other services still carry the head decision.
+//!
+//! Spans of the trace that end after the request's span follow its decision
+//! for a while. A trace whose request span never ends is eventually dropped,
+//! along with the spans held back for it.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use opentelemetry::Value;
+use opentelemetry::trace::SpanContext;
+use opentelemetry::trace::SpanId;
+use opentelemetry::trace::SpanKind;
+use opentelemetry::trace::Status as SpanStatus;
+use opentelemetry::trace::TraceFlags;
+use opentelemetry::trace::TraceId;
+use opentelemetry_sdk::export::trace::SpanData;
+use sys_traits::EnvVar;
+
+/// Traces per second kept by the `ratelimiting` samplers when
+/// `OTEL_TRACES_SAMPLER_ARG` is not set.
+const DEFAULT_RATE_LIMIT: f64 = 100.0;
+
+/// How many traces can wait for a tail decision at once. Further requests
+/// are sampled by the head sampler alone.
+const MAX_DEFERRED_TRACES: usize = 4096;
+
+/// How many spans of a trace waiting for a tail decision are held back.
+/// Further spans of the trace are dropped.
+const MAX_DEFERRED_SPANS: usize = 1024;
+
+/// How long a trace waits for its local root span to end before it is
+/// dropped.
+const DEFERRED_TRACE_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// How long the tail decision of a trace applies to its spans that end after
+/// the local root span.
+const DECIDED_TRACE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How often expired traces are looked for.
+const EVICTION_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Sampling settings from the config file.
+#[derive(Debug, Clone, Default)]
+pub struct SamplerConfig {
+  /// A sampler name accepted by `OTEL_TRACES_SAMPLER`, which takes
+  /// precedence.
+  pub sampler: Option<String>,
+  /// The argument of `sampler`, like `OTEL_TRACES_SAMPLER_ARG`.
+  pub arg: Option<f64>,
+  pub rules: Vec<SamplingRule>,
+}
+
+/// Decides whether to keep the trace of a `Deno.serve` request once the
+/// request's span has ended. The first rule that matches a request applies.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SamplingRule {
+  /// The route (`http.route`) or else path (`url.path`) of the requests the
+  /// rule applies to, or a prefix of it followed by `*`. Applies to every
+  /// request when unset.
+  pub route: Option<String>,
+  /// The share of requests to keep, in the range [0, 1]. When unset, the
+  /// head sampler's decision is kept.
+  pub ratio: Option<f64>,
+  /// Always keep requests that fail: their span has an error status or the
+  /// response has a 5xx status code.
+  pub errors: bool,
+  /// Always keep requests that take at least this long.
+  pub slower_than: Option<Duration>,
+}
+
+impl SamplingRule {
+  fn matches(&self, span: &SpanData) -> bool {
+    let Some(pattern) = &self.route else {
+      return true;
+    };
+    let Some(route) = string_attribute(span, "http.route")
+      .or_else(|| string_attribute(span, "url.path"))
+    else {
+      return false;
+    };
+    match pattern.strip_suffix('*') {
+      Some(prefix) => route.starts_with(prefix),
+      None => route == pattern.as_str(),
+    }
+  }
+
+  fn keep(&self, span: &SpanData, head_sampled: bool) -> bool {
+    if self.errors && is_error(span) {
+      return true;
+    }
+    if let Some(threshold) = self.slower_than
+      && span
+        .end_time
+        .duration_since(span.start_time)
+        .is_ok_and(|duration| duration >= threshold)
+    {
+      return true;
+    }
+    match self.ratio {
+      Some(ratio) => trace_id_ratio(ratio, span.span_context.trace_id()),
+      None => head_sampled,
+    }
+  }
+}
+
+fn string_attribute<'a>(span: &'a SpanData, key: &str) -> Option<&'a str> {
+  span.attributes.iter().find_map(|kv| match &kv.value {
+    Value::String(value) if kv.key.as_str() == key => Some(value.as_str()),
+    _ => None,
+  })
+}
+
+fn is_error(span: &SpanData) -> bool {
+  matches!(span.status, SpanStatus::Error { .. })
+    || span.attributes.iter().any(|kv| {
+      kv.key.as_str() == "http.response.status_code"
+        && match &kv.value {
+          Value::I64(status) => *status >= 500,
+          Value::String(status) => status
+            .as_str()
+            .parse::<u16>()
+            .is_ok_and(|status| status >= 500),
+          _ => false,
+        }
+    })
+}
+
+/// The decision for a span that starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SamplingDecision {
+  Drop,
+  Sample,
+  /// Record the span, but decide whether to export it when its trace's
+  /// local root span ends.
+  Defer,
+}
+
+/// Keeps a share of traces per second, as a token bucket that holds up to
+/// one second of traces.
+#[derive(Debug)]
+struct RateLimiter {
+  per_second: f64,
+  state: Mutex<(f64, Instant)>,
+}
+
+impl RateLimiter {
+  fn new(per_second: f64) -> Self {
+    let per_second = per_second.max(0.0);
+    let limiter = Self {
+      per_second,
+      state: Mutex::new((0.0, Instant::now())),
+    };
+    limiter.state.lock().unwrap().0 = limiter.capacity();
+    limiter
+  }
+
+  /// At least one trace, so that rates below one per second still keep
+  /// some, unless the rate is zero.
+  fn capacity(&self) -> f64 {
+    if self.per_second == 0.0 {
+      0.0
+    } else {
+      self.per_second.max(1.0)
+    }
+  }
+
+  fn try_acquire(&self) -> bool {
+    self.try_acquire_at(Instant::now())
+  }
+
+  fn try_acquire_at(&self, now: Instant) -> bool {
+    let capacity = self.capacity();
+    let mut state = self.state.lock().unwrap();
+    let (tokens, last) = &mut *state;
+    *tokens = (*tokens
+      + now.duration_since(*last).as_secs_f64() * self.per_second)
+      .min(capacity);
+    *last = now;
+    if *tokens >= 1.0 {
+      *tokens -= 1.0;
+      true
+    } else {
+      false
+    }
+  }
+}
+
+#[derive(Debug)]
+enum RootSampler {
+  AlwaysOn,
+  AlwaysOff,
+  TraceIdRatio(f64),
+  RateLimiting(RateLimiter),
+}
+
+#[derive(Debug)]
+struct DeferredTrace {
+  root_span_id: SpanId,
+  head_sampled: bool,
+  spans: Vec<SpanData>,
+  expires: Instant,
+}
+
+#[derive(Debug, Default)]
+struct DeferredTraces {
+  /// Traces waiting for their local root span to end.
+  pending: HashMap<TraceId, DeferredTrace>,
+  /// Whether the traces whose local root span ended recently were kept, and
+  /// until when their later spans follow that.
+  decided: HashMap<TraceId, (bool, Instant)>,
+  last_eviction: Option<Instant>,
+}
+
+impl DeferredTraces {
+  fn contains(&self, trace_id: &TraceId) -> bool {
+    self.pending.contains_key(trace_id) || self.decided.contains_key(trace_id)
+  }
+
+  fn evict_expired(&mut self, now: Instant) {
+    if self
+      .last_eviction
+      .is_some_and(|last| now.duration_since(last) < EVICTION_INTERVAL)
+    {
+      return;
+    }
+    self.last_eviction = Some(now);
+    self.pending.retain(|_, trace| trace.expires > now);
+    self.decided.retain(|_, (_, expires)| *expires > now);
+  }
+}
+
+/// Trace sampler configured via the `OTEL_TRACES_SAMPLER` and
+/// `OTEL_TRACES_SAMPLER_ARG` environment variables, or the config file.
+///
+/// Besides the samplers the OpenTelemetry spec defines, `ratelimiting` and
+/// `parentbased_ratelimiting` keep at most `OTEL_TRACES_SAMPLER_ARG` traces
+/// per second.
+///
+/// See <https://opentelemetry.io/docs/specs/otel/configuration/sdk-environment-variables/#general-sdk-configuration>
+#[derive(Debug)]
+pub struct Sampler {
+  /// When set, the sampling decision of a valid parent span takes precedence
+  /// over `root` (the `parentbased_*` variants).
+  parent_based: bool,
+  /// The sampler consulted when there is no parent (or `parent_based` is
+  /// false).
+  root: RootSampler,
+  rules: Vec<SamplingRule>,
+  deferred: Mutex<DeferredTraces>,
+}
+
+impl Default for Sampler {
+  fn default() -> Self {
+    // When `OTEL_TRACES_SAMPLER` is unset, preserve Deno's historical behavior
+    // of recording and sampling every span.
+    Self::new(false, RootSampler::AlwaysOn)
+  }
+}
+
+impl Sampler {
+  fn new(parent_based: bool, root: RootSampler) -> Self {
+    Sampler {
+      parent_based,
+      root,
+      rules: Vec::new(),
+      deferred: Default::default(),
+    }
+  }
+
+  pub(crate) fn from_env(
+    sys: &impl EnvVar,
+  ) -> Result<Self, deno_core::anyhow::Error> {
+    let Ok(value) = sys.env_var("OTEL_TRACES_SAMPLER") else {
+      return Ok(Self::default());
+    };
+    let value = value.trim();
+    if value.is_empty() {
+      return Ok(Self::default());
+    }
+    let arg = sys
+      .env_var("OTEL_TRACES_SAMPLER_ARG")
+      .ok()
+      .and_then(|s| s.trim().parse::<f64>().ok());
+    Self::from_name(value, arg).map_err(|name| {
+      deno_core::anyhow::anyhow!(
+        "Env var OTEL_TRACES_SAMPLER specifies an unsupported sampler: {}",
+        name
+      )
+    })
+  }
+
+  /// Creates the sampler `name`, returning the name back if it is unknown.
+  fn from_name<'a>(name: &'a str, arg: Option<f64>) -> Result<Self, &'a str> {
+    // `arg` is the sampling probability for the `traceidratio` samplers, in
+    // the range [0, 1], and the traces per second for the `ratelimiting`
+    // ones. It is ignored by the other samplers.
+    let ratio = || arg.unwrap_or(1.0).clamp(0.0, 1.0);
+    let rate_limiter = || RateLimiter::new(arg.unwrap_or(DEFAULT_RATE_LIMIT));
+    let (parent_based, root) = match name {
+      "always_on" => (false, RootSampler::AlwaysOn),
+      "always_off" => (false, RootSampler::AlwaysOff),
+      "traceidratio" => (false, RootSampler::TraceIdRatio(ratio())),
+      "ratelimiting" => (false, RootSampler::RateLimiting(rate_limiter())),
+      "parentbased_always_on" => (true, RootSampler::AlwaysOn),
+      "parentbased_always_off" => (true, RootSampler::AlwaysOff),
+      "parentbased_traceidratio" => (true, RootSampler::TraceIdRatio(ratio())),
+      "parentbased_ratelimiting" => {
+        (true, RootSampler::RateLimiting(rate_limiter()))
+      }
+      other => return Err(other),
+    };
+    Ok(Self::new(parent_based, root))
+  }
+
+  /// Applies the config file's sampling settings. `sampler` only replaces
+  /// this sampler when it didn't come from `OTEL_TRACES_SAMPLER`.
+  pub(crate) fn configure(
+    &mut self,
+    sys: &impl EnvVar,
+    config: SamplerConfig,
+  ) -> Result<(), deno_core::anyhow::Error> {
+    let from_env = sys
+      .env_var("OTEL_TRACES_SAMPLER")
+      .is_ok_and(|value| !value.trim().is_empty());
+    if let Some(name) = &config.sampler
+      && !from_env
+    {
+      *self = Self::from_name(name, config.arg).map_err(|name| {
+        deno_core::anyhow::anyhow!(
+          "The \"telemetry.sampler\" configuration specifies an unsupported sampler: {}",
+          name
+        )
+      })?;
+    }
+    self.rules = config.rules;
+    Ok(())
+  }
+
+  /// Decides whether a span with the given `trace_id` should be recorded and
+  /// exported, given its `parent` span context if any.
+  pub(crate) fn should_sample(
+    &self,
+    parent: Option<&SpanContext>,
+    trace_id: TraceId,
+    span_id: SpanId,
+    span_kind: &SpanKind,
+  ) -> SamplingDecision {
+    self.should_sample_at(parent, trace_id, span_id, span_kind, Instant::now())
+  }
+
+  fn should_sample_at(
+    &self,
+    parent: Option<&SpanContext>,
+    trace_id: TraceId,
+    span_id: SpanId,
+    span_kind: &SpanKind,
+    now: Instant,
+  ) -> SamplingDecision {
+    if let Some(parent) = parent
+      && !parent.is_remote()
+      && !parent.is_sampled()
+      && self.is_deferred(trace_id)
+    {
+      return SamplingDecision::Defer;
+    }
+    let parent_decision = parent.and_then(|parent| {
+      (self.parent_based && parent.is_valid()).then(|| parent.is_sampled())
+    });
+    // A sampled parent decided for the whole trace already.
+    if parent_decision == Some(true) {
+      return SamplingDecision::Sample;
+    }
+    let head_sampled = parent_decision.unwrap_or_else(|| match parent {
+      // Rate limits count traces, not spans: a local child follows its
+      // parent instead of taking a token.
+      Some(parent)
+        if matches!(self.root, RootSampler::RateLimiting(_))
+          && !parent.is_remote() =>
+      {
+        parent.is_sampled()
+      }
+      _ => self.sample_root(trace_id),
+    });
+    let is_local_root = parent.is_none_or(|parent| parent.is_remote());
+    if self.rules.is_empty() || *span_kind != SpanKind::Server || !is_local_root
+    {
+      return if head_sampled {
+        SamplingDecision::Sample
+      } else {
+        SamplingDecision::Drop
+      };
+    }
+    let mut deferred = self.deferred.lock().unwrap();
+    deferred.evict_expired(now);
+    if deferred.pending.len() >= MAX_DEFERRED_TRACES {
+      return if head_sampled {
+        SamplingDecision::Sample
+      } else {
+        SamplingDecision::Drop
+      };
+    }
+    deferred.pending.insert(
+      trace_id,
+      DeferredTrace {
+        root_span_id: span_id,
+        head_sampled,
+        spans: Vec::new(),
+        expires: now + DEFERRED_TRACE_TIMEOUT,
+      },
+    );
+    SamplingDecision::Defer
+  }
+
+  fn sample_root(&self, trace_id: TraceId) -> bool {
+    match &self.root {
+      RootSampler::AlwaysOn => true,
+      RootSampler::AlwaysOff => false,
+      RootSampler::TraceIdRatio(ratio) => trace_id_ratio(*ratio, trace_id),
+      RootSampler::RateLimiting(limiter) => limiter.try_acquire(),
+    }
+  }
+
+  fn is_deferred(&self, trace_id: TraceId) -> bool {
+    self.deferred.lock().unwrap().contains(&trace_id)
+  }
+
+  /// Takes a span that ended with a deferred decision. Returns the spans to
+  /// export: none until the trace's local root span ends, and then either
+  /// all of the trace's spans or none, depending on the rules. Spans that end
+  /// later follow that decision.
+  pub(crate) fn end_deferred(&self, span: SpanData) -> Vec<SpanData> {
+    self.end_deferred_at(span, Instant::now())
+  }
+
+  fn end_deferred_at(&self, mut span: SpanData, now: Instant) -> Vec<SpanData> {
+    let trace_id = span.span_context.trace_id();
+    let mut deferred = self.deferred.lock().unwrap();
+    deferred.evict_expired(now);
+    if let Some((keep, _)) = deferred.decided.get(&trace_id) {
+      if !*keep {
+        return Vec::new();
+      }
+      mark_sampled(&mut span);
+      return vec![span];
+    }
+    let Some(trace) = deferred.pending.get_mut(&trace_id) else {
+      return Vec::new();
+    };
+    if trace.root_span_id != span.span_context.span_id() {
+      if trace.spans.len() < MAX_DEFERRED_SPANS {
+        trace.spans.push(span);
+      }
+      return Vec::new();
+    }
+    let trace = deferred.pending.remove(&trace_id).unwrap();
+    let keep = match self.rules.iter().find(|rule| rule.matches(&span)) {
+      Some(rule) => rule.keep(&span, trace.head_sampled),
+      None => trace.head_sampled,
+    };
+    if deferred.decided.len() < MAX_DEFERRED_TRACES {
+      deferred
+        .decided
+        .insert(trace_id, (keep, now + DECIDED_TRACE_TIMEOUT));
+    }
+    drop(deferred);
+    if !keep {
+      return Vec::new();
+    }
+    let mut spans = trace.spans;
+    spans.push(span);
+    for span in &mut spans {
+      mark_sampled(span);
+    }
+    spans
+  }
+}
+
+fn mark_sampled(span: &mut SpanData) {
+  let context = &span.span_context;
+  span.span_context = SpanContext::new(
+    context.trace_id(),
+    context.span_id(),
+    TraceFlags::SAMPLED,
+    context.is_remote(),
+    context.trace_state().clone(),
+  );
+}
+
+fn trace_id_ratio(ratio: f64, trace_id: TraceId) -> bool {
+  if ratio >= 1.0 {
+    return true;
+  }
+  if ratio <= 0.0 {
+    return false;
+  }
+  // Matches the opentelemetry-rust `TraceIdRatioBased` sampler: derive a
+  // deterministic value in [0, 2^63) from the lower 64 bits of the trace
+  // id and keep the span when it falls below the probability bound.
+  let bytes = trace_id.to_bytes();
+  let low = u64::from_be_bytes(bytes[8..16].try_into().unwrap());
+  let value = low >> 1;
+  let bound = (ratio * (1u64 << 63) as f64) as u64;
+  value < bound
+}
+
+#[cfg(test)]
+mod tests {
+  use std::borrow::Cow;
+  use std::time::SystemTime;
+
+  use opentelemetry::InstrumentationScope;
+  use opentelemetry::KeyValue;
+  use opentelemetry::trace::TraceState;
+  use opentelemetry_sdk::trace::SpanEvents;
+  use opentelemetry_sdk::trace::SpanLinks;
+
+  use super::*;
+
+  const TRACE_ID: TraceId = TraceId::from_u128(1);
+  const ROOT_SPAN_ID: SpanId = SpanId::from_u64(1);
+  const CHILD_SPAN_ID: SpanId = SpanId::from_u64(2);
+
+  fn context(span_id: SpanId) -> SpanContext {
+    SpanContext::new(
+      TRACE_ID,
+      span_id,
+      TraceFlags::default(),
+      false,
+      TraceState::default(),
+    )
+  }
+
+  fn span(
+    span_id: SpanId,
+    duration: Duration,
+    attributes: Vec<KeyValue>,
+  ) -> SpanData {
+    let start_time = SystemTime::UNIX_EPOCH;
+    SpanData {
+      span_context: context(span_id),
+      parent_span_id: SpanId::INVALID,
+      span_kind: SpanKind::Server,
+      name: Cow::Borrowed("span"),
+      start_time,
+      end_time: start_time + duration,
+      attributes,
+      dropped_attributes_count: 0,
+      status: SpanStatus::Unset,
+      events: SpanEvents::default(),
+      links: SpanLinks::default(),
+      instrumentation_scope: InstrumentationScope::builder("test").build(),
+    }
+  }
+
+  fn sampler_with_rule(rule: SamplingRule) -> Sampler {
+    let mut sampler = Sampler::new(false, RootSampler::AlwaysOff);
+    sampler.rules = vec![rule];
+    sampler
+  }
+
+  /// Starts a request span and a child span of it at `now`.
+  fn start_trace(sampler: &Sampler, now: Instant) {
+    assert_eq!(
+      sampler.should_sample_at(
+        None,
+        TRACE_ID,
+        ROOT_SPAN_ID,
+        &SpanKind::Server,
+        now
+      ),
+      SamplingDecision::Defer
+    );
+    assert_eq!(
+      sampler.should_sample_at(
+        Some(&context(ROOT_SPAN_ID)),
+        TRACE_ID,
+        CHILD_SPAN_ID,
+        &SpanKind::Internal,
+        now
+      ),
+      SamplingDecision::Defer
+    );
+  }
+
+  #[test]
+  fn rate_limiter_refills_over_time() {
+    let limiter = RateLimiter::new(2.0);
+    let start = limiter.state.lock().unwrap().1;
+    assert!(limiter.try_acquire_at(start));
+    assert!(limiter.try_acquire_at(start));
+    assert!(!limiter.try_acquire_at(start));
+    let later = start + Duration::from_millis(500);
+    assert!(limiter.try_acquire_at(later));
+    assert!(!limiter.try_acquire_at(later));
+    // no more than a second of traces builds up
+    let much_later = start + Duration::from_secs(60);
+    assert!(limiter.try_acquire_at(much_later));
+    assert!(limiter.try_acquire_at(much_later));
+    assert!(!limiter.try_acquire_at(much_later));
+  }
+
+  #[test]
+  fn rate_limiter_below_one_per_second() {
+    let limiter = RateLimiter::new(0.5);
+    let start = limiter.state.lock().unwrap().1;
+    assert!(limiter.try_acquire_at(start));
+    assert!(!limiter.try_acquire_at(start + Duration::from_secs(1)));
+    assert!(limiter.try_acquire_at(start + Duration::from_secs(2)));
+
+    let limiter = RateLimiter::new(0.0);
+    let start = limiter.state.lock().unwrap().1;
+    assert!(!limiter.try_acquire_at(start));
+    assert!(!limiter.try_acquire_at(start + Duration::from_secs(60)));
+  }
+
+  #[test]
+  fn rule_matches_routes() {
+    let rule = SamplingRule {
+      route: Some("/api/*".to_string()),
+      ..Default::default()
+    };
+    let with_attribute = |key: &'static str, value: &'static str| {
+      span(
+        ROOT_SPAN_ID,
+        Duration::ZERO,
+        vec![KeyValue::new(key, value)],
+      )
+    };
+    assert!(rule.matches(&with_attribute("http.route", "/api/users/:id")));
+    assert!(rule.matches(&with_attribute("url.path", "/api/users/1")));
+    assert!(!rule.matches(&with_attribute("url.path", "/static/app.js")));
+    assert!(!rule.matches(&span(ROOT_SPAN_ID, Duration::ZERO, vec![])));
+    assert!(SamplingRule::default().matches(&span(
+      ROOT_SPAN_ID,
+      Duration::ZERO,
+      vec![]
+    )));
+  }
+
+  #[test]
+  fn rule_keeps_errors_and_slow_requests() {
+    let rule = SamplingRule {
+      errors: true,
+      slower_than: Some(Duration::from_secs(1)),
+      ratio: Some(0.0),
+      ..Default::default()
+    };
+    let status = |status: i64| {
+      span(
+        ROOT_SPAN_ID,
+        Duration::ZERO,
+        vec![KeyValue::new("http.response.status_code", status)],
+      )
+    };
+    assert!(rule.keep(&status(500), false));
+    assert!(!rule.keep(&status(404), true));
+    assert!(
+      rule.keep(&span(ROOT_SPAN_ID, Duration::from_secs(2), vec![]), false)
+    );
+    assert!(!rule.keep(
+      &span(ROOT_SPAN_ID, Duration::from_millis(10), vec![]),
+      false
+    ));
+    // without a ratio, the head decision stands
+    let rule = SamplingRule::default();
+    assert!(rule.keep(&status(200), true));
+    assert!(!rule.keep(&status(200), false));
+  }
+
+  #[test]
+  fn tail_decision_exports_the_whole_trace() {
+    let sampler = sampler_with_rule(SamplingRule {
+      errors: true,
+      ..Default::default()
+    });
+    let now = Instant::now();
+    start_trace(&sampler, now);
+    let child = span(CHILD_SPAN_ID, Duration::ZERO, vec![]);
+    assert!(sampler.end_deferred_at(child, now).is_empty());
+    let root = span(
+      ROOT_SPAN_ID,
+      Duration::ZERO,
+      vec![KeyValue::new("http.response.status_code", 503_i64)],
+    );
+    let spans = sampler.end_deferred_at(root, now);
+    assert_eq!(
+      spans
+        .iter()
+        .map(|span| span.span_context.span_id())
+        .collect::<Vec<_>>(),
+      vec![CHILD_SPAN_ID, ROOT_SPAN_ID]
+    );
+    assert!(spans.iter().all(|span| span.span_context.is_sampled()));
+  }
+
+  #[test]
+  fn late_spans_follow_the_tail_decision() {
+    for (status, kept) in [(500_i64, true), (200_i64, false)] {
+      let sampler = sampler_with_rule(SamplingRule {
+        errors: true,
+        ..Default::default()
+      });
+      let now = Instant::now();
+      start_trace(&sampler, now);
+      let root = span(
+        ROOT_SPAN_ID,
+        Duration::ZERO,
+        vec![KeyValue::new("http.response.status_code", status)],
+      );
+      assert_eq!(sampler.end_deferred_at(root, now).len(), kept as usize);
+      let child = span(CHILD_SPAN_ID, Duration::ZERO, vec![]);
+      let spans = sampler.end_deferred_at(child, now);
+      assert_eq!(spans.len(), kept as usize);
+      assert!(spans.iter().all(|span| span.span_context.is_sampled()));
+
+      // until the decision is forgotten
+      let later = now + DECIDED_TRACE_TIMEOUT + EVICTION_INTERVAL;
+      let child = span(CHILD_SPAN_ID, Duration::ZERO, vec![]);
+      assert!(sampler.end_deferred_at(child, later).is_empty());
+      assert!(sampler.deferred.lock().unwrap().decided.is_empty());
+    }
+  }
+
+  #[test]
+  fn traces_whose_root_never_ends_are_dropped() {
+    let sampler = sampler_with_rule(SamplingRule {
+      ratio: Some(1.0),
+      ..Default::default()
+    });
+    let now = Instant::now();
+    start_trace(&sampler, now);
+    let child = span(CHILD_SPAN_ID, Duration::ZERO, vec![]);
+    assert!(sampler.end_deferred_at(child, now).is_empty());
+
+    let later = now + DEFERRED_TRACE_TIMEOUT + EVICTION_INTERVAL;
+    let root = span(ROOT_SPAN_ID, Duration::ZERO, vec![]);
+    assert!(sampler.end_deferred_at(root, later).is_empty());
+    assert!(sampler.deferred.lock().unwrap().pending.is_empty());
+  }
+}
@@ -1831,6 +1831,15 @@ function builtinTracer(): Tracer {
   return builtinTracerCache;
 }
 
+let builtinMeterCache: Meter;
+
+function builtinMeter(): Meter {
+  if (!builtinMeterCache) {
+    builtinMeterCache = new Meter(OtelMeter.builtin());
+  }
+  return builtinMeterCache;
+}
+
 function enableIsolateMetrics() {
   op_otel_enable_isolate_metrics();
   ISOLATE_METRICS = true;
@@ -1968,6 +1977,7 @@ return {
   W3CBaggagePropagator,
   CompositePropagator,
   builtinTracer,
+  builtinMeter,
   bootstrap: wrappedBootstrap,
   telemetry,
 };
@@ -7,10 +7,12 @@
 // - timer nesting depth tracking (WHATWG spec)
 // - numeric timer IDs
 // - AsyncContext propagation across the callback boundary
+//
+// Also home to the virtual clock behind `t.clock` in `deno test`.
 
 (function () {
 const { core, primordials } = __bootstrap;
-const { op_defer } = core.ops;
+const { op_defer, op_now, op_set_virtual_now } = core.ops;
 const {
   createTimer,
   cancelTimer,
@@ -18,15 +20,30 @@ const {
   unrefTimer: coreUnrefTimer,
   getAsyncContext,
   setAsyncContext,
+  enableVirtualTime,
+  advanceVirtualTime,
+  nextTimerExpiry,
+  disableVirtualTime,
 } = core;
 const {
+  DateNow,
+  DatePrototypeToString,
+  Error,
+  MathMax,
   MapPrototypeDelete,
   MapPrototypeGet,
   MapPrototypeSet,
+  NumberIsFinite,
+  ObjectSetPrototypeOf,
   PromisePrototypeThen,
+  RangeError,
   ReflectApply,
+  ReflectConstruct,
   SafeMap,
+  TypedArrayPrototypeGetBuffer,
   TypeError,
+  Uint32Array,
+  Uint8Array,
   indirectEval,
 } = primordials;
 
@@ -171,9 +188,132 @@ function defer(go) {
   PromisePrototypeThen(op_defer(), () => go());
 }
 
+// A virtual clock keeps running timers past this many expiries in
+// `runAll()`, so an interval that is never cleared can't hang a test.
+const VIRTUAL_CLOCK_LOOP_LIMIT = 10_000;
+
+const hrU8 = new Uint8Array(8);
+const hr = new Uint32Array(TypedArrayPrototypeGetBuffer(hrU8));
+
+function performanceNow() {
+  op_now(hrU8);
+  return hr[0] * 1000 + hr[1] / 1e6;
+}
+
+/**
+ * A `Date` constructor that reads the current time from `now` instead of
+ * the system clock.
+ */
+function createVirtualDate(OriginalDate, now) {
+  function Date(...args) {
+    if (new.target === undefined) {
+      return ReflectApply(DatePrototypeToString, new OriginalDate(now()), []);
+    }
+    return ReflectConstruct(
+      OriginalDate,
+      args.length === 0 ? [now()] : args,
+      new.target,
+    );
+  }
+  // `Date.parse`, `Date.UTC`, etc. are inherited.
+  ObjectSetPrototypeOf(Date, OriginalDate);
+  Date.prototype = OriginalDate.prototype;
+  Date.now = now;
+  return Date;
+}
+
+/**
+ * Freeze time for timers, `Date` and `performance.now()`, so that it only
+ * moves when the returned clock is advanced. Every timer goes through the
+ * core timer queue, including internal ones like `AbortSignal.timeout()`,
+ * so they all observe the same virtual time.
+ */
+function createVirtualClock() {
+  const timerStart = enableVirtualTime();
+  const performanceStart = performanceNow();
+  const dateStart = DateNow();
+  let elapsed = 0;
+
+  const OriginalDate = globalThis.Date;
+  const VirtualDate = createVirtualDate(
+    OriginalDate,
+    () => dateStart + elapsed,
+  );
+  globalThis.Date = VirtualDate;
+
+  function advanceTo(nextElapsed) {
+    elapsed = MathMax(elapsed, nextElapsed);
+    op_set_virtual_now(performanceStart + elapsed);
+    advanceVirtualTime(timerStart + elapsed);
+  }
+  advanceTo(0);
+
+  // Timers are run one expiry at a time with an event loop turn in between,
+  // so timers scheduled by callbacks or promise continuations are picked up
+  // at the right virtual time.
+  async function runNextTimers(limit) {
+    const expiry = nextTimerExpiry();
+    if (expiry === null || expiry - timerStart > limit) {
+      return false;
+    }
+    advanceTo(expiry - timerStart);
+    await op_defer();
+    return true;
+  }
+
+  let restored = false;
+  function checkRestored() {
+    if (restored) {
+      throw new Error("The virtual clock has been restored");
+    }
+  }
+
+  return {
+    /** Milliseconds since the epoch, as `Date.now()` returns. */
+    get now() {
+      return dateStart + elapsed;
+    },
+    async tick(ms) {
+      checkRestored();
+      ms = +ms;
+      if (!NumberIsFinite(ms) || ms < 0) {
+        throw new RangeError(
+          `Cannot tick the clock by ${ms}ms: expected a non-negative number`,
+        );
+      }
+      const target = elapsed + ms;
+      while (await runNextTimers(target)) {
+        // keep going until the next timer is past the target
+      }
+      advanceTo(target);
+    },
+    async runAll() {
+      checkRestored();
+      for (let i = 0; i < VIRTUAL_CLOCK_LOOP_LIMIT; i++) {
+        if (!await runNextTimers(Infinity)) {
+          return;
+        }
+      }
+      throw new Error(
+        `Aborted after running ${VIRTUAL_CLOCK_LOOP_LIMIT} timers, assuming an infinite loop`,
+      );
+    },
+    restore() {
+      if (restored) return;
+      restored = true;
+      if (globalThis.Date === VirtualDate) {
+        globalThis.Date = OriginalDate;
+      }
+      op_set_virtual_now(-1);
+      disableVirtualTime();
+    },
+  };
+}
+
 return {
   clearInterval,
   clearTimeout,
+  createVirtualClock,
   defer,
   refTimer,
   setInterval,
@@ -36,13 +36,29 @@ fn expose_time(duration: Duration, out: &mut [u8]) {
   }
 }
 
+/// The frozen `performance.now()` value while a test fake clock is
+/// installed.
+struct VirtualNow(Duration);
+
 #[op2(fast)]
 pub fn op_now(state: &mut OpState, #[buffer] buf: &mut [u8]) {
-  let start_time = state.borrow::<StartTime>();
-  let elapsed = start_time.elapsed();
+  let elapsed = match state.try_borrow::<VirtualNow>() {
+    Some(virtual_now) => virtual_now.0,
+    None => state.borrow::<StartTime>().elapsed(),
+  };
   expose_time(elapsed, buf);
 }
 
+/// Freeze `performance.now()` at `now_ms`, or unfreeze it when negative.
+#[op2(fast)]
+pub fn op_set_virtual_now(state: &mut OpState, now_ms: f64) {
+  if now_ms >= 0.0 {
+    state.put(VirtualNow(Duration::from_secs_f64(now_ms / 1000.0)));
+  } else {
+    state.try_take::<VirtualNow>();
+  }
+}
+
 #[op2(fast)]
 pub fn op_time_origin(state: &mut OpState, #[buffer] buf: &mut [u8]) {
   // https://w3c.github.io/hr-time/#dfn-estimated-monotonic-time-of-the-unix-epoch
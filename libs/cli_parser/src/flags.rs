@@ -138,6 +138,15 @@ pub enum VersionIncrement {
   Prerelease,
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum BenchReporterConfig {
+  #[default]
+  Pretty,
+  Junit,
+  Tap,
+  Github,
+}
+
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct BenchFlags {
   pub files: FileFlags,
@@ -146,6 +155,11 @@ pub struct BenchFlags {
   pub no_run: bool,
   pub permit_no_files: bool,
   pub watch: Option<WatchFlags>,
+  pub reporter: BenchReporterConfig,
+  pub junit_path: Option<String>,
+  pub save_baseline: Option<String>,
+  pub baseline: Option<String>,
+  pub regression_threshold: Option<u32>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -281,6 +295,8 @@ pub enum CoverageType {
   Detailed,
   Lcov,
   Html,
+  Cobertura,
+  Istanbul,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
@@ -293,6 +309,10 @@ pub struct CoverageFlags {
   /// Minimum coverage percentage (0-100) applied to line, branch, and function
   /// coverage. Overrides per-metric thresholds from `deno.json`.
   pub threshold: Option<u32>,
+  /// Git ref to diff against. When set, only lines changed since the
+  /// merge-base of this ref and `HEAD` are reported and checked against the
+  /// threshold.
+  pub changed_since: Option<String>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
@@ -459,6 +479,95 @@ pub struct JupyterFlags {
   pub force: bool,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KvServeFlags {
+  /// The SQLite database to serve, or an in-memory one when `None`.
+  pub path: Option<String>,
+  /// Falls back to the `DENO_KV_ACCESS_TOKEN` environment variable.
+  pub access_token: Option<String>,
+  pub host: String,
+  pub port: u16,
+  /// The URL clients reach the server at, when it isn't the address it
+  /// listens on.
+  pub public_url: Option<String>,
+}
+
+/// Keys are JSON arrays of key parts, parsed when the command runs.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KvListFlags {
+  pub path: Option<String>,
+  pub prefix: Option<String>,
+  pub limit: Option<usize>,
+  pub reverse: bool,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KvGetFlags {
+  pub path: Option<String>,
+  pub key: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KvSetFlags {
+  pub path: Option<String>,
+  pub key: String,
+  /// A JSON value.
+  pub value: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KvDeleteFlags {
+  pub path: Option<String>,
+  pub key: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KvWatchFlags {
+  pub path: Option<String>,
+  pub keys: Vec<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KvExportFlags {
+  pub path: Option<String>,
+  pub prefix: Option<String>,
+  /// Written to stdout when `None`.
+  pub output: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KvImportFlags {
+  pub path: Option<String>,
+  /// Read from stdin when `None`.
+  pub input: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KvBackupFlags {
+  pub path: Option<String>,
+  pub output: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KvRestoreFlags {
+  pub path: Option<String>,
+  pub input: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum KvFlags {
+  Serve(KvServeFlags),
+  List(KvListFlags),
+  Get(KvGetFlags),
+  Set(KvSetFlags),
+  Delete(KvDeleteFlags),
+  Watch(KvWatchFlags),
+  Export(KvExportFlags),
+  Import(KvImportFlags),
+  Backup(KvBackupFlags),
+  Restore(KvRestoreFlags),
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct UninstallFlagsGlobal {
   pub name: String,
@@ -630,6 +739,19 @@ pub struct TaskFlags {
   pub concurrency: Option<NonZeroUsize>,
   /// Exit with code 0 instead of an error when the named task is not found.
   pub if_present: bool,
+  /// Print the resolved task graph and what would run or be skipped,
+  /// without running anything.
+  pub dry_run: bool,
+  /// Export the resolved task graph in this format instead of running it.
+  pub graph: Option<TaskGraphFormat>,
+  /// Rerun the tasks whose inputs changed, keeping unaffected tasks running.
+  pub watch: Option<WatchFlags>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TaskGraphFormat {
+  Dot,
+  Json,
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -676,6 +798,13 @@ pub struct TestFlags {
   /// Run only test modules that depend on the given source files (`--related`).
   pub related: Vec<String>,
   pub update_snapshots: bool,
+  /// Run mutation testing instead of a regular test run. Empty to mutate
+  /// every covered source module, otherwise only the given paths.
+  pub mutate: Option<Vec<String>>,
+  /// Record each test's outcome to a history file and report flaky tests.
+  /// `None` when `--history` is absent, `Some(None)` for the default file in
+  /// the Deno dir, `Some(Some(path))` for `--history=<path>`.
+  pub history: Option<Option<String>>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
@@ -807,6 +936,7 @@ pub enum DenoSubcommand {
   Install(InstallFlags),
   JSONReference(JSONReferenceFlags),
   Jupyter(JupyterFlags),
+  Kv(KvFlags),
   Uninstall(UninstallFlags),
   Link(LinkFlags),
   Unlink(UnlinkFlags),
@@ -856,6 +986,9 @@ impl DenoSubcommand {
       })
       | Self::Compile(CompileFlags {
         watch: Some(flags), ..
+      })
+      | Self::Task(TaskFlags {
+        watch: Some(flags), ..
       }) => Some(WatchFlagsRef::Watch(flags)),
       _ => None,
     }
@@ -1016,6 +1149,8 @@ pub struct Flags {
   pub allow_scripts: PackagesAllowedScripts,
   pub deny_scripts: Vec<PackageReq>,
   pub permission_set: Option<String>,
+  pub permission_audit: Option<String>,
+  pub permissions_learn: Option<String>,
   pub eszip: bool,
   pub node_conditions: Vec<String>,
   pub preload: Vec<String>,
@@ -8,6 +8,7 @@ use std::path::PathBuf;
 
 use boxed_error::Boxed;
 use deno_error::JsError;
+use deno_path_util::normalize_path;
 use deno_path_util::url_from_file_path;
 use deno_path_util::url_parent;
 use deno_path_util::url_to_file_path;
@@ -651,6 +652,15 @@ struct SerializedTestConfig {
   pub sanitize_ops: Option<bool>,
   #[serde(rename = "sanitizeResources")]
   pub sanitize_resources: Option<bool>,
+  pub quarantine: Vec<SerializedQuarantinedTest>,
+}
+
+/// `test.quarantine` entry representation for serde.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+struct SerializedQuarantinedTest {
+  pub module: String,
+  pub name: String,
 }
 
 impl SerializedTestConfig {
@@ -682,6 +692,16 @@ impl SerializedTestConfig {
       },
       sanitize_ops: self.sanitize_ops,
       sanitize_resources: self.sanitize_resources,
+      quarantine: self
+        .quarantine
+        .into_iter()
+        .map(|test| {
+          Ok(QuarantinedTest {
+            module: config_file_specifier.join(&test.module)?,
+            name: test.name,
+          })
+        })
+        .collect::<Result<_, IntoResolvedError>>()?,
     })
   }
 }
@@ -692,6 +712,16 @@ pub struct TestConfig {
   pub permissions: Option<Box<PermissionsObjectWithBase>>,
   pub sanitize_ops: Option<bool>,
   pub sanitize_resources: Option<bool>,
+  /// Tests whose failures are reported but don't fail the run.
+  pub quarantine: Vec<QuarantinedTest>,
+}
+
+/// A test listed in `test.quarantine`, identified by its module and name.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct QuarantinedTest {
+  /// The test module, resolved relative to the config file.
+  pub module: Url,
+  pub name: String,
 }
 
 impl TestConfig {
@@ -701,6 +731,7 @@ impl TestConfig {
       permissions: None,
       sanitize_ops: None,
       sanitize_resources: None,
+      quarantine: Vec::new(),
     }
   }
 }
@@ -831,6 +862,113 @@ struct SerializedCoverageConfig {
   pub thresholds: SerializedCoverageThresholds,
 }
 
+/// `taskCache` config: lets `deno task` share cached task outputs across
+/// machines through a remote store.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TaskCacheConfig {
+  pub remote: Option<RemoteTaskCacheConfig>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoteTaskCacheConfig {
+  /// Base URL of a content-addressed HTTP store. Entries are read with `GET`
+  /// and written with `PUT` to `<url>/<fingerprint>`.
+  pub url: Url,
+  /// Name of the environment variable holding a bearer token sent in the
+  /// `Authorization` header. No header is sent when the variable is unset.
+  pub token_env: String,
+  /// Only read from the remote store, never upload to it.
+  pub read_only: bool,
+}
+
+const DEFAULT_TASK_CACHE_TOKEN_ENV: &str = "DENO_TASK_CACHE_TOKEN";
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+struct SerializedRemoteTaskCacheConfig {
+  pub url: String,
+  pub token_env: Option<String>,
+  pub read_only: bool,
+}
+
+/// `taskCache` config representation for serde.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct SerializedTaskCacheConfig {
+  pub remote: Option<SerializedRemoteTaskCacheConfig>,
+}
+
+/// `kv` config: limits for the KV databases a program opens locally with
+/// `Deno.openKv()`. Remote databases always keep the default limits.
+///
+/// The limits are kept as JSON objects and typed by the runtime that
+/// enforces them.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KvConfig {
+  /// Limits for every local database.
+  pub limits: serde_json::Map<String, Value>,
+  /// Limits for single database files, keyed by their path resolved
+  /// relative to the config file.
+  pub databases: IndexMap<PathBuf, serde_json::Map<String, Value>>,
+}
+
+impl KvConfig {
+  /// Returns this config with the limits set in `other` taking precedence,
+  /// limit by limit.
+  pub fn merge(mut self, other: KvConfig) -> KvConfig {
+    self.limits.extend(other.limits);
+    for (path, limits) in other.databases {
+      self.databases.entry(path).or_default().extend(limits);
+    }
+    self
+  }
+}
+
+/// `kv.databases` entry representation for serde.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct SerializedKvDatabaseConfig {
+  pub limits: serde_json::Map<String, Value>,
+}
+
+/// `kv` config representation for serde.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct SerializedKvConfig {
+  pub limits: serde_json::Map<String, Value>,
+  pub databases: IndexMap<String, SerializedKvDatabaseConfig>,
+}
+
+/// `telemetry` config: how traces are sampled. The `OTEL_TRACES_SAMPLER`
+/// and `OTEL_TRACES_SAMPLER_ARG` environment variables take precedence over
+/// `sampler.type` and `sampler.arg`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct TelemetryConfig {
+  pub sampler: TelemetrySamplerConfig,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct TelemetrySamplerConfig {
+  /// A sampler name accepted by `OTEL_TRACES_SAMPLER`.
+  #[serde(rename = "type")]
+  pub kind: Option<String>,
+  pub arg: Option<f64>,
+  pub rules: Vec<TelemetrySamplingRule>,
+}
+
+/// Decides whether to keep the trace of a `Deno.serve` request once it
+/// has been handled.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct TelemetrySamplingRule {
+  pub route: Option<String>,
+  pub ratio: Option<f64>,
+  pub errors: bool,
+  pub slower_than_ms: Option<u64>,
+}
+
 /// `compile` config representation for serde
 ///
 /// fields `include` and `exclude` are expanded from [SerializedFilesConfig].
@@ -1506,6 +1644,9 @@ pub struct ConfigFileJson {
   pub lint: Option<Value>,
   pub fmt: Option<Value>,
   pub tasks: Option<Value>,
+  pub task_cache: Option<Value>,
+  pub kv: Option<Value>,
+  pub telemetry: Option<Value>,
   pub test: Option<Value>,
   pub bench: Option<Value>,
   pub coverage: Option<Value>,
@@ -1672,6 +1813,16 @@ pub enum ToInvalidConfigError {
     "Invalid \"coverage\" configuration: \"thresholds.{metric}\" must be between 0 and 100, but got {value}"
   )]
   CoverageThresholdOutOfRange { metric: &'static str, value: f64 },
+  #[class(type)]
+  #[error(
+    "Invalid \"taskCache\" configuration: \"remote.url\" must be an http or https URL, but got \"{url}\""
+  )]
+  InvalidTaskCacheUrl { url: String },
+  #[class(type)]
+  #[error(
+    "Invalid \"telemetry\" configuration: \"{field}\" must be between 0 and 1, but got {value}"
+  )]
+  TelemetryRatioOutOfRange { field: &'static str, value: f64 },
 }
 
 #[derive(Debug, Error, JsError)]
@@ -2387,6 +2538,7 @@ impl ConfigFile {
         permissions: None,
         sanitize_ops: None,
         sanitize_resources: None,
+        quarantine: Vec::new(),
       }),
     }
   }
@@ -2522,6 +2674,87 @@ impl ConfigFile {
     }
   }
 
+  pub fn to_task_cache_config(
+    &self,
+  ) -> Result<TaskCacheConfig, ToInvalidConfigError> {
+    let Some(config) = self.json.task_cache.clone() else {
+      return Ok(TaskCacheConfig::default());
+    };
+    let serialized: SerializedTaskCacheConfig = serde_json::from_value(config)
+      .map_err(|error| ToInvalidConfigError::Parse {
+        config: "taskCache",
+        source: error,
+      })?;
+    let remote = match serialized.remote {
+      Some(remote) => {
+        let url = Url::parse(&remote.url)
+          .ok()
+          .filter(|url| matches!(url.scheme(), "http" | "https"))
+          .ok_or_else(|| ToInvalidConfigError::InvalidTaskCacheUrl {
+            url: remote.url.clone(),
+          })?;
+        Some(RemoteTaskCacheConfig {
+          url,
+          token_env: remote
+            .token_env
+            .unwrap_or_else(|| DEFAULT_TASK_CACHE_TOKEN_ENV.to_string()),
+          read_only: remote.read_only,
+        })
+      }
+      None => None,
+    };
+    Ok(TaskCacheConfig { remote })
+  }
+
+  pub fn to_kv_config(&self) -> Result<KvConfig, ToInvalidConfigError> {
+    let Some(config) = self.json.kv.clone() else {
+      return Ok(KvConfig::default());
+    };
+    let serialized: SerializedKvConfig = serde_json::from_value(config)
+      .map_err(|error| ToInvalidConfigError::Parse {
+        config: "kv",
+        source: error,
+      })?;
+    let dir_path = self.dir_path();
+    Ok(KvConfig {
+      limits: serialized.limits,
+      databases: serialized
+        .databases
+        .into_iter()
+        .map(|(path, database)| {
+          let path = normalize_path(Cow::Owned(dir_path.join(path)));
+          (path.into_owned(), database.limits)
+        })
+        .collect(),
+    })
+  }
+
+  pub fn to_telemetry_config(
+    &self,
+  ) -> Result<TelemetryConfig, ToInvalidConfigError> {
+    let Some(config) = self.json.telemetry.clone() else {
+      return Ok(TelemetryConfig::default());
+    };
+    let config: TelemetryConfig =
+      serde_json::from_value(config).map_err(|error| {
+        ToInvalidConfigError::Parse {
+          config: "telemetry",
+          source: error,
+        }
+      })?;
+    for rule in &config.sampler.rules {
+      if let Some(value) = rule.ratio
+        && !(0.0..=1.0).contains(&value)
+      {
+        return Err(ToInvalidConfigError::TelemetryRatioOutOfRange {
+          field: "sampler.rules[].ratio",
+          value,
+        });
+      }
+    }
+    Ok(config)
+  }
+
   pub fn to_allow_scripts_config(
     &self,
   ) -> Result<AllowScriptsConfig, ToInvalidConfigError> {
@@ -3357,6 +3590,154 @@ mod tests {
     );
   }
 
+  #[test]
+  fn task_cache_remote() {
+    let specifier = Url::parse("file:///deno/deno.json").unwrap();
+    let config_file = ConfigFile::new(
+      r#"{ "taskCache": { "remote": { "url": "https://cache.example.com/deno", "readOnly": true } } }"#,
+      specifier.clone(),
+    )
+    .unwrap();
+    assert_eq!(
+      config_file.to_task_cache_config().unwrap(),
+      TaskCacheConfig {
+        remote: Some(RemoteTaskCacheConfig {
+          url: Url::parse("https://cache.example.com/deno").unwrap(),
+          token_env: "DENO_TASK_CACHE_TOKEN".to_string(),
+          read_only: true,
+        }),
+      }
+    );
+
+    let config_file = ConfigFile::new(
+      r#"{ "taskCache": { "remote": { "url": "file:///tmp/cache" } } }"#,
+      specifier,
+    )
+    .unwrap();
+    assert!(matches!(
+      config_file.to_task_cache_config().unwrap_err(),
+      ToInvalidConfigError::InvalidTaskCacheUrl { .. }
+    ));
+  }
+
+  #[test]
+  fn kv_limits() {
+    let specifier = root_url().join("deno.json").unwrap();
+    let config_file = ConfigFile::new(
+      r#"{
+        "kv": {
+          "limits": { "maxValueSizeBytes": 1048576 },
+          "databases": {
+            "./data/../cache.db": { "limits": { "maxMutations": 5000 } }
+          }
+        }
+      }"#,
+      specifier.clone(),
+    )
+    .unwrap();
+    let config = config_file.to_kv_config().unwrap();
+    assert_eq!(
+      config.limits,
+      *json!({ "maxValueSizeBytes": 1048576 }).as_object().unwrap()
+    );
+    let cache_db_path =
+      url_to_file_path(&root_url().join("cache.db").unwrap()).unwrap();
+    assert_eq!(
+      config.databases.into_iter().collect::<Vec<_>>(),
+      vec![(
+        cache_db_path.clone(),
+        json!({ "maxMutations": 5000 }).as_object().unwrap().clone()
+      )]
+    );
+
+    // the limits of `other` take precedence one by one
+    let root = KvConfig {
+      limits: json!({ "maxChecks": 1, "maxMutations": 2 })
+        .as_object()
+        .unwrap()
+        .clone(),
+      databases: IndexMap::from([(
+        cache_db_path.clone(),
+        json!({ "maxChecks": 3 }).as_object().unwrap().clone(),
+      )]),
+    };
+    let member = KvConfig {
+      limits: json!({ "maxMutations": 4 }).as_object().unwrap().clone(),
+      databases: IndexMap::from([(
+        cache_db_path.clone(),
+        json!({ "maxMutations": 5 }).as_object().unwrap().clone(),
+      )]),
+    };
+    let merged = root.merge(member);
+    assert_eq!(
+      Value::Object(merged.limits),
+      json!({ "maxChecks": 1, "maxMutations": 4 })
+    );
+    assert_eq!(
+      Value::Object(merged.databases[&cache_db_path].clone()),
+      json!({ "maxChecks": 3, "maxMutations": 5 })
+    );
+
+    let config_file =
+      ConfigFile::new(r#"{ "kv": { "database": {} } }"#, specifier).unwrap();
+    assert!(matches!(
+      config_file.to_kv_config().unwrap_err(),
+      ToInvalidConfigError::Parse { config: "kv", .. }
+    ));
+  }
+
+  #[test]
+  fn telemetry_sampler() {
+    let specifier = Url::parse("file:///deno/deno.json").unwrap();
+    let config_file = ConfigFile::new(
+      r#"{
+        "telemetry": {
+          "sampler": {
+            "type": "parentbased_traceidratio",
+            "arg": 0.1,
+            "rules": [
+              { "route": "/api/*", "errors": true, "slowerThanMs": 500 },
+              { "ratio": 0 }
+            ]
+          }
+        }
+      }"#,
+      specifier.clone(),
+    )
+    .unwrap();
+    assert_eq!(
+      config_file.to_telemetry_config().unwrap(),
+      TelemetryConfig {
+        sampler: TelemetrySamplerConfig {
+          kind: Some("parentbased_traceidratio".to_string()),
+          arg: Some(0.1),
+          rules: vec![
+            TelemetrySamplingRule {
+              route: Some("/api/*".to_string()),
+              ratio: None,
+              errors: true,
+              slower_than_ms: Some(500),
+            },
+            TelemetrySamplingRule {
+              ratio: Some(0.0),
+              ..Default::default()
+            },
+          ],
+        },
+      }
+    );
+
+    let config_file = ConfigFile::new(
+      r#"{ "telemetry": { "sampler": { "rules": [{ "ratio": 2 }] } } }"#,
+      specifier,
+    )
+    .unwrap();
+    assert!(matches!(
+      config_file.to_telemetry_config().unwrap_err(),
+      ToInvalidConfigError::TelemetryRatioOutOfRange { value: 2.0, .. }
+    ));
+  }
+
   #[track_caller]
   fn run_task_error_test(config_text: &str, expected_error: &str) {
     let config_dir = Url::parse("file:///deno/").unwrap();
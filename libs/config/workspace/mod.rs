@@ -54,6 +54,7 @@ use crate::deno_json::DeployConfig;
 use crate::deno_json::DesktopConfig;
 use crate::deno_json::FmtConfig;
 use crate::deno_json::FmtOptionsConfig;
+use crate::deno_json::KvConfig;
 use crate::deno_json::LinkConfigParseError;
 use crate::deno_json::LintConfig;
 use crate::deno_json::LintRulesConfig;
@@ -2451,6 +2452,20 @@ impl WorkspaceDirectory {
     })
   }
 
+  /// Resolves the kv config, merging the workspace root and member `kv`
+  /// sections (member limits take precedence limit by limit).
+  pub fn to_kv_config(&self) -> Result<KvConfig, ToInvalidConfigError> {
+    let root_config = match &self.deno_json.root {
+      Some(root) => root.to_kv_config()?,
+      None => KvConfig::default(),
+    };
+    let member_config = match &self.deno_json.member {
+      Some(member) => member.to_kv_config()?,
+      None => KvConfig::default(),
+    };
+    Ok(root_config.merge(member_config))
+  }
+
   fn to_bench_config_inner(
     &self,
   ) -> Result<&BenchConfig, ToInvalidConfigError> {
@@ -2715,6 +2730,7 @@ impl WorkspaceDirectory {
         permissions: None,
         sanitize_ops: None,
         sanitize_resources: None,
+        quarantine: Vec::new(),
       },
     };
     let root_config = match &self.deno_json.root {
@@ -2733,6 +2749,15 @@ impl WorkspaceDirectory {
       sanitize_resources: member_config
         .sanitize_resources
         .or(root_config.sanitize_resources),
+      quarantine: {
+        let mut quarantine = root_config.quarantine;
+        for test in member_config.quarantine {
+          if !quarantine.contains(&test) {
+            quarantine.push(test);
+          }
+        }
+        quarantine
+      },
     })
   }
 
@@ -4420,6 +4445,7 @@ pub mod test {
         permissions: None,
         sanitize_ops: None,
         sanitize_resources: None,
+        quarantine: Vec::new(),
       }
     );
 
@@ -4445,6 +4471,7 @@ pub mod test {
         permissions: None,
         sanitize_ops: None,
         sanitize_resources: None,
+        quarantine: Vec::new(),
       }
     );
   }
@@ -1218,6 +1218,11 @@
     refreshTimer: __timers.refreshTimer,
     refTimer: __timers.refTimer,
     unrefTimer: __timers.unrefTimer,
+    // Virtual time for test fake clocks.
+    enableVirtualTime: __timers.enableVirtualTime,
+    advanceVirtualTime: __timers.advanceVirtualTime,
+    nextTimerExpiry: __timers.nextTimerExpiry,
+    disableVirtualTime: __timers.disableVirtualTime,
     // System timers bypass Deno's test sanitizer resource tracking.
     createSystemTimer: (callback, after, isRefed) =>
       __timers.createTimer(callback, after, undefined, false, !!isRefed, true),
@@ -12,6 +12,7 @@
     op_timer_track,
     op_timer_untrack,
     op_timer_now,
+    op_timer_set_virtual_now,
     op_leak_tracing_submit,
   } = window.Deno.core.ops;
   const {
@@ -407,6 +408,51 @@
     }
   }
 
+  // ---------------------------------------------------------------------------
+  // Virtual time (used by test fake clocks). While enabled, `op_timer_now()`
+  // is frozen and timers only fire when JS advances the clock.
+  // ---------------------------------------------------------------------------
+  let virtualNow = null;
+
+  function enableVirtualTime() {
+    if (virtualNow === null) {
+      virtualNow = op_timer_now();
+      op_timer_set_virtual_now(virtualNow);
+    }
+    return virtualNow;
+  }
+
+  // Move virtual time forward to `now` and run the timers due by then.
+  function advanceVirtualTime(now) {
+    if (virtualNow === null) {
+      throw new Error("Virtual time is not enabled");
+    }
+    if (now > virtualNow) {
+      virtualNow = now;
+      op_timer_set_virtual_now(now);
+    }
+    processTimers(virtualNow);
+  }
+
+  // Expiry of the earliest timer list, or null when there are no timers.
+  function nextTimerExpiry() {
+    const list = timerListQueue.peek();
+    return list == null ? null : list.expiry;
+  }
+
+  function disableVirtualTime() {
+    if (virtualNow === null) return;
+    virtualNow = null;
+    op_timer_set_virtual_now(-1);
+    // Timers created under virtual time were never handed to the native
+    // timer, so schedule the earliest one now.
+    const list = timerListQueue.peek();
+    if (list != null) {
+      nextExpiry = list.expiry;
+      op_timer_schedule(MathMax(list.expiry - op_timer_now(), 1));
+    }
+  }
+
   // Exported on window.__timers for 01_core.js to pick up.
   window.__timers = {
     processTimers,
@@ -427,6 +473,10 @@
     L_remove,
     L_append,
     L_isEmpty,
+    enableVirtualTime,
+    advanceVirtualTime,
+    nextTimerExpiry,
+    disableVirtualTime,
     __setTimerInfo(buf) {
       timerInfo = buf;
     },
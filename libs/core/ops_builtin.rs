@@ -106,6 +106,7 @@ builtin_ops! {
   ops_builtin_v8::op_timer_track,
   ops_builtin_v8::op_timer_untrack,
   ops_builtin_v8::op_timer_now,
+  ops_builtin_v8::op_timer_set_virtual_now,
   ops_builtin_v8::op_ref_op,
   ops_builtin_v8::op_unref_op,
   ops_builtin_v8::op_lazy_load_esm,
@@ -143,6 +143,20 @@ pub fn op_timer_schedule(scope: &mut v8::PinScope, delay_ms: f64) {
   }
 }
 
+/// Switch the user timer between real and virtual time.
+///
+/// - `now >= 0`: freeze time at `now` (as returned by `op_timer_now`)
+/// - `now < 0`: go back to real time
+#[op2(fast)]
+pub fn op_timer_set_virtual_now(scope: &mut v8::PinScope, now: f64) {
+  let context_state = JsRealm::state_from_scope(scope);
+  context_state.user_timer.set_virtual_now(if now >= 0.0 {
+    Some(now)
+  } else {
+    None
+  });
+}
+
 /// Register a JS-managed timer with the Rust stats system for leak detection.
 /// System timers (e.g. AbortSignal.timeout) are tracked but excluded from
 /// sanitizer stats, matching the old `op_timer_queue_system` behavior.
@@ -203,6 +203,10 @@ pub(crate) struct UserTimer<R: Reactor> {
   base_instant: R::Instant,
   /// Whether the timer handle is "ref'd" (keeps event loop alive).
   refed: Cell<bool>,
+  /// When set, time is frozen at this value (in the same units as
+  /// [`UserTimer::now`]) and only moves when JS advances it. Used by test
+  /// fake clocks so that every timer observes the same virtual time.
+  virtual_now: Cell<Option<f64>>,
 }
 
 impl<R: Reactor + Default> Default for UserTimer<R> {
@@ -218,11 +222,19 @@ impl<R: Reactor> UserTimer<R> {
       sleep: MutableSleep::new(),
       reactor,
       refed: Cell::new(false),
+      virtual_now: Cell::new(None),
     }
   }
 
   /// Schedule a wakeup after `delay` from now.
+  ///
+  /// Under virtual time there is nothing to wait for, as timers only fire
+  /// when JS advances the clock.
   pub fn schedule(&self, delay: Duration) {
+    if self.virtual_now.get().is_some() {
+      self.sleep.clear();
+      return;
+    }
     let deadline = self.reactor.now().checked_add(delay).unwrap();
     self.sleep.change(self.reactor.timer(deadline));
   }
@@ -240,9 +252,23 @@ impl<R: Reactor> UserTimer<R> {
   /// Get the current monotonic time in milliseconds since this timer
   /// was created (process start).
   pub fn now(&self) -> f64 {
+    if let Some(now) = self.virtual_now.get() {
+      return now;
+    }
     self.base_instant.elapsed().as_secs_f64() * 1000.0
   }
 
+  /// Freeze time at `now`, or go back to real time with `None`.
+  ///
+  /// Going back to real time doesn't reschedule the wakeup; JS does that
+  /// once it knows its next expiry.
+  pub fn set_virtual_now(&self, now: Option<f64>) {
+    if now.is_some() {
+      self.sleep.clear();
+    }
+    self.virtual_now.set(now);
+  }
+
   /// Mark the timer handle as ref'd (keeps event loop alive).
   pub fn ref_timer(&self) {
     self.refed.set(true);
@@ -254,7 +280,10 @@ impl<R: Reactor> UserTimer<R> {
   }
 
   /// Whether the timer handle is ref'd.
+  ///
+  /// Timers never keep the event loop alive under virtual time: they can't
+  /// fire on their own, so waiting for them would hang forever.
   pub fn is_refed(&self) -> bool {
-    self.refed.get()
+    self.refed.get() && self.virtual_now.get().is_none()
   }
 }
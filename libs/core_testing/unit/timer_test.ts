@@ -115,3 +115,22 @@ test(async function testCancellationDuringDispatch() {
   await new Promise((r) => setTimeout(r, 10));
   assertEquals(timeouts, 1);
 });
+
+test(function testVirtualTime() {
+  const start = Deno.core.enableVirtualTime();
+  try {
+    const fired: string[] = [];
+    setTimeout(() => fired.push("100ms"), 100);
+    setTimeout(() => {
+      fired.push("50ms");
+      setTimeout(() => fired.push("50ms + 10ms"), 10);
+    }, 50);
+    Deno.core.advanceVirtualTime(start + 50);
+    assertEquals(fired, ["50ms"]);
+    assertEquals(Deno.core.nextTimerExpiry(), start + 60);
+    Deno.core.advanceVirtualTime(start + 100);
+    assertEquals(fired, ["50ms", "50ms + 10ms", "100ms"]);
+  } finally {
+    Deno.core.disableVirtualTime();
+  }
+});
@@ -491,6 +491,13 @@ fn get_message_suggestions(e: &JsError) -> Vec<FixSuggestion<'_>> {
           "Run again with `--unstable-cron` flag to enable this API.",
         ),
       ];
+    } else if msg.contains("cronHistory is not a function") {
+      return vec![
+        FixSuggestion::info("Deno.cronHistory() is an unstable API."),
+        FixSuggestion::hint(
+          "Run again with `--unstable-cron` flag to enable this API.",
+        ),
+      ];
     } else if msg.contains("WebSocketStream is not defined") {
       return vec![
         FixSuggestion::info("new WebSocketStream() is an unstable API."),
@@ -319,6 +319,9 @@ denoNsUnstableById[unstableIds.cron] = {
   get cron() {
     return lazyCron().cron;
   },
+  get cronHistory() {
+    return lazyCron().cronHistory;
+  },
 };
 
 denoNsUnstableById[unstableIds.kv] = {
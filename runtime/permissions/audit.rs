@@ -0,0 +1,128 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! The permission audit log written with `--permission-audit=<file>`.
+//!
+//! Unlike `DENO_AUDIT_PERMISSIONS`, which records every access before it is
+//! checked, this records the outcome of the first check of each distinct
+//! descriptor: whether it was granted and whether a flag, a prompt or the
+//! permission broker decided it. Running a program once with it is enough to
+//! derive the `--allow-*` flags it actually needs.
+
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::Write;
+use std::sync::OnceLock;
+
+use parking_lot::Mutex;
+use serde::Serialize;
+
+use crate::prompter::MAYBE_CURRENT_STACKTRACE;
+
+static PERMISSION_AUDIT_LOG: OnceLock<PermissionAuditLog> = OnceLock::new();
+
+struct PermissionAuditLog {
+  file: Mutex<File>,
+  seen: Mutex<HashSet<(&'static str, Option<String>)>>,
+}
+
+/// What decided the outcome of a permission check.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AuditSource {
+  /// The `--allow-*` and `--deny-*` flags, or the lack of them when
+  /// prompting is disabled.
+  Flag,
+  /// The answer to a permission prompt.
+  Prompt,
+  /// The permission broker.
+  Broker,
+}
+
+#[derive(Serialize)]
+struct AuditRecord<'a> {
+  v: u8,
+  datetime: String,
+  permission: &'a str,
+  value: Option<&'a str>,
+  result: &'static str,
+  source: AuditSource,
+  frame: Option<String>,
+}
+
+/// Starts writing the audit log to `file`. Returns `false` if a log was
+/// already set.
+pub fn set_permission_audit_log(file: File) -> bool {
+  PERMISSION_AUDIT_LOG
+    .set(PermissionAuditLog {
+      file: Mutex::new(file),
+      seen: Default::default(),
+    })
+    .is_ok()
+}
+
+pub fn has_permission_audit_log() -> bool {
+  PERMISSION_AUDIT_LOG.get().is_some()
+}
+
+/// The innermost non-internal JS stack frame of the op doing the current
+/// check, if stack traces are being collected.
+///
+/// This has to be read before prompting, as the prompt takes the stack.
+pub(crate) fn current_frame() -> Option<String> {
+  if !has_permission_audit_log() {
+    return None;
+  }
+  let get_stack = MAYBE_CURRENT_STACKTRACE.lock();
+  let stack = get_stack.as_ref()?();
+  stack
+    .into_iter()
+    .find(|frame| !frame.starts_with("ext:") && !frame.contains("(ext:"))
+}
+
+/// Records the outcome of a check, unless one was already recorded for the
+/// same descriptor.
+pub(crate) fn audit_permission_check(
+  permission: &'static str,
+  value: impl FnOnce() -> Option<String>,
+  granted: bool,
+  source: AuditSource,
+  frame: impl FnOnce() -> Option<String>,
+) {
+  let Some(log) = PERMISSION_AUDIT_LOG.get() else {
+    return;
+  };
+  let value = value();
+  if !log.seen.lock().insert((permission, value.clone())) {
+    return;
+  }
+
+  #[allow(
+    clippy::disallowed_methods,
+    reason = "TODO: support passing in a sys here"
+  )]
+  let datetime =
+    chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+  let record = AuditRecord {
+    v: 1,
+    datetime,
+    permission,
+    value: value.as_deref(),
+    result: if granted { "granted" } else { "denied" },
+    source,
+    frame: frame(),
+  };
+  let mut line = serde_json::to_vec(&record).unwrap();
+  line.push(b'\n');
+  // The log is best effort and never fails a permission check.
+  let _ = log.file.lock().write_all(&line);
+}
+
+/// Formats a value passed to the fully-granted fast path like the other
+/// audit sinks do, with `()` meaning the whole permission.
+pub(crate) fn audit_value(value: impl Serialize) -> Option<String> {
+  match serde_json::to_value(value).ok()? {
+    serde_json::Value::Null => None,
+    serde_json::Value::String(s) => Some(s),
+    other => Some(other.to_string()),
+  }
+}
@@ -0,0 +1,116 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! Records the permission descriptors a program uses, for
+//! `deno run --permissions-learn`. The program runs with all permissions
+//! granted, so every access goes through the fully-granted fast path, which
+//! reports it here.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::sync::OnceLock;
+
+use deno_path_util::url_to_file_path;
+use parking_lot::Mutex;
+use url::Url;
+
+static LEARNED_PERMISSIONS: OnceLock<Mutex<LearnedPermissions>> =
+  OnceLock::new();
+
+/// The values a permission was used with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LearnedPermission {
+  /// Used without a specific value, e.g. by `Deno.env.toObject()`.
+  All,
+  Some(BTreeSet<String>),
+}
+
+/// Learned permissions keyed by their flag name, e.g. `read` or `net`.
+pub type LearnedPermissions = BTreeMap<String, LearnedPermission>;
+
+pub fn start_learning_permissions() {
+  LEARNED_PERMISSIONS.get_or_init(Default::default);
+}
+
+pub fn is_learning_permissions() -> bool {
+  LEARNED_PERMISSIONS.get().is_some()
+}
+
+/// Takes the permissions recorded so far.
+pub fn take_learned_permissions() -> LearnedPermissions {
+  match LEARNED_PERMISSIONS.get() {
+    Some(learned) => std::mem::take(&mut *learned.lock()),
+    None => Default::default(),
+  }
+}
+
+pub(crate) fn record(name: &str, value: Option<String>) {
+  let Some(learned) = LEARNED_PERMISSIONS.get() else {
+    return;
+  };
+  let value = value.map(|value| to_flag_value(name, value));
+  let mut learned = learned.lock();
+  let entry = learned
+    .entry(name.to_string())
+    .or_insert_with(|| LearnedPermission::Some(BTreeSet::new()));
+  match (entry, value) {
+    (LearnedPermission::All, _) => {}
+    (entry, None) => *entry = LearnedPermission::All,
+    (LearnedPermission::Some(values), Some(value)) => {
+      values.insert(value);
+    }
+  }
+}
+
+/// Turns a value passed to a check into what the matching `--allow-*` flag
+/// takes: a path for `file:` specifiers and a host for remote URLs.
+fn to_flag_value(name: &str, value: String) -> String {
+  match name {
+    "read" | "write" | "ffi" if value.starts_with("file:") => {
+      match Url::parse(&value)
+        .ok()
+        .and_then(|u| url_to_file_path(&u).ok())
+      {
+        Some(path) => path.display().to_string(),
+        None => value,
+      }
+    }
+    "net" | "import" if value.contains("://") => {
+      let Ok(url) = Url::parse(&value) else {
+        return value;
+      };
+      match (url.host_str(), url.port_or_known_default()) {
+        (Some(host), Some(port)) => format!("{host}:{port}"),
+        (Some(host), None) => host.to_string(),
+        _ => value,
+      }
+    }
+    _ => value,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn flag_values() {
+    assert_eq!(
+      to_flag_value("import", "https://jsr.io/@std/path/mod.ts".to_string()),
+      "jsr.io:443"
+    );
+    assert_eq!(
+      to_flag_value("net", "http://localhost:8000/api".to_string()),
+      "localhost:8000"
+    );
+    assert_eq!(
+      to_flag_value("net", "localhost:8000".to_string()),
+      "localhost:8000"
+    );
+    assert_eq!(to_flag_value("env", "HOME".to_string()), "HOME");
+    #[cfg(unix)]
+    assert_eq!(
+      to_flag_value("read", "file:///tmp/data.txt".to_string()),
+      "/tmp/data.txt"
+    );
+  }
+}
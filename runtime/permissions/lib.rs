@@ -32,8 +32,10 @@ use serde::Serialize;
 use serde::de;
 use url::Url;
 
+pub mod audit;
 pub mod broker;
 mod ipc_pipe;
+pub mod learn;
 pub mod prompter;
 mod runtime_descriptor_parser;
 pub mod which;
@@ -52,6 +54,7 @@ pub enum BrokerResponse {
   Deny { message: Option<String> },
 }
 
+use self::audit::AuditSource;
 use self::broker::has_broker;
 use self::broker::maybe_check_with_broker;
 
@@ -89,6 +92,10 @@ fn write_audit<T>(flag_name: &str, value: T)
 where
   T: Serialize,
 {
+  if learn::is_learning_permissions() {
+    learn::record(flag_name, audit::audit_value(&value));
+  }
+
   let Some(sink) = AUDIT_SINK.get() else {
     return;
   };
@@ -144,9 +151,17 @@ where
 /// is in the "fully-granted" state.
 macro_rules! audit_and_skip_check_if_is_permission_fully_granted {
   ($this:expr, $flag_name:expr, $value:expr) => {
-    write_audit($flag_name, $value);
+    let value = $value;
+    write_audit($flag_name, &value);
 
     if $this.is_allow_all() {
+      audit::audit_permission_check(
+        $flag_name,
+        || audit::audit_value(&value),
+        true,
+        AuditSource::Flag,
+        audit::current_frame,
+      );
       return Ok(());
     }
   };
@@ -526,6 +541,36 @@ impl PermissionState {
     stringify_value_fn: impl Fn() -> Option<String>,
     info: impl Fn() -> Option<String>,
     prompt: bool,
+  ) -> (Result<(), PermissionDeniedError>, bool, bool) {
+    // read before prompting, which takes the stack
+    let frame = audit::current_frame();
+    let (result, prompted, is_allow_all) =
+      self.check_inner(name, api_name, &stringify_value_fn, info, prompt);
+    let source = if has_broker() {
+      AuditSource::Broker
+    } else if prompted {
+      AuditSource::Prompt
+    } else {
+      AuditSource::Flag
+    };
+    audit::audit_permission_check(
+      name,
+      stringify_value_fn,
+      result.is_ok(),
+      source,
+      || frame,
+    );
+    (result, prompted, is_allow_all)
+  }
+
+  #[inline]
+  fn check_inner(
+    self,
+    name: &'static str,
+    api_name: Option<&str>,
+    stringify_value_fn: impl Fn() -> Option<String>,
+    info: impl Fn() -> Option<String>,
+    prompt: bool,
   ) -> (Result<(), PermissionDeniedError>, bool, bool) {
     if let Some(resp) = maybe_check_with_broker(name, &stringify_value_fn) {
       match resp {
@@ -37,6 +37,7 @@ pub fn get_extensions_in_snapshot() -> Vec<Extension> {
     deno_kv::deno_kv::init(
       Box::new(deno_kv::sqlite::SqliteDbHandler::new(None, None)),
       deno_kv::KvConfig::builder().build(),
+      Default::default(),
     ),
     deno_cron::deno_cron::init(Box::new(
       deno_cron::local::LocalCronHandler::new(),
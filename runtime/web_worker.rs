@@ -394,6 +394,8 @@ pub struct WebWorkerOptions {
   pub format_js_error_fn: Option<Arc<FormatJsErrorFn>>,
   pub worker_type: WorkerThreadType,
   pub cache_storage_dir: Option<std::path::PathBuf>,
+  /// Limits that override the defaults for KV databases stored locally.
+  pub kv_local_limits: deno_kv::LocalKvLimits,
   pub stdio: Stdio,
   pub trace_ops: Option<Vec<String>>,
   pub close_on_idle: bool,
@@ -570,6 +572,7 @@ impl WebWorker {
           },
         )),
         deno_kv::KvConfig::builder().build(),
+        options.kv_local_limits,
       ),
       deno_cron::deno_cron::init(Box::new(CronHandlerImpl::create_from_env())),
       deno_napi::deno_napi::init(services.deno_rt_native_addon_loader.clone()),
@@ -308,6 +308,8 @@ pub struct WorkerOptions {
 
   pub cache_storage_dir: Option<std::path::PathBuf>,
   pub origin_storage_dir: Option<std::path::PathBuf>,
+  /// Limits that override the defaults for KV databases stored locally.
+  pub kv_local_limits: deno_kv::LocalKvLimits,
   pub stdio: Stdio,
   pub enable_raw_imports: bool,
   pub enable_stack_trace_arg_in_ops: bool,
@@ -329,6 +331,7 @@ impl Default for WorkerOptions {
       trace_ops: Default::default(),
       format_js_error_fn: Default::default(),
       origin_storage_dir: Default::default(),
+      kv_local_limits: Default::default(),
       cache_storage_dir: Default::default(),
       extensions: Default::default(),
       startup_snapshot: Default::default(),
@@ -656,6 +659,7 @@ impl MainWorker {
             },
           )),
           deno_kv::KvConfig::builder().build(),
+          options.kv_local_limits,
         ),
         deno_napi::deno_napi::args(
           services.deno_rt_native_addon_loader.clone(),
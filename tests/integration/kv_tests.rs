@@ -0,0 +1,160 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Lines;
+use std::process::ChildStderr;
+
+use test_util::DenoChild;
+use test_util::TestContext;
+use test_util::TestContextBuilder;
+use test_util::test;
+
+const KV_CONNECT_CLIENT: &str = r#"
+const kv = await Deno.openKv(Deno.args[0]);
+
+const first = await kv.set(["greeting"], "hello");
+console.log((await kv.get(["greeting"])).value);
+
+// a stale check fails, and a current one commits
+const stale = await kv.atomic()
+  .check({ key: ["greeting"], versionstamp: null })
+  .set(["greeting"], "stale")
+  .commit();
+console.log(stale.ok);
+const current = await kv.atomic()
+  .check({ key: ["greeting"], versionstamp: first.versionstamp })
+  .set(["greeting"], "hi")
+  .sum(["count"], 2n)
+  .commit();
+console.log(current.ok);
+const [greeting, count] = await kv.getMany([["greeting"], ["count"]]);
+console.log(greeting.value, count.value.value);
+
+const reader = kv.watch([["greeting"]]).getReader();
+console.log((await reader.read()).value[0].value);
+await kv.set(["greeting"], "bye");
+console.log((await reader.read()).value[0].value);
+await reader.cancel();
+kv.close();
+"#;
+
+/// A `deno kv serve` process, stopped when dropped.
+struct KvServer {
+  child: DenoChild,
+  /// Kept open until the server is stopped, so that its logging doesn't
+  /// fail.
+  _stderr: Lines<BufReader<ChildStderr>>,
+  url: String,
+}
+
+impl KvServer {
+  fn start(context: &TestContext) -> Self {
+    let mut child = context
+      .new_command()
+      .args_vec([
+        "kv",
+        "serve",
+        "--path",
+        "kv.sqlite3",
+        "--access-token",
+        "secret",
+        "--port",
+        "0",
+      ])
+      .env("NO_COLOR", "1")
+      .stderr_piped()
+      .spawn()
+      .unwrap();
+    let url_regex = regex::Regex::new(r#"Deno\.openKv\("([^"]+)"\)"#).unwrap();
+    let mut stderr = BufReader::new(child.stderr.take().unwrap()).lines();
+    let url = stderr
+      .by_ref()
+      .map(|line| line.unwrap())
+      .find_map(|line| Some(url_regex.captures(&line)?[1].to_string()))
+      .expect("kv serve exited before serving");
+    Self {
+      child,
+      _stderr: stderr,
+      url,
+    }
+  }
+}
+
+impl Drop for KvServer {
+  fn drop(&mut self) {
+    self.child.kill().unwrap();
+    self.child.wait().unwrap();
+  }
+}
+
+#[test]
+fn kv_serve_connect() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  context.temp_dir().write("client.js", KV_CONNECT_CLIENT);
+  let server = KvServer::start(&context);
+
+  let output = context
+    .new_command()
+    .args_vec([
+      "run",
+      "--unstable-kv",
+      "--allow-net",
+      "client.js",
+      &server.url,
+    ])
+    .env("DENO_KV_ACCESS_TOKEN", "secret")
+    .run();
+  output.assert_matches_text("hello\nfalse\ntrue\nhi 2n\nhi\nbye\n");
+  output.assert_exit_code(0);
+
+  // a wrong access token is rejected
+  let output = context
+    .new_command()
+    .args_vec([
+      "run",
+      "--unstable-kv",
+      "--allow-net",
+      "client.js",
+      &server.url,
+    ])
+    .env("DENO_KV_ACCESS_TOKEN", "wrong")
+    .run();
+  output.assert_exit_code(1);
+}
+
+#[test]
+fn kv_watch_prints_changes() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  // the changes are made by other processes, which only the server's
+  // watches hear about
+  let server = KvServer::start(&context);
+  let set = |value: &str| {
+    context
+      .new_command()
+      .args_vec(["kv", "set", "--path", &server.url, r#"["a"]"#, value])
+      .env("DENO_KV_ACCESS_TOKEN", "secret")
+      .run()
+      .skip_output_check()
+      .assert_exit_code(0);
+  };
+  set("1");
+  let mut watcher = context
+    .new_command()
+    .args_vec(["kv", "watch", "--path", &server.url, r#"["a"]"#])
+    .env("DENO_KV_ACCESS_TOKEN", "secret")
+    .stdout_piped()
+    .spawn()
+    .unwrap();
+  let mut stdout = BufReader::new(watcher.stdout.take().unwrap()).lines();
+  let mut next_value = || {
+    let line = stdout.next().unwrap().unwrap();
+    let entries = serde_json::from_str::<serde_json::Value>(&line).unwrap();
+    entries[0]["value"].clone()
+  };
+  assert_eq!(next_value(), serde_json::json!(1));
+  set("2");
+  assert_eq!(next_value(), serde_json::json!(2));
+  watcher.kill().unwrap();
+  watcher.wait().unwrap();
+}
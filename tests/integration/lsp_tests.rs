@@ -1416,6 +1416,77 @@ fn lsp_did_refresh_deno_configuration_tree_notification() {
   client.shutdown();
 }
 
+#[test(timeout = 300)]
+fn lsp_document_link() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir();
+  let deno_json = "{\n  \"imports\": {\n    \"b\": \"./b.ts\"\n  }\n}\n";
+  temp_dir.write("deno.json", deno_json);
+  temp_dir.write("b.ts", "export const b = 1;\n");
+  let b_uri = url_to_uri(&temp_dir.url().join("b.ts").unwrap()).unwrap();
+  let mut client = context.new_lsp_command().build();
+  client.initialize_default();
+  client.did_open(json!({
+    "textDocument": {
+      "uri": temp_dir.url().join("main.ts").unwrap(),
+      "languageId": "typescript",
+      "version": 1,
+      "text": "import { b } from \"b\";\nimport \"./b.ts\";\nconsole.log(b);\n",
+    },
+  }));
+  let res = client.write_request(
+    "textDocument/documentLink",
+    json!({
+      "textDocument": { "uri": temp_dir.url().join("main.ts").unwrap() },
+    }),
+  );
+  assert_eq!(
+    res,
+    json!([
+      {
+        "range": {
+          "start": { "line": 0, "character": 19 },
+          "end": { "line": 0, "character": 20 },
+        },
+        "target": b_uri,
+      },
+      {
+        "range": {
+          "start": { "line": 1, "character": 8 },
+          "end": { "line": 1, "character": 14 },
+        },
+        "target": b_uri,
+      },
+    ]),
+  );
+
+  client.did_open(json!({
+    "textDocument": {
+      "uri": temp_dir.url().join("deno.json").unwrap(),
+      "languageId": "json",
+      "version": 1,
+      "text": deno_json,
+    },
+  }));
+  let res = client.write_request(
+    "textDocument/documentLink",
+    json!({
+      "textDocument": { "uri": temp_dir.url().join("deno.json").unwrap() },
+    }),
+  );
+  assert_eq!(
+    res,
+    json!([{
+      "range": {
+        "start": { "line": 2, "character": 10 },
+        "end": { "line": 2, "character": 16 },
+      },
+      "target": b_uri,
+    }]),
+  );
+  client.shutdown();
+}
+
 #[test(timeout = 300)]
 fn lsp_did_change_deno_configuration_notification() {
   let context = TestContextBuilder::new().use_temp_cwd().build();
@@ -3990,6 +4061,83 @@ fn lsp_call_hierarchy() {
   client.shutdown();
 }
 
+#[test(timeout = 300)]
+fn lsp_type_hierarchy() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let mut client = context.new_lsp_command().build();
+  client.initialize_default();
+  client.did_open(
+    json!({
+      "textDocument": {
+        "uri": "file:///a/file.ts",
+        "languageId": "typescript",
+        "version": 1,
+        "text": "interface Shape {}\nclass Base implements Shape {}\nclass Circle extends Base {}\n"
+      }
+    }),
+  );
+  let base = json!({
+    "name": "Base",
+    "kind": 5,
+    "uri": "file:///a/file.ts",
+    "range": {
+      "start": { "line": 1, "character": 0 },
+      "end": { "line": 1, "character": 30 }
+    },
+    "selectionRange": {
+      "start": { "line": 1, "character": 6 },
+      "end": { "line": 1, "character": 10 }
+    }
+  });
+  let res = client.write_request(
+    "textDocument/prepareTypeHierarchy",
+    json!({
+      "textDocument": {
+        "uri": "file:///a/file.ts"
+      },
+      "position": { "line": 1, "character": 7 }
+    }),
+  );
+  assert_eq!(res, json!([base]));
+  let res =
+    client.write_request("typeHierarchy/supertypes", json!({ "item": base }));
+  assert_eq!(
+    res,
+    json!([{
+      "name": "Shape",
+      "kind": 11,
+      "uri": "file:///a/file.ts",
+      "range": {
+        "start": { "line": 0, "character": 0 },
+        "end": { "line": 0, "character": 18 }
+      },
+      "selectionRange": {
+        "start": { "line": 0, "character": 10 },
+        "end": { "line": 0, "character": 15 }
+      }
+    }])
+  );
+  let res =
+    client.write_request("typeHierarchy/subtypes", json!({ "item": base }));
+  assert_eq!(
+    res,
+    json!([{
+      "name": "Circle",
+      "kind": 5,
+      "uri": "file:///a/file.ts",
+      "range": {
+        "start": { "line": 2, "character": 0 },
+        "end": { "line": 2, "character": 28 }
+      },
+      "selectionRange": {
+        "start": { "line": 2, "character": 6 },
+        "end": { "line": 2, "character": 12 }
+      }
+    }])
+  );
+  client.shutdown();
+}
+
 #[test(timeout = 300)]
 fn lsp_large_doc_changes() {
   let context = TestContextBuilder::new().use_temp_cwd().build();
@@ -4725,6 +4873,76 @@ fn lsp_selection_range() {
   client.shutdown();
 }
 
+#[test(timeout = 300)]
+fn lsp_linked_editing_range() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let mut client = context.new_lsp_command().build();
+  client.initialize_default();
+  client.did_open(json!({
+    "textDocument": {
+      "uri": "file:///a/file.tsx",
+      "languageId": "typescriptreact",
+      "version": 1,
+      "text": "const a = <div>hello</div>;\nconst b = <>x</>;\n",
+    },
+  }));
+  let res = client.write_request(
+    "textDocument/linkedEditingRange",
+    json!({
+      "textDocument": { "uri": "file:///a/file.tsx" },
+      "position": { "line": 0, "character": 12 },
+    }),
+  );
+  assert_eq!(
+    res,
+    json!({
+      "ranges": [
+        {
+          "start": { "line": 0, "character": 11 },
+          "end": { "line": 0, "character": 14 },
+        },
+        {
+          "start": { "line": 0, "character": 22 },
+          "end": { "line": 0, "character": 25 },
+        },
+      ],
+      "wordPattern": "[a-zA-Z0-9:\\-\\._$]*",
+    }),
+  );
+  let res = client.write_request(
+    "textDocument/linkedEditingRange",
+    json!({
+      "textDocument": { "uri": "file:///a/file.tsx" },
+      "position": { "line": 1, "character": 11 },
+    }),
+  );
+  assert_eq!(
+    res,
+    json!({
+      "ranges": [
+        {
+          "start": { "line": 1, "character": 11 },
+          "end": { "line": 1, "character": 11 },
+        },
+        {
+          "start": { "line": 1, "character": 15 },
+          "end": { "line": 1, "character": 15 },
+        },
+      ],
+      "wordPattern": "[a-zA-Z0-9:\\-\\._$]*",
+    }),
+  );
+  let res = client.write_request(
+    "textDocument/linkedEditingRange",
+    json!({
+      "textDocument": { "uri": "file:///a/file.tsx" },
+      "position": { "line": 0, "character": 17 },
+    }),
+  );
+  assert_eq!(res, json!(null));
+  client.shutdown();
+}
+
 // TODO(nayeemrmn): Enable for tsgo when implemented upstream:
 // https://github.com/microsoft/typescript-go/pull/1990
 #[test(timeout = 300)]
@@ -13846,6 +14064,88 @@ fn lsp_format_mbc() {
   client.shutdown();
 }
 
+#[test(timeout = 300)]
+fn lsp_format_range() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let mut client = context.new_lsp_command().build();
+  client.initialize_default();
+  client.did_open(json!({
+    "textDocument": {
+      "uri": "file:///a/file.ts",
+      "languageId": "typescript",
+      "version": 1,
+      "text": "const a = 'a'\nconst b = 1;\nconst c = 'c'\n"
+    }
+  }));
+  let res = client.write_request(
+    "textDocument/rangeFormatting",
+    json!({
+      "textDocument": {
+        "uri": "file:///a/file.ts"
+      },
+      "range": {
+        "start": { "line": 2, "character": 0 },
+        "end": { "line": 3, "character": 0 }
+      },
+      "options": {
+        "tabSize": 2,
+        "insertSpaces": true
+      }
+    }),
+  );
+  assert_eq!(
+    res,
+    json!([{
+      "range": {
+        "start": { "line": 2, "character": 0 },
+        "end": { "line": 3, "character": 0 }
+      },
+      "newText": "const c = \"c\";\n"
+    }])
+  );
+  client.shutdown();
+}
+
+#[test(timeout = 300)]
+fn lsp_format_on_type() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let mut client = context.new_lsp_command().build();
+  client.initialize_default();
+  client.did_open(json!({
+    "textDocument": {
+      "uri": "file:///a/file.ts",
+      "languageId": "typescript",
+      "version": 1,
+      "text": "const a = 'a'\nfunction f() {\nreturn 'x'\n}\nconst c = 'c'\n"
+    }
+  }));
+  let res = client.write_request(
+    "textDocument/onTypeFormatting",
+    json!({
+      "textDocument": {
+        "uri": "file:///a/file.ts"
+      },
+      "position": { "line": 3, "character": 1 },
+      "ch": "}",
+      "options": {
+        "tabSize": 2,
+        "insertSpaces": true
+      }
+    }),
+  );
+  assert_eq!(
+    res,
+    json!([{
+      "range": {
+        "start": { "line": 2, "character": 0 },
+        "end": { "line": 3, "character": 0 }
+      },
+      "newText": "  return \"x\";\n"
+    }])
+  );
+  client.shutdown();
+}
+
 #[test(timeout = 300)]
 fn lsp_format_exclude_with_config() {
   let context = TestContextBuilder::new().use_temp_cwd().build();
@@ -15074,6 +15374,159 @@ fn lsp_workspace_symbol() {
   client.shutdown();
 }
 
+#[test(timeout = 300)]
+fn lsp_code_actions_lint_plugin_fix_all() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir();
+  temp_dir.write(
+    "deno.json",
+    json!({
+      "lint": {
+        "plugins": ["./plugin.ts"],
+        "rules": { "tags": [] },
+      },
+    })
+    .to_string(),
+  );
+  temp_dir.write(
+    "plugin.ts",
+    r#"export default {
+  name: "test-plugin",
+  rules: {
+    "my-rule": {
+      create(context) {
+        return {
+          Literal(node) {
+            if (node.value === "unfixed") {
+              context.report({
+                node,
+                message: "should be fixed",
+                fix: (fixer) => fixer.replaceText(node, '"fixed"'),
+              });
+            }
+          },
+        };
+      },
+    },
+  },
+} satisfies Deno.lint.Plugin;
+"#,
+  );
+  let uri = url_to_uri(&temp_dir.url().join("main.ts").unwrap()).unwrap();
+  let mut client = context.new_lsp_command().build();
+  client.initialize_default();
+  let diagnostics = client.did_open(json!({
+    "textDocument": {
+      "uri": uri,
+      "languageId": "typescript",
+      "version": 1,
+      "text": "const a = \"unfixed\";\nconst b = \"unfixed\";\nconsole.log(a, b);\n",
+    },
+  }));
+  let diagnostics = diagnostics.for_file(&uri);
+  assert_eq!(diagnostics.len(), 2);
+  let other_uri =
+    url_to_uri(&temp_dir.url().join("other.ts").unwrap()).unwrap();
+  client.did_open(json!({
+    "textDocument": {
+      "uri": other_uri,
+      "languageId": "typescript",
+      "version": 1,
+      "text": "console.log(\"unfixed\");\n",
+    },
+  }));
+  let fix_all_edits = json!([
+    {
+      "range": {
+        "start": { "line": 0, "character": 10 },
+        "end": { "line": 0, "character": 19 },
+      },
+      "newText": "\"fixed\"",
+    },
+    {
+      "range": {
+        "start": { "line": 1, "character": 10 },
+        "end": { "line": 1, "character": 19 },
+      },
+      "newText": "\"fixed\"",
+    },
+  ]);
+
+  let res = client.write_request(
+    "textDocument/codeAction",
+    json!({
+      "textDocument": { "uri": uri },
+      "range": diagnostics[0].range,
+      "context": {
+        "diagnostics": [diagnostics[0]],
+        "only": ["quickfix"],
+      },
+    }),
+  );
+  let actions = res.as_array().unwrap();
+  let titles = actions
+    .iter()
+    .map(|a| a["title"].as_str().unwrap())
+    .collect::<Vec<_>>();
+  assert_eq!(
+    titles,
+    vec![
+      "Fix this test-plugin/my-rule problem",
+      "Disable test-plugin/my-rule for this line",
+      "Disable test-plugin/my-rule for the entire file",
+      "Ignore lint errors for the entire file",
+      "Fix all test-plugin/my-rule problems in this file",
+      "Fix all test-plugin/my-rule problems in the workspace",
+    ]
+  );
+  let fix_all = &actions[4];
+  assert_eq!(fix_all["kind"], json!("quickfix"));
+  assert_eq!(fix_all["diagnostics"], json!(diagnostics));
+  assert_eq!(fix_all["edit"]["changes"][uri.as_str()], fix_all_edits);
+  let workspace_fix_all = &actions[5];
+  assert_eq!(workspace_fix_all["kind"], json!("quickfix"));
+  assert_eq!(workspace_fix_all["diagnostics"], json!(diagnostics));
+  assert_eq!(
+    workspace_fix_all["edit"]["changes"],
+    json!({
+      uri.as_str(): fix_all_edits,
+      other_uri.as_str(): [
+        {
+          "range": {
+            "start": { "line": 0, "character": 12 },
+            "end": { "line": 0, "character": 21 },
+          },
+          "newText": "\"fixed\"",
+        },
+      ],
+    })
+  );
+
+  let res = client.write_request(
+    "textDocument/codeAction",
+    json!({
+      "textDocument": { "uri": uri },
+      "range": {
+        "start": { "line": 0, "character": 0 },
+        "end": { "line": 0, "character": 0 },
+      },
+      "context": {
+        "diagnostics": [],
+        "only": ["source.fixAll.deno"],
+      },
+    }),
+  );
+  let actions = res.as_array().unwrap();
+  assert_eq!(actions.len(), 1);
+  assert_eq!(
+    actions[0]["title"],
+    json!("Fix all auto-fixable lint problems")
+  );
+  assert_eq!(actions[0]["kind"], json!("source.fixAll.deno"));
+  assert_eq!(actions[0]["edit"]["changes"][uri.as_str()], fix_all_edits);
+  client.shutdown();
+}
+
 #[test(timeout = 300)]
 fn lsp_code_actions_ignore_lint() {
   let context = TestContextBuilder::new().use_temp_cwd().build();
@@ -45,6 +45,8 @@ mod jsr;
 mod jupyter;
 #[path = "jupyter_client.rs"]
 mod jupyter_client;
+#[path = "kv_tests.rs"]
+mod kv;
 #[path = "lsp_tests.rs"]
 mod lsp;
 #[path = "napi_tests.rs"]
@@ -518,6 +518,39 @@ fn permissions_audit_with_traces() {
   );
 }
 
+#[test]
+fn permission_audit_flag() {
+  let ctx = TestContext::default();
+  let dir = ctx.temp_dir();
+  let path = dir.path().join(std::path::Path::new("audit.jsonl"));
+
+  ctx
+    .new_command()
+    .args_vec([
+      "run".to_string(),
+      format!("--permission-audit={}", path),
+      "--allow-sys".to_string(),
+      "--allow-read".to_string(),
+      "--allow-write".to_string(),
+      "--no-prompt".to_string(),
+      "run/permissions_audit.ts".to_string(),
+    ])
+    .run()
+    .skip_output_check();
+
+  // each descriptor is only recorded once, and the denied env access ends
+  // the program
+  let file = std::fs::read_to_string(path).unwrap();
+  test_util::assertions::assert_wildcard_match(
+    &file,
+    r#"{"v":1,"datetime":"[WILDCARD]","permission":"sys","value":"hostname","result":"granted","source":"flag","frame":"[WILDCARD]permissions_audit.ts:[WILDCARD]"}
+{"v":1,"datetime":"[WILDCARD]","permission":"read","value":[WILDCARD],"result":"granted","source":"flag","frame":[WILDCARD]}
+{"v":1,"datetime":"[WILDCARD]","permission":"write","value":[WILDCARD],"result":"granted","source":"flag","frame":[WILDCARD]}
+{"v":1,"datetime":"[WILDCARD]","permission":"env","value":"FOO","result":"denied","source":"flag","frame":"[WILDCARD]permissions_audit.ts:[WILDCARD]"}
+"#,
+  );
+}
+
 #[test]
 fn lock_redirects() {
   let context = TestContextBuilder::new()
@@ -3868,3 +3868,122 @@ Deno.test("env test", () => {
 
   check_alive_then_kill(child);
 }
+
+#[test]
+async fn task_watch_reruns_only_affected_tasks() {
+  let t = TempDir::new();
+  t.write(
+    "deno.json",
+    r#"{
+  "tasks": {
+    "gen": { "command": "echo gen ran", "files": ["gen.txt"] },
+    "build": {
+      "command": "echo build ran",
+      "files": ["build.txt"],
+      "dependencies": ["gen"]
+    },
+    "server": "deno run server.js",
+    "dev": { "dependencies": ["build", "server"] }
+  }
+}"#,
+  );
+  t.write("gen.txt", "1");
+  t.write("build.txt", "1");
+  t.write(
+    "server.js",
+    "console.log('server started'); setInterval(() => {}, 1000);",
+  );
+
+  let mut child = util::deno_cmd()
+    .current_dir(t.path())
+    .arg("task")
+    .arg("--watch")
+    .arg("-L")
+    .arg("debug")
+    .arg("dev")
+    .env("NO_COLOR", "1")
+    .piped_output()
+    .spawn()
+    .unwrap();
+  let (mut stdout_lines, mut stderr_lines) = child_lines(&mut child);
+
+  // Both inputs are registered in a single call, before any task runs, so
+  // once this is logged every later write is observed.
+  wait_contains("Task started", &mut stderr_lines).await;
+  let line = wait_for_watcher("build.txt", &mut stderr_lines).await;
+  assert_contains!(&line, "gen.txt");
+  // `server` runs alongside `gen` and `build`, so their output can come in
+  // any order.
+  let mut expected = vec!["server started", "gen ran", "build ran"];
+  while !expected.is_empty() {
+    let line = next_line(&mut stdout_lines).await.unwrap();
+    expected.retain(|s| !line.contains(s));
+  }
+
+  // Only `build` depends on this file: `gen` is untouched, and the
+  // long-running `server` keeps running instead of being restarted.
+  t.write("build.txt", "2");
+  let line = wait_contains("Rerunning:", &mut stderr_lines).await;
+  assert_contains!(&line, "build");
+  assert_not_contains!(&line, "gen");
+  assert_not_contains!(&line, "server");
+  wait_contains("build ran", &mut stdout_lines).await;
+
+  // Changing an upstream input reruns the task and its dependents.
+  t.write("gen.txt", "2");
+  let line = wait_contains("Rerunning:", &mut stderr_lines).await;
+  assert_contains!(&line, "gen, build");
+  assert_not_contains!(&line, "server");
+  wait_contains("gen ran", &mut stdout_lines).await;
+  wait_contains("build ran", &mut stdout_lines).await;
+
+  check_alive_then_kill(child);
+}
+
+#[test]
+async fn task_watch_reruns_uncacheable_task_on_own_input_change() {
+  let t = TempDir::new();
+  // `setup` declares no `files`, so `build` can't be cached and has no
+  // fingerprint, but it must still rerun when its own inputs change.
+  t.write(
+    "deno.json",
+    r#"{
+  "tasks": {
+    "setup": "echo setup ran",
+    "build": {
+      "command": "echo build ran",
+      "files": ["build.txt"],
+      "dependencies": ["setup"]
+    }
+  }
+}"#,
+  );
+  t.write("build.txt", "1");
+
+  let mut child = util::deno_cmd()
+    .current_dir(t.path())
+    .arg("task")
+    .arg("--watch")
+    .arg("-L")
+    .arg("debug")
+    .arg("build")
+    .env("NO_COLOR", "1")
+    .piped_output()
+    .spawn()
+    .unwrap();
+  let (mut stdout_lines, mut stderr_lines) = child_lines(&mut child);
+
+  wait_contains("Task started", &mut stderr_lines).await;
+  wait_for_watcher("build.txt", &mut stderr_lines).await;
+  wait_contains("setup ran", &mut stdout_lines).await;
+  wait_contains("build ran", &mut stdout_lines).await;
+  wait_contains("Tasks finished", &mut stderr_lines).await;
+
+  t.write("build.txt", "2");
+  let line = wait_contains("Rerunning:", &mut stderr_lines).await;
+  assert_contains!(&line, "build");
+  assert_not_contains!(&line, "setup");
+  wait_contains("build ran", &mut stdout_lines).await;
+
+  check_alive_then_kill(child);
+}
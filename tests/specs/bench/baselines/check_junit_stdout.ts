@@ -0,0 +1,16 @@
+const { stdout } = new Deno.Command(Deno.execPath(), {
+  args: [
+    "bench",
+    "--allow-env",
+    "--reporter=junit",
+    "--baseline=main",
+    "--regression-threshold=1000",
+    "main_bench.ts",
+  ],
+  stderr: "null",
+}).outputSync();
+const xml = new TextDecoder().decode(stdout).trim();
+console.log(
+  xml.endsWith("</testsuites>"),
+  xml.includes("comparison against baseline"),
+);
@@ -0,0 +1,9 @@
+const iterations = Deno.env.get("SLOW") ? 100_000 : 100;
+
+Deno.bench("loop", () => {
+  let sum = 0;
+  for (let i = 0; i < iterations; i++) {
+    sum += i;
+  }
+  if (sum < 0) throw new Error("unreachable");
+});
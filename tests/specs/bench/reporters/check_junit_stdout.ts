@@ -0,0 +1,6 @@
+const { stdout } = new Deno.Command(Deno.execPath(), {
+  args: ["bench", "--reporter=junit", "--junit-path=-", "main_bench.ts"],
+  stderr: "null",
+}).outputSync();
+const xml = new TextDecoder().decode(stdout);
+console.log(xml.split("<?xml").length - 1);
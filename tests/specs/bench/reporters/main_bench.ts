@@ -0,0 +1,5 @@
+Deno.bench("pass", () => {});
+Deno.bench("fail", () => {
+  throw new Error("boom");
+});
+Deno.bench("grouped", { group: "g" }, () => {});
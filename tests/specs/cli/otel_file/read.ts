@@ -0,0 +1,43 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+const dir = Deno.args[0];
+
+// metrics are only exported when the process exits, so they aren't checked
+const files = Array.from(Deno.readDirSync(dir), (entry) => entry.name)
+  .filter((name) => !name.startsWith("metrics"))
+  .sort();
+console.log(files);
+
+const spans = [];
+for (const file of files.filter((name) => name.startsWith("traces"))) {
+  for (const line of Deno.readTextFileSync(`${dir}/${file}`).split("\n")) {
+    if (line === "") continue;
+    const request = JSON.parse(line);
+    for (const resourceSpans of request.resourceSpans) {
+      for (const scopeSpans of resourceSpans.scopeSpans) {
+        for (const span of scopeSpans.spans) {
+          spans.push(span);
+        }
+      }
+    }
+  }
+}
+console.log(spans.map((span) => {
+  const parent = spans.find((other) => other.spanId === span.parentSpanId);
+  return { name: span.name, parent: parent?.name ?? null };
+}));
+
+const logs = [];
+for (const file of files.filter((name) => name.startsWith("logs"))) {
+  for (const line of Deno.readTextFileSync(`${dir}/${file}`).split("\n")) {
+    if (line === "") continue;
+    for (const resourceLogs of JSON.parse(line).resourceLogs) {
+      for (const scopeLogs of resourceLogs.scopeLogs) {
+        for (const record of scopeLogs.logRecords) {
+          logs.push(record.body.stringValue);
+        }
+      }
+    }
+  }
+}
+console.log(logs);
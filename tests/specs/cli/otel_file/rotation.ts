@@ -0,0 +1,11 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+import { trace } from "npm:@opentelemetry/api@1.9.0";
+
+const tracer = trace.getTracer("example-tracer");
+
+// each span is exported on its own, so every export rotates the file
+for (const name of ["first", "second", "third"]) {
+  tracer.startSpan(name).end();
+  await new Promise((resolve) => setTimeout(resolve, 100));
+}
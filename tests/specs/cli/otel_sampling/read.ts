@@ -0,0 +1,16 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+const spans = [];
+for (const line of Deno.readTextFileSync("otel/traces.jsonl").split("\n")) {
+  if (line === "") continue;
+  for (const resourceSpans of JSON.parse(line).resourceSpans) {
+    for (const scopeSpans of resourceSpans.scopeSpans) {
+      spans.push(...scopeSpans.spans);
+    }
+  }
+}
+const summary = spans.map((span) => {
+  const path = span.attributes.find((attr) => attr.key === "url.path");
+  return `${span.name} ${path?.value.stringValue ?? ""}`.trim();
+});
+console.log(summary.sort());
@@ -0,0 +1,31 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+import { trace } from "npm:@opentelemetry/api@1.9.0";
+
+const tracer = trace.getTracer("example-tracer");
+
+const server = Deno.serve({
+  port: 0,
+  async onListen({ port }) {
+    try {
+      for (const path of ["/ok", "/slow", "/error"]) {
+        await (await fetch(`http://localhost:${port}${path}`)).text();
+      }
+    } finally {
+      server.shutdown();
+    }
+  },
+  handler: async (req) => {
+    const { pathname } = new URL(req.url);
+    if (pathname === "/slow") {
+      await new Promise((resolve) => setTimeout(resolve, 200));
+      return new Response("slow");
+    }
+    if (pathname === "/error") {
+      // kept along with the request's span
+      tracer.startActiveSpan("handle error", (span) => span.end());
+      return new Response("error", { status: 500 });
+    }
+    return new Response("ok");
+  },
+});
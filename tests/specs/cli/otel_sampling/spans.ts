@@ -0,0 +1,13 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+import { trace } from "npm:@opentelemetry/api@1.9.0";
+
+const tracer = trace.getTracer("example-tracer");
+
+// only the first trace fits in the limit of one trace per second
+for (const name of ["first", "second", "third"]) {
+  tracer.startActiveSpan(name, (root) => {
+    tracer.startActiveSpan(`${name} child`, (child) => child.end());
+    root.end();
+  });
+}
@@ -0,0 +1,20 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+const kv = await Deno.openKv(":memory:");
+const { versionstamp } = await kv.set(["a"], 1);
+await kv.atomic()
+  .check({ key: ["a"], versionstamp })
+  .set(["a"], 2)
+  .set(["b"], 3)
+  .commit();
+// fails the check, the versionstamp changed
+await kv.atomic()
+  .check({ key: ["a"], versionstamp })
+  .delete(["a"])
+  .commit();
+kv.close();
+
+const cache = await caches.open("otel");
+await cache.put("http://localhost/hit", new Response("cached"));
+await (await cache.match("http://localhost/hit"))!.text();
+await cache.match("http://localhost/miss");
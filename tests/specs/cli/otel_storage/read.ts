@@ -0,0 +1,20 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+const spans = [];
+for (const line of Deno.readTextFileSync("otel/traces.jsonl").split("\n")) {
+  if (line === "") continue;
+  for (const resourceSpans of JSON.parse(line).resourceSpans) {
+    for (const scopeSpans of resourceSpans.scopeSpans) {
+      spans.push(...scopeSpans.spans);
+    }
+  }
+}
+for (const span of spans) {
+  const attributes = Object.fromEntries(
+    span.attributes.map((attr) => [
+      attr.key,
+      Object.values(attr.value)[0],
+    ]),
+  );
+  console.log(span.name, span.kind, attributes);
+}
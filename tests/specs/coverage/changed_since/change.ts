@@ -0,0 +1,11 @@
+Deno.writeTextFileSync(
+  "mod.ts",
+  Deno.readTextFileSync("mod.ts") + `
+export function uncovered(a: number) {
+  if (a > 0) {
+    return a;
+  }
+  return -a;
+}
+`,
+);
@@ -0,0 +1,9 @@
+const { stdout } = new Deno.Command(Deno.execPath(), {
+  args: ["coverage", "--changed-since=base", "--lcov", "cov2"],
+  stderr: "null",
+}).outputSync();
+const lines = new TextDecoder().decode(stdout).trimEnd().split("\n");
+const invalid = lines.filter((line) =>
+  !/^(SF|FN|FNDA|FNF|FNH|BRDA|BRF|BRH|DA|LH|LF):|^end_of_record$/.test(line)
+);
+console.log(lines.includes("end_of_record"), invalid);
@@ -0,0 +1,3 @@
+export function covered(a: number) {
+  return a + 1;
+}
@@ -0,0 +1,5 @@
+import { covered } from "./mod.ts";
+
+Deno.test("covered", () => {
+  covered(1);
+});
@@ -0,0 +1,9 @@
+export function f(b: boolean) {
+  var result;
+  if (b) {
+    result = true;
+  } else {
+    result = false;
+  }
+  return result;
+}
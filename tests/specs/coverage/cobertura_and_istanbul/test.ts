@@ -0,0 +1,6 @@
+import { f } from "./mod.ts";
+
+Deno.test("f", () => {
+  f(true);
+  f(false);
+});
@@ -0,0 +1 @@
+await Deno.openKv(":memory:");
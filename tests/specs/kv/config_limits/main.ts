@@ -0,0 +1,14 @@
+async function trySet(path: string, size: number) {
+  const db = await Deno.openKv(path);
+  try {
+    await db.set(["k"], new Uint8Array(size));
+    console.log(`${path}: set ${size} bytes`);
+  } catch (err) {
+    console.log(`${path}: ${(err as Error).message}`);
+  } finally {
+    db.close();
+  }
+}
+
+await trySet(":memory:", 70_000);
+await trySet("small.db", 11);
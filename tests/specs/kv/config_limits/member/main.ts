@@ -0,0 +1,10 @@
+const db = await Deno.openKv(":memory:");
+// the value size limit comes from the workspace root
+await db.set(["k"], new Uint8Array(70_000));
+console.log("set 70000 bytes");
+try {
+  db.watch([["a"], ["b"]]);
+} catch (err) {
+  console.log((err as Error).message);
+}
+db.close();
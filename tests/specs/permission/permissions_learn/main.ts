@@ -0,0 +1,2 @@
+console.log(Deno.readTextFileSync("./data.txt").trim());
+console.log(Deno.env.get("FOO"));
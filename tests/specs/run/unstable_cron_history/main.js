@@ -0,0 +1,30 @@
+let count = 0;
+const { promise, resolve } = Promise.withResolvers();
+const ac = new AbortController();
+const c = Deno.cron(
+  "history",
+  "* * * * *",
+  { backoffSchedule: [], signal: ac.signal },
+  () => {
+    count++;
+    if (count === 2) {
+      throw new Error("boom");
+    }
+    if (count === 3) {
+      resolve();
+    }
+  },
+);
+await promise;
+
+// The first two executions are recorded before the third one is dispatched.
+const history = await Deno.cronHistory("history");
+const [second, first] = history.slice(-2);
+console.log(first.success, second.success);
+console.log(first.catchUp, first.scheduledAt instanceof Date);
+console.log(first.scheduledAt <= first.finishedAt);
+console.log((await Deno.cronHistory("history", { limit: 1 })).length);
+console.log((await Deno.cronHistory("unknown")).length);
+
+ac.abort();
+await c;
@@ -0,0 +1,30 @@
+// The test server keeps entries in memory for its whole lifetime, so give
+// each run its own namespace to avoid hitting entries from a previous run.
+const namespace = crypto.randomUUID();
+Deno.writeTextFileSync(
+  "deno.json",
+  JSON.stringify({
+    taskCache: {
+      remote: { url: `http://localhost:4270/${namespace}` },
+    },
+    tasks: {
+      build: {
+        command: "deno run -A src/gen.ts",
+        files: ["src/**/*.ts"],
+        output: ["dist/**"],
+      },
+      // same as `build` except for the name
+      "build-copy": {
+        command: "deno run -A src/gen.ts",
+        files: ["src/**/*.ts"],
+        output: ["dist/**"],
+      },
+      // same as `build` except for the output globs
+      "build-txt": {
+        command: "deno run -A src/gen.ts",
+        files: ["src/**/*.ts"],
+        output: ["dist/*.txt"],
+      },
+    },
+  }),
+);
@@ -0,0 +1,3 @@
+Deno.mkdirSync("dist", { recursive: true });
+Deno.writeTextFileSync("dist/out.txt", "generated");
+console.log("generated");
@@ -0,0 +1,94 @@
+function assertEquals(actual: unknown, expected: unknown) {
+  const a = JSON.stringify(actual);
+  const e = JSON.stringify(expected);
+  if (a !== e) {
+    throw new Error(`Expected ${e}, got ${a}`);
+  }
+}
+
+Deno.test("tick runs timers in order at their scheduled time", async (t) => {
+  // time is frozen from the first access
+  const clock = t.clock;
+  const start = Date.now();
+  const fired: [string, number][] = [];
+  setTimeout(() => fired.push(["200ms", Date.now() - start]), 200);
+  setTimeout(() => {
+    fired.push(["50ms", Date.now() - start]);
+    setTimeout(() => fired.push(["50ms + 30ms", Date.now() - start]), 30);
+  }, 50);
+
+  await clock.tick(79);
+  assertEquals(fired, [["50ms", 50]]);
+  await clock.tick(200);
+  assertEquals(fired, [["50ms", 50], ["50ms + 30ms", 80], ["200ms", 200]]);
+});
+
+Deno.test("Date and performance.now follow the clock", async (t) => {
+  const clock = t.clock;
+  const date = Date.now();
+  const perf = performance.now();
+  assertEquals(new Date().getTime(), date);
+  assertEquals(clock.now, date);
+  await clock.tick(1500);
+  assertEquals(Date.now() - date, 1500);
+  assertEquals(new Date().getTime() - date, 1500);
+  assertEquals(Math.round(performance.now() - perf), 1500);
+  assertEquals(new Date(0).getTime(), 0);
+  assertEquals(new Date() instanceof Date, true);
+});
+
+Deno.test("promise continuations run between timers", async (t) => {
+  const clock = t.clock;
+  const fired: string[] = [];
+  setTimeout(async () => {
+    await Promise.resolve();
+    fired.push("first");
+    setTimeout(() => fired.push("second"), 10);
+  }, 10);
+  await clock.tick(20);
+  assertEquals(fired, ["first", "second"]);
+});
+
+Deno.test("AbortSignal.timeout uses the clock", async (t) => {
+  const clock = t.clock;
+  const signal = AbortSignal.timeout(5000);
+  await clock.tick(4999);
+  assertEquals(signal.aborted, false);
+  await clock.tick(1);
+  assertEquals(signal.aborted, true);
+});
+
+Deno.test("runAll runs every timer", async (t) => {
+  const clock = t.clock;
+  const start = clock.now;
+  let count = 0;
+  const id = setInterval(() => {
+    if (++count === 5) {
+      clearInterval(id);
+    }
+  }, 1000);
+  setTimeout(() => count++, 60_000);
+  await clock.runAll();
+  assertEquals(count, 6);
+  assertEquals(clock.now - start, 60_000);
+});
+
+Deno.test("runAll gives up on endless intervals", async (t) => {
+  const clock = t.clock;
+  const id = setInterval(() => {}, 1);
+  try {
+    await clock.runAll();
+  } finally {
+    clearInterval(id);
+  }
+});
+
+Deno.test("time is real again after the test", () => {
+  const start = performance.now();
+  while (performance.now() === start) {
+    // wait for the real clock to move
+  }
+  if (Date === undefined || Date.now() < 1_000_000_000_000) {
+    throw new Error("Date was not restored");
+  }
+});
@@ -0,0 +1,13 @@
+Deno.test("stable", () => {});
+
+let attempts = 0;
+Deno.test({
+  name: "flaky",
+  retry: 1,
+  fn() {
+    attempts++;
+    if (attempts < 2) {
+      throw new Error("not yet");
+    }
+  },
+});
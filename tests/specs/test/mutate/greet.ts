@@ -0,0 +1,10 @@
+import { greeting } from "./greeting.ts";
+
+const verbose = false;
+
+export function greet(name: string): string {
+  if (verbose) {
+    console.log("greeting", name);
+  }
+  return greeting + name;
+}
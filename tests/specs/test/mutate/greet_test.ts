@@ -0,0 +1,7 @@
+import { greet } from "./greet.ts";
+
+Deno.test("greet", () => {
+  if (greet("Deno") !== "Hello Deno") {
+    throw new Error("unexpected greeting");
+  }
+});
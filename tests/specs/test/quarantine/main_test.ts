@@ -0,0 +1,5 @@
+Deno.test("passes", () => {});
+
+Deno.test("known flaky", () => {
+  throw new Error("boom");
+});
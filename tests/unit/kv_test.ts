@@ -2600,3 +2600,158 @@ dbTest("AtomicOperation custom inspect - handles special values", (db) => {
   assert(inspected.includes('set([ "undefined" ], undefined)'));
   assert(inspected.includes('set([ "bigint" ], 9007199254740991n)'));
 });
+
+Deno.test({
+  name: "backup and restore",
+  // https://github.com/denoland/deno/issues/18363
+  ignore: Deno.build.os === "darwin" && isCI,
+  async fn() {
+    const dir = await Deno.makeTempDir();
+    const db = await Deno.openKv(`${dir}/kv.sqlite3`);
+    try {
+      await db.set(["a"], "before");
+      await db.backup(`${dir}/backup.sqlite3`);
+
+      const after = await db.set(["a"], "after");
+      await db.set(["b"], 1);
+      await db.restore(`${dir}/backup.sqlite3`);
+
+      const a = await db.get(["a"]);
+      assertEquals(a.value, "before");
+      assertEquals((await db.get(["b"])).value, null);
+      // versionstamps keep increasing across the restore
+      const res = await db.set(["a"], "restored");
+      assert(res.ok);
+      assert(res.versionstamp > after.versionstamp);
+
+      // the backup is itself a database
+      const copy = await Deno.openKv(`${dir}/backup.sqlite3`);
+      assertEquals((await copy.get(["a"])).value, "before");
+      copy.close();
+    } finally {
+      db.close();
+      await Deno.remove(dir, { recursive: true });
+    }
+  },
+});
+
+Deno.test({
+  name: "restore wakes watches",
+  // https://github.com/denoland/deno/issues/18363
+  ignore: Deno.build.os === "darwin" && isCI,
+  async fn() {
+    const dir = await Deno.makeTempDir();
+    const db = await Deno.openKv(`${dir}/kv.sqlite3`);
+    try {
+      await db.set(["a"], "before");
+      await db.backup(`${dir}/backup.sqlite3`);
+      await db.set(["a"], "after");
+
+      const reader = db.watch([["a"]]).getReader();
+      const [initial] = (await reader.read()).value!;
+      assertEquals(initial.value, "after");
+
+      await db.restore(`${dir}/backup.sqlite3`);
+      const [restored] = (await reader.read()).value!;
+      assertEquals(restored.value, "before");
+      await reader.cancel();
+    } finally {
+      db.close();
+      await Deno.remove(dir, { recursive: true });
+    }
+  },
+});
+
+Deno.test({
+  name: "restore redelivers messages that were being delivered",
+  // https://github.com/denoland/deno/issues/18363
+  ignore: Deno.build.os === "darwin" && isCI,
+  async fn() {
+    const dir = await Deno.makeTempDir();
+    const db = await Deno.openKv(`${dir}/kv.sqlite3`);
+    const backedUp = Promise.withResolvers<void>();
+    const listener = db.listenQueue(async () => {
+      // the message is being delivered while the backup is taken
+      await db.backup(`${dir}/backup.sqlite3`);
+      backedUp.resolve();
+    });
+    try {
+      await db.enqueue("message");
+      await backedUp.promise;
+    } finally {
+      db.close();
+      await listener;
+    }
+
+    const restored = await Deno.openKv(`${dir}/restored.sqlite3`);
+    try {
+      await restored.restore(`${dir}/backup.sqlite3`);
+    } finally {
+      restored.close();
+    }
+    const reopened = await Deno.openKv(`${dir}/restored.sqlite3`);
+    const redelivered = Promise.withResolvers<unknown>();
+    const redeliveredListener = reopened.listenQueue((message) => {
+      redelivered.resolve(message);
+    });
+    try {
+      assertEquals(await redelivered.promise, "message");
+    } finally {
+      reopened.close();
+      await redeliveredListener;
+      await Deno.remove(dir, { recursive: true });
+    }
+  },
+});
+
+Deno.test({
+  name: "backup rejects in-memory databases",
+  async fn() {
+    const db = await Deno.openKv(":memory:");
+    try {
+      await assertRejects(
+        () => db.backup("backup.sqlite3"),
+        TypeError,
+        "Only databases stored in local files can be backed up or restored",
+      );
+    } finally {
+      db.close();
+    }
+  },
+});
+
+Deno.test({
+  name: "openKv limits override the defaults",
+  async fn() {
+    const db = await Deno.openKv(":memory:", {
+      limits: { maxValueSizeBytes: 100_000, maxWatchedKeys: 1 },
+    });
+    try {
+      const res = await db.set(["a"], new Uint8Array(70_000));
+      assert(res.ok);
+      await assertRejects(
+        () => db.set(["b"], new Uint8Array(100_001)),
+        TypeError,
+        "Value too large (max 100000 bytes)",
+      );
+      assertThrows(
+        () => db.watch([["a"], ["b"]]),
+        TypeError,
+        "Too many keys (max 1)",
+      );
+    } finally {
+      db.close();
+    }
+  },
+});
+
+Deno.test({
+  name: "openKv rejects invalid limits",
+  async fn() {
+    await assertRejects(
+      () => Deno.openKv(":memory:", { limits: { maxMutations: 0 } }),
+      TypeError,
+      'Limit "maxMutations" must be a positive integer: received 0',
+    );
+  },
+});
@@ -13,3 +13,4 @@ pub const PRIVATE_NPM_REGISTRY_3_PORT: u16 = 4263;
 pub const PRIVATE_NPM_REGISTRY_MTLS_PORT: u16 = 4265;
 pub const SOCKET_DEV_API_PORT: u16 = 4268;
 pub const PUBLIC_NPM_JSR_REGISTRY_PORT: u16 = 4269;
+pub const TASK_CACHE_SERVER_PORT: u16 = 4270;
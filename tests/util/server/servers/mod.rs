@@ -33,7 +33,7 @@ use http_body_util::Full;
 use http_body_util::combinators::UnsyncBoxBody;
 use hyper_utils::run_server_with_remote_addr;
 use pretty_assertions::assert_eq;
-use prost::Message;
+use prost_denokv::Message;
 use sha2::Digest;
 use tokio::io::AsyncWriteExt;
 use tokio::net::TcpStream;
@@ -44,6 +44,7 @@ mod jsr_registry;
 mod nodejs_org_mirror;
 mod npm_registry;
 mod socket_dev;
+mod task_cache;
 mod ws;
 
 use hyper_utils::ServerKind;
@@ -149,6 +150,8 @@ pub async fn run_all_servers() {
   let npm_jsr_registry_server_futs =
     npm_registry::public_npm_jsr_registry(PUBLIC_NPM_JSR_REGISTRY_PORT);
   let socket_dev_api_futs = socket_dev::api(SOCKET_DEV_API_PORT);
+  let task_cache_server_fut =
+    task_cache::task_cache_server(TASK_CACHE_SERVER_PORT);
 
   // for serving node header files to node-gyp in tests
   let node_js_mirror_server_fut =
@@ -181,6 +184,7 @@ pub async fn run_all_servers() {
     registry_server_fut.boxed_local(),
     provenance_mock_server_fut.boxed_local(),
     node_js_mirror_server_fut.boxed_local(),
+    task_cache_server_fut,
   ];
   futures.extend(npm_registry_server_futs);
   futures.extend(private_npm_registry_1_server_futs);
@@ -1007,7 +1011,8 @@ console.log("imported", import.meta.url);
         .await
         .unwrap_or_default()
         .to_bytes();
-      let Ok(body): Result<SnapshotRead, _> = prost::Message::decode(&body[..])
+      let Ok(body): Result<SnapshotRead, _> =
+        prost_denokv::Message::decode(&body[..])
       else {
         return Ok(
           Response::builder()
@@ -1064,7 +1069,8 @@ console.log("imported", import.meta.url);
         .await
         .unwrap_or_default()
         .to_bytes();
-      let Ok(_body): Result<AtomicWrite, _> = prost::Message::decode(&body[..])
+      let Ok(_body): Result<AtomicWrite, _> =
+        prost_denokv::Message::decode(&body[..])
       else {
         return Ok(
           Response::builder()
@@ -0,0 +1,88 @@
+// Copyright 2018-2026 the Deno authors. MIT license.
+
+//! In-memory stand-in for a remote `deno task` cache: a content-addressed
+//! store that serves `GET /<path>` and accepts `PUT /<path>`. Writes require
+//! `Authorization: Bearer <TEST_AUTH_TOKEN>`; reads are public so tests can
+//! cover read-only clients. Tests should namespace their entries under a
+//! unique path prefix, as the store lives as long as the server.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+
+use bytes::Bytes;
+use futures::FutureExt;
+use futures::future::LocalBoxFuture;
+use http_body_util::BodyExt;
+use http_body_util::Full;
+use http_body_util::combinators::UnsyncBoxBody;
+use hyper::Method;
+use hyper::Request;
+use hyper::Response;
+use hyper::StatusCode;
+
+use super::ServerKind;
+use super::ServerOptions;
+use super::TEST_AUTH_TOKEN;
+use super::empty_body;
+use super::hyper_utils::HandlerOutput;
+use super::run_server;
+
+static STORE: LazyLock<Mutex<HashMap<String, Bytes>>> =
+  LazyLock::new(Default::default);
+
+pub fn task_cache_server(port: u16) -> LocalBoxFuture<'static, ()> {
+  run_server(
+    ServerOptions {
+      addr: SocketAddr::from(([127, 0, 0, 1], port)),
+      kind: ServerKind::Auto,
+      error_msg: "task cache server error",
+    },
+    task_cache_handler,
+  )
+  .boxed_local()
+}
+
+async fn task_cache_handler(
+  req: Request<hyper::body::Incoming>,
+) -> HandlerOutput {
+  let path = req.uri().path().to_string();
+  match *req.method() {
+    Method::GET => {
+      let entry = STORE.lock().unwrap().get(&path).cloned();
+      Ok(match entry {
+        Some(bytes) => Response::new(UnsyncBoxBody::new(Full::new(bytes))),
+        None => Response::builder()
+          .status(StatusCode::NOT_FOUND)
+          .body(empty_body())?,
+      })
+    }
+    Method::PUT => {
+      let expected = format!("Bearer {TEST_AUTH_TOKEN}");
+      let authorized = req
+        .headers()
+        .get(hyper::header::AUTHORIZATION)
+        .is_some_and(|value| value.as_bytes() == expected.as_bytes());
+      if !authorized {
+        return Ok(
+          Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(empty_body())?,
+        );
+      }
+      let bytes = req.into_body().collect().await?.to_bytes();
+      STORE.lock().unwrap().insert(path, bytes);
+      Ok(
+        Response::builder()
+          .status(StatusCode::CREATED)
+          .body(empty_body())?,
+      )
+    }
+    _ => Ok(
+      Response::builder()
+        .status(StatusCode::METHOD_NOT_ALLOWED)
+        .body(empty_body())?,
+    ),
+  }
+}